@@ -0,0 +1,267 @@
+//! 任务完成证书
+//!
+//! 节点的 `tasks_completionCertificate` RPC 返回 [`CompletionCertificate`]：已最终确定区块的区块头、
+//! 该区块状态中 `Tasks` 存储项的读取证明，以及证明该区块已被 GRANDPA 最终确定的 finality proof。
+//!
+//! [`verify`] 只依赖证书本身和调用方信任的 GRANDPA 验证人集合，不需要运行节点：
+//! 1. 检查 justification 由验证人集合中超过三分之二权重的验证人签名
+//! 2. 检查证书区块头是 justification 确定的区块或其祖先
+//! 3. 用区块头中的状态根检查存储证明，并取出任务的 SCALE 编码
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_consensus_grandpa::{AuthorityList, GrandpaJustification, SetId};
+use sp_core::{
+    hashing::{blake2_128, twox_128},
+    Bytes,
+};
+use sp_runtime::{traits::Header as HeaderT, RuntimeAppPublic};
+use sp_trie::StorageProof;
+
+#[cfg(test)]
+mod tests;
+
+/// 运行时中任务 pallet 的名称，也是其存储前缀
+pub const PALLET_PREFIX: &[u8] = b"Tasks";
+
+/// 任务存储项的名称
+pub const STORAGE_PREFIX: &[u8] = b"Tasks";
+
+/// 任务完成证书
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionCertificate<Header> {
+    /// 任务 ID
+    pub task_id: u32,
+    /// 读取任务状态的已最终确定区块的区块头
+    pub header: Header,
+    /// 该区块状态中 `Tasks` 存储项的 SCALE 编码
+    pub task: Bytes,
+    /// 存储读取证明的 trie 节点
+    pub storage_proof: Vec<Bytes>,
+    /// SCALE 编码的 [`FinalityProof`]
+    pub finality_proof: Bytes,
+}
+
+/// GRANDPA finality proof，编码与节点 `sc_consensus_grandpa::FinalityProof` 一致
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct FinalityProof<Header: HeaderT> {
+    /// justification 确定的区块
+    pub block: Header::Hash,
+    /// SCALE 编码的 [`GrandpaJustification`]
+    pub justification: Vec<u8>,
+    /// 从证书区块的下一个区块到 `block` 的区块头
+    pub unknown_headers: Vec<Header>,
+}
+
+/// 调用方信任的 GRANDPA 验证人集合
+///
+/// 必须是证书区块所在 era 的集合：finality proof 由该集合签名。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthoritySet {
+    /// 验证人集合 ID
+    pub set_id: SetId,
+    /// 验证人及其权重
+    pub authorities: AuthorityList,
+}
+
+/// 通过验证的证书内容
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedCertificate<Header: HeaderT, Task> {
+    /// 任务 ID
+    pub task_id: u32,
+    /// 证书区块的哈希
+    pub block_hash: Header::Hash,
+    /// 证书区块的高度
+    pub block_number: Header::Number,
+    /// 解码后的任务
+    pub task: Task,
+}
+
+/// 证书验证错误
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// finality proof 或 justification 无法解码
+    MalformedFinalityProof,
+    /// justification 确定的区块与 finality proof 不符
+    JustificationTargetMismatch,
+    /// 签名者不在验证人集合中
+    UnknownAuthority,
+    /// 签名无效
+    BadSignature,
+    /// 投票的区块不是 justification 确定区块的后代
+    InvalidVoteAncestry,
+    /// 签名的验证人权重未超过三分之二
+    InsufficientWeight,
+    /// 证书区块头无法连接到 justification 确定的区块
+    InvalidHeaderChain,
+    /// 存储证明无法通过状态根检查
+    InvalidStorageProof,
+    /// 证明表明该区块状态中没有该任务
+    TaskNotFound,
+    /// 证明中的任务与证书携带的任务不一致
+    TaskMismatch,
+    /// 任务无法解码
+    MalformedTask,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Error::MalformedFinalityProof => "finality proof cannot be decoded",
+            Error::JustificationTargetMismatch => {
+                "justification does not finalize the proven block"
+            }
+            Error::UnknownAuthority => "precommit signed by an unknown authority",
+            Error::BadSignature => "precommit signature is invalid",
+            Error::InvalidVoteAncestry => {
+                "precommit target does not descend from the commit target"
+            }
+            Error::InsufficientWeight => "precommits do not reach the supermajority threshold",
+            Error::InvalidHeaderChain => {
+                "certificate header is not an ancestor of the finalized block"
+            }
+            Error::InvalidStorageProof => "storage proof does not match the state root",
+            Error::TaskNotFound => "task does not exist at the certified block",
+            Error::TaskMismatch => "certificate task differs from the proven storage value",
+            Error::MalformedTask => "task cannot be decoded",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// 任务 `task_id` 的存储键：`twox128(pallet) ++ twox128(storage) ++ blake2_128_concat(task_id)`
+pub fn task_storage_key(task_id: u32) -> Vec<u8> {
+    let encoded = task_id.encode();
+    [
+        &twox_128(PALLET_PREFIX)[..],
+        &twox_128(STORAGE_PREFIX)[..],
+        &blake2_128(&encoded)[..],
+        &encoded[..],
+    ]
+    .concat()
+}
+
+/// 验证证书，返回解码为 `Task` 的任务
+///
+/// 任务一般解码为运行时的 `pallet_tasks::Task<Runtime>`，调用方还应检查其状态和执行者。
+pub fn verify<Header, Task>(
+    certificate: &CompletionCertificate<Header>,
+    authority_set: &AuthoritySet,
+) -> Result<VerifiedCertificate<Header, Task>, Error>
+where
+    Header: HeaderT,
+    Task: Decode,
+{
+    let proof = FinalityProof::<Header>::decode(&mut &certificate.finality_proof[..])
+        .map_err(|_| Error::MalformedFinalityProof)?;
+    check_justification::<Header>(&proof, authority_set)?;
+    check_header_chain(&certificate.header, &proof)?;
+
+    let key = task_storage_key(certificate.task_id);
+    let storage_proof =
+        StorageProof::new(certificate.storage_proof.iter().map(|node| node.to_vec()));
+    let mut values = sp_state_machine::read_proof_check::<Header::Hashing, _>(
+        *certificate.header.state_root(),
+        storage_proof,
+        [&key],
+    )
+    .map_err(|_| Error::InvalidStorageProof)?;
+    let value = values.remove(&key).flatten().ok_or(Error::TaskNotFound)?;
+    if value != certificate.task.0 {
+        return Err(Error::TaskMismatch);
+    }
+
+    Ok(VerifiedCertificate {
+        task_id: certificate.task_id,
+        block_hash: certificate.header.hash(),
+        block_number: *certificate.header.number(),
+        task: Task::decode(&mut &value[..]).map_err(|_| Error::MalformedTask)?,
+    })
+}
+
+/// 检查 justification 确定了 `proof.block`，且签名权重超过验证人集合的三分之二
+fn check_justification<Header: HeaderT>(
+    proof: &FinalityProof<Header>,
+    authority_set: &AuthoritySet,
+) -> Result<(), Error> {
+    let justification = GrandpaJustification::<Header>::decode(&mut &proof.justification[..])
+        .map_err(|_| Error::MalformedFinalityProof)?;
+    let commit = &justification.commit;
+    if commit.target_hash != proof.block {
+        return Err(Error::JustificationTargetMismatch);
+    }
+
+    let ancestry: BTreeMap<_, _> = justification
+        .votes_ancestries
+        .iter()
+        .map(|header| (header.hash(), *header.parent_hash()))
+        .collect();
+
+    let mut signers = BTreeSet::new();
+    let mut signed_weight = 0u64;
+    for signed in &commit.precommits {
+        let weight = authority_set
+            .authorities
+            .iter()
+            .find(|(id, _)| *id == signed.id)
+            .map(|(_, weight)| *weight)
+            .ok_or(Error::UnknownAuthority)?;
+
+        let message = finality_grandpa::Message::Precommit(signed.precommit.clone());
+        let payload = sp_consensus_grandpa::localized_payload(
+            justification.round,
+            authority_set.set_id,
+            &message,
+        );
+        if !signed.id.verify(&payload, &signed.signature) {
+            return Err(Error::BadSignature);
+        }
+
+        // 投票可以指向确定区块的后代，沿 votes_ancestries 回溯到确定区块
+        let mut hash = signed.precommit.target_hash;
+        while hash != commit.target_hash {
+            hash = *ancestry.get(&hash).ok_or(Error::InvalidVoteAncestry)?;
+        }
+
+        // 同一验证人的多个投票只计一次
+        if signers.insert(signed.id.clone()) {
+            signed_weight = signed_weight.saturating_add(weight);
+        }
+    }
+
+    let total_weight = authority_set
+        .authorities
+        .iter()
+        .fold(0u64, |total, (_, weight)| total.saturating_add(*weight));
+    let threshold = total_weight - total_weight.saturating_sub(1) / 3;
+    if total_weight == 0 || signed_weight < threshold {
+        return Err(Error::InsufficientWeight);
+    }
+    Ok(())
+}
+
+/// 检查 `header` 就是 `proof.block`，或能经 `unknown_headers` 逐个连接到它
+fn check_header_chain<Header: HeaderT>(
+    header: &Header,
+    proof: &FinalityProof<Header>,
+) -> Result<(), Error> {
+    let mut hash = header.hash();
+    for next in &proof.unknown_headers {
+        if hash == proof.block {
+            break;
+        }
+        if *next.parent_hash() != hash {
+            return Err(Error::InvalidHeaderChain);
+        }
+        hash = next.hash();
+    }
+    if hash != proof.block {
+        return Err(Error::InvalidHeaderChain);
+    }
+    Ok(())
+}
@@ -0,0 +1,330 @@
+use crate::*;
+use finality_grandpa::{Commit, Precommit, SignedPrecommit};
+use sp_consensus_grandpa::{AuthorityId, AuthoritySignature};
+use sp_core::{Pair, H256};
+use sp_keyring::Ed25519Keyring;
+use sp_runtime::{generic, traits::BlakeTwo256, Digest, StateVersion};
+use sp_state_machine::InMemoryBackend;
+
+type Header = generic::Header<u32, BlakeTwo256>;
+
+/// 测试用的任务：(执行者, 是否完成)
+type Task = (u64, bool);
+
+const TASK_ID: u32 = 7;
+const SET_ID: SetId = 3;
+const ROUND: u64 = 11;
+const TASK: Task = (2, true);
+
+fn header(number: u32, parent_hash: H256, state_root: H256) -> Header {
+    Header::new(
+        number,
+        Default::default(),
+        state_root,
+        parent_hash,
+        Digest::default(),
+    )
+}
+
+/// 四个权重为 1 的验证人，三个签名即可达到阈值
+fn authority_set() -> AuthoritySet {
+    AuthoritySet {
+        set_id: SET_ID,
+        authorities: [
+            Ed25519Keyring::Alice,
+            Ed25519Keyring::Bob,
+            Ed25519Keyring::Charlie,
+            Ed25519Keyring::Dave,
+        ]
+        .into_iter()
+        .map(|key| (key.public().into(), 1))
+        .collect(),
+    }
+}
+
+fn sign(
+    key: Ed25519Keyring,
+    set_id: SetId,
+    target: &Header,
+) -> SignedPrecommit<H256, u32, AuthoritySignature, AuthorityId> {
+    let precommit = Precommit::new(target.hash(), *target.number());
+    let message = finality_grandpa::Message::Precommit(precommit.clone());
+    let payload = sp_consensus_grandpa::localized_payload(ROUND, set_id, &message);
+    SignedPrecommit {
+        precommit,
+        signature: key.pair().sign(&payload).into(),
+        id: key.public().into(),
+    }
+}
+
+/// 确定 `target` 的 justification，`voters` 都投给 `vote_for`
+fn justification(
+    target: &Header,
+    vote_for: &Header,
+    voters: &[Ed25519Keyring],
+    set_id: SetId,
+    votes_ancestries: Vec<Header>,
+) -> Vec<u8> {
+    GrandpaJustification::<Header> {
+        round: ROUND,
+        commit: Commit {
+            target_hash: target.hash(),
+            target_number: *target.number(),
+            precommits: voters
+                .iter()
+                .map(|key| sign(*key, set_id, vote_for))
+                .collect(),
+        },
+        votes_ancestries,
+    }
+    .encode()
+}
+
+struct Chain {
+    /// 证书区块，状态中只有任务 `TASK_ID`
+    certified: Header,
+    /// 证书区块之后的区块，最后一个被 justification 确定
+    descendants: Vec<Header>,
+    storage_proof: Vec<Bytes>,
+}
+
+fn chain() -> Chain {
+    let key = task_storage_key(TASK_ID);
+    let backend = InMemoryBackend::<BlakeTwo256>::from((
+        vec![(None, vec![(key.clone(), Some(TASK.encode()))])],
+        StateVersion::V1,
+    ));
+    let state_root = *backend.root();
+    let storage_proof = sp_state_machine::prove_read(backend, [&key])
+        .expect("key is in the backend")
+        .into_iter_nodes()
+        .map(Bytes)
+        .collect();
+
+    let certified = header(1, H256::repeat_byte(1), state_root);
+    let second = header(2, certified.hash(), H256::repeat_byte(2));
+    let third = header(3, second.hash(), H256::repeat_byte(3));
+    Chain {
+        certified,
+        descendants: vec![second, third],
+        storage_proof,
+    }
+}
+
+fn certificate(chain: &Chain, justification: Vec<u8>) -> CompletionCertificate<Header> {
+    let finalized = chain.descendants.last().expect("chain has descendants");
+    CompletionCertificate {
+        task_id: TASK_ID,
+        header: chain.certified.clone(),
+        task: TASK.encode().into(),
+        storage_proof: chain.storage_proof.clone(),
+        finality_proof: FinalityProof::<Header> {
+            block: finalized.hash(),
+            justification,
+            unknown_headers: chain.descendants.clone(),
+        }
+        .encode()
+        .into(),
+    }
+}
+
+fn valid_certificate() -> CompletionCertificate<Header> {
+    let chain = chain();
+    let finalized = chain.descendants.last().unwrap();
+    let justification = justification(
+        finalized,
+        finalized,
+        &[
+            Ed25519Keyring::Alice,
+            Ed25519Keyring::Bob,
+            Ed25519Keyring::Charlie,
+        ],
+        SET_ID,
+        vec![],
+    );
+    certificate(&chain, justification)
+}
+
+fn verify_task(certificate: &CompletionCertificate<Header>) -> Result<Task, Error> {
+    verify::<Header, Task>(certificate, &authority_set()).map(|verified| verified.task)
+}
+
+#[test]
+fn valid_certificate_is_verified() {
+    let certificate = valid_certificate();
+
+    let verified = verify::<Header, Task>(&certificate, &authority_set()).unwrap();
+
+    assert_eq!(verified.task_id, TASK_ID);
+    assert_eq!(verified.block_hash, certificate.header.hash());
+    assert_eq!(verified.block_number, 1);
+    assert_eq!(verified.task, TASK);
+}
+
+#[test]
+fn certificate_at_finalized_block_needs_no_unknown_headers() {
+    let chain = chain();
+    let voters = [
+        Ed25519Keyring::Alice,
+        Ed25519Keyring::Bob,
+        Ed25519Keyring::Dave,
+    ];
+    let mut certificate = certificate(&chain, vec![]);
+    certificate.finality_proof = FinalityProof::<Header> {
+        block: chain.certified.hash(),
+        justification: justification(&chain.certified, &chain.certified, &voters, SET_ID, vec![]),
+        unknown_headers: vec![],
+    }
+    .encode()
+    .into();
+
+    assert_eq!(verify_task(&certificate), Ok(TASK));
+}
+
+#[test]
+fn certificate_serializes_as_json() {
+    let certificate = valid_certificate();
+
+    let json = serde_json::to_string(&certificate).unwrap();
+
+    assert!(json.contains("\"taskId\":7"));
+    assert_eq!(
+        serde_json::from_str::<CompletionCertificate<Header>>(&json).unwrap(),
+        certificate
+    );
+}
+
+#[test]
+fn supermajority_is_required() {
+    let chain = chain();
+    let finalized = chain.descendants.last().unwrap();
+    let certificate = certificate(
+        &chain,
+        justification(
+            finalized,
+            finalized,
+            &[Ed25519Keyring::Alice, Ed25519Keyring::Bob],
+            SET_ID,
+            vec![],
+        ),
+    );
+
+    assert_eq!(verify_task(&certificate), Err(Error::InsufficientWeight));
+}
+
+#[test]
+fn duplicate_votes_are_counted_once() {
+    let chain = chain();
+    let finalized = chain.descendants.last().unwrap();
+    let certificate = certificate(
+        &chain,
+        justification(
+            finalized,
+            finalized,
+            &[
+                Ed25519Keyring::Alice,
+                Ed25519Keyring::Bob,
+                Ed25519Keyring::Bob,
+            ],
+            SET_ID,
+            vec![],
+        ),
+    );
+
+    assert_eq!(verify_task(&certificate), Err(Error::InsufficientWeight));
+}
+
+#[test]
+fn signatures_are_bound_to_the_authority_set() {
+    let chain = chain();
+    let finalized = chain.descendants.last().unwrap();
+    let voters = [
+        Ed25519Keyring::Alice,
+        Ed25519Keyring::Bob,
+        Ed25519Keyring::Charlie,
+    ];
+
+    let other_set = certificate(
+        &chain,
+        justification(finalized, finalized, &voters, SET_ID + 1, vec![]),
+    );
+    assert_eq!(verify_task(&other_set), Err(Error::BadSignature));
+
+    let outsider = certificate(
+        &chain,
+        justification(
+            finalized,
+            finalized,
+            &[
+                Ed25519Keyring::Alice,
+                Ed25519Keyring::Bob,
+                Ed25519Keyring::Eve,
+            ],
+            SET_ID,
+            vec![],
+        ),
+    );
+    assert_eq!(verify_task(&outsider), Err(Error::UnknownAuthority));
+}
+
+#[test]
+fn votes_for_descendants_need_ancestry() {
+    let chain = chain();
+    let finalized = chain.descendants.last().unwrap();
+    let descendant = header(4, finalized.hash(), H256::repeat_byte(4));
+    let voters = [
+        Ed25519Keyring::Alice,
+        Ed25519Keyring::Bob,
+        Ed25519Keyring::Charlie,
+    ];
+
+    let with_ancestry = certificate(
+        &chain,
+        justification(
+            finalized,
+            &descendant,
+            &voters,
+            SET_ID,
+            vec![descendant.clone()],
+        ),
+    );
+    assert_eq!(verify_task(&with_ancestry), Ok(TASK));
+
+    let without_ancestry = certificate(
+        &chain,
+        justification(finalized, &descendant, &voters, SET_ID, vec![]),
+    );
+    assert_eq!(
+        verify_task(&without_ancestry),
+        Err(Error::InvalidVoteAncestry)
+    );
+}
+
+#[test]
+fn header_must_lead_to_the_finalized_block() {
+    let mut certificate = valid_certificate();
+    let mut proof = FinalityProof::<Header>::decode(&mut &certificate.finality_proof[..]).unwrap();
+
+    // 去掉中间的区块头后无法连接
+    proof.unknown_headers.remove(0);
+    certificate.finality_proof = proof.encode().into();
+
+    assert_eq!(verify_task(&certificate), Err(Error::InvalidHeaderChain));
+}
+
+#[test]
+fn storage_proof_is_checked_against_the_state_root() {
+    let valid = valid_certificate();
+
+    let mut tampered = valid.clone();
+    tampered.task = (3u64, true).encode().into();
+    assert_eq!(verify_task(&tampered), Err(Error::TaskMismatch));
+
+    let mut other_task = valid.clone();
+    other_task.task_id = TASK_ID + 1;
+    assert_eq!(verify_task(&other_task), Err(Error::TaskNotFound));
+
+    let mut missing_nodes = valid;
+    missing_nodes.storage_proof.clear();
+    assert_eq!(verify_task(&missing_nodes), Err(Error::InvalidStorageProof));
+}
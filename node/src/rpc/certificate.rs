@@ -0,0 +1,116 @@
+//! `tasks_completionCertificate` RPC method.
+//!
+//! A certificate proves that a task was completed on this chain: it carries the `Tasks` storage
+//! entry read at the latest finalized block, a storage read proof against that block's state root
+//! and a GRANDPA finality proof for the block. Outside parties check it with
+//! `task_certificate::verify`, without running a node.
+
+use std::sync::Arc;
+
+use codec::Decode;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_tasks::{Task, TaskStatus};
+use sc_client_api::{Backend, ProofProvider, StorageProvider};
+use sc_consensus_grandpa::FinalityProofProvider;
+use solochain_template_runtime::{opaque::Block, Runtime};
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+use sp_runtime::traits::Block as BlockT;
+use task_certificate::{task_storage_key, CompletionCertificate};
+
+/// Error code returned when reading the chain or building a proof fails.
+const PROOF_ERROR: i32 = 1;
+/// Error code returned when the task does not exist at the finalized block.
+const TASK_NOT_FOUND: i32 = 2;
+/// Error code returned when the task is not completed at the finalized block.
+const TASK_NOT_COMPLETED: i32 = 3;
+/// Error code returned when no GRANDPA justification covers the finalized block yet.
+const NOT_JUSTIFIED: i32 = 4;
+
+/// Completion certificate RPC methods.
+#[rpc(server)]
+pub trait CertificateApi<Header> {
+	/// Get a completion certificate for `task_id` at the latest finalized block.
+	#[method(name = "tasks_completionCertificate")]
+	fn completion_certificate(&self, task_id: u32) -> RpcResult<CompletionCertificate<Header>>;
+}
+
+/// Provides the `tasks_completionCertificate` RPC method.
+pub struct Certificate<C, B> {
+	client: Arc<C>,
+	finality_proof_provider: Arc<FinalityProofProvider<B, Block>>,
+}
+
+impl<C, B> Certificate<C, B> {
+	/// Create a new `Certificate` RPC handler.
+	pub fn new(
+		client: Arc<C>,
+		finality_proof_provider: Arc<FinalityProofProvider<B, Block>>,
+	) -> Self {
+		Self { client, finality_proof_provider }
+	}
+}
+
+fn error(code: i32, message: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(code, message, None::<()>)
+}
+
+fn proof_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(PROOF_ERROR, "Unable to build certificate.", Some(format!("{:?}", err)))
+}
+
+impl<C, B> CertificateApiServer<<Block as BlockT>::Header> for Certificate<C, B>
+where
+	B: Backend<Block> + Send + Sync + 'static,
+	C: HeaderBackend<Block> + StorageProvider<Block, B> + ProofProvider<Block>,
+	C: Send + Sync + 'static,
+{
+	fn completion_certificate(
+		&self,
+		task_id: u32,
+	) -> RpcResult<CompletionCertificate<<Block as BlockT>::Header>> {
+		let info = self.client.info();
+		let hash = info.finalized_hash;
+		let key = task_storage_key(task_id);
+
+		let task =
+			self.client.storage(hash, &StorageKey(key.clone())).map_err(proof_error)?.ok_or_else(
+				|| error(TASK_NOT_FOUND, "Task does not exist at the finalized block."),
+			)?;
+		let status = Task::<Runtime>::decode(&mut &task.0[..]).map_err(proof_error)?.status;
+		if status != TaskStatus::Completed {
+			return Err(error(TASK_NOT_COMPLETED, "Task is not completed at the finalized block."));
+		}
+
+		let header = self
+			.client
+			.header(hash)
+			.map_err(proof_error)?
+			.ok_or_else(|| proof_error("finalized header is missing"))?;
+		let storage_proof = self
+			.client
+			.read_proof(hash, &mut std::iter::once(key.as_slice()))
+			.map_err(proof_error)?;
+		// Justifications are only kept periodically, so the proof may finalize a later block and
+		// link back to this one through its headers.
+		let finality_proof = self
+			.finality_proof_provider
+			.prove_finality(info.finalized_number)
+			.map_err(proof_error)?
+			.ok_or_else(|| {
+				error(NOT_JUSTIFIED, "No GRANDPA justification covers the finalized block yet.")
+			})?;
+
+		Ok(CompletionCertificate {
+			task_id,
+			header,
+			task: task.0.into(),
+			storage_proof: storage_proof.into_iter_nodes().map(Into::into).collect(),
+			finality_proof: finality_proof.into(),
+		})
+	}
+}
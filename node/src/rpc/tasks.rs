@@ -0,0 +1,322 @@
+//! `tasks_*` RPC methods backed by the `TasksApi` runtime API.
+//!
+//! Tasks are returned as decoded JSON: titles and descriptions are UTF-8 strings and accounts are
+//! SS58 addresses, so clients do not need the runtime metadata to read them.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_tasks::{
+	AccountTaskStats, Priority, Task, TaskStatistics, TaskStatus, VerificationOutcome,
+};
+use serde::{Deserialize, Serialize};
+use solochain_template_runtime::{apis::TasksApi as TasksRuntimeApi, AccountId, Balance, Runtime};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, Percent, Permill};
+
+/// Page size used when the caller does not pass a `limit`.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+/// Upper bound on the page size a caller may request.
+const MAX_PAGE_SIZE: u32 = 200;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// A task as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskInfo {
+	/// Task id.
+	pub id: u32,
+	/// Account that created the task.
+	pub creator: AccountId,
+	/// Account the task is assigned to, if any.
+	pub assignee: Option<AccountId>,
+	/// Title, lossily decoded as UTF-8.
+	pub title: String,
+	/// Description, lossily decoded as UTF-8.
+	pub description: String,
+	/// Current status.
+	pub status: TaskStatus,
+	/// Priority.
+	pub priority: Priority,
+	/// Difficulty from 1 to 10.
+	pub difficulty: u8,
+	/// Escrowed reward.
+	pub reward: Balance,
+	/// Creation time in milliseconds since the Unix epoch.
+	pub created_at: u64,
+	/// Last update time in milliseconds since the Unix epoch.
+	pub updated_at: u64,
+	/// Deadline in milliseconds since the Unix epoch, if any.
+	pub deadline: Option<u64>,
+	/// Parent task id, if this is a subtask.
+	pub parent: Option<u32>,
+	/// Result of the latest verification round, if the task was ever verified.
+	pub verification: Option<VerificationOutcome>,
+	/// Time the task was completed, cancelled or expired, in milliseconds since the Unix epoch.
+	pub closed_at: Option<u64>,
+	/// Time the task was last submitted for verification, in milliseconds since the Unix epoch.
+	pub submitted_at: Option<u64>,
+	/// Whether any account may claim the task while it is unassigned.
+	pub open: bool,
+	/// Bond held from the assignee, released on on-time completion and slashed otherwise.
+	pub bond: Balance,
+	/// Co-assignees and their share of the reward; the assignee receives the rest.
+	pub co_assignees: Vec<(AccountId, Percent)>,
+	/// Organisation the task belongs to, if any.
+	pub org: Option<u32>,
+}
+
+impl From<Task<Runtime>> for TaskInfo {
+	fn from(task: Task<Runtime>) -> Self {
+		Self {
+			id: task.id,
+			creator: task.creator,
+			assignee: task.assignee,
+			title: String::from_utf8_lossy(&task.title).into_owned(),
+			description: String::from_utf8_lossy(&task.description).into_owned(),
+			status: task.status,
+			priority: task.priority,
+			difficulty: task.difficulty,
+			reward: task.reward,
+			created_at: task.created_at,
+			updated_at: task.updated_at,
+			deadline: task.deadline,
+			parent: task.parent,
+			verification: task.verification,
+			closed_at: task.closed_at,
+			submitted_at: task.submitted_at,
+			open: task.open,
+			bond: task.bond,
+			co_assignees: task.co_assignees.into_inner(),
+			org: task.org,
+		}
+	}
+}
+
+/// Lifetime task statistics of an account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStatistics {
+	/// Raw counters kept by the pallet.
+	#[serde(flatten)]
+	pub stats: AccountTaskStats,
+	/// Share of completed tasks that met their deadline, in parts per million.
+	pub on_time_rate: Permill,
+}
+
+/// Task query RPC methods.
+#[rpc(server)]
+pub trait TasksApi<BlockHash> {
+	/// Get a task by id.
+	#[method(name = "tasks_getTask")]
+	fn task(&self, task_id: u32, at: Option<BlockHash>) -> RpcResult<Option<TaskInfo>>;
+
+	/// List tasks created by `creator`.
+	#[method(name = "tasks_tasksByCreator")]
+	fn tasks_by_creator(
+		&self,
+		creator: AccountId,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TaskInfo>>;
+
+	/// List tasks assigned to `assignee`.
+	#[method(name = "tasks_tasksByAssignee")]
+	fn tasks_by_assignee(
+		&self,
+		assignee: AccountId,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TaskInfo>>;
+
+	/// List tasks currently in `status`, ordered by task id.
+	#[method(name = "tasks_tasksByStatus")]
+	fn tasks_by_status(
+		&self,
+		status: TaskStatus,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TaskInfo>>;
+
+	/// List open, unassigned tasks that any account can claim, ordered by task id.
+	#[method(name = "tasks_claimableTasks")]
+	fn claimable_tasks(
+		&self,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TaskInfo>>;
+
+	/// List the direct subtasks of `task_id`.
+	#[method(name = "tasks_subtasks")]
+	fn subtasks(&self, task_id: u32, at: Option<BlockHash>) -> RpcResult<Vec<TaskInfo>>;
+
+	/// Get the share of completed direct subtasks of `task_id`, in percent.
+	#[method(name = "tasks_progress")]
+	fn progress(&self, task_id: u32, at: Option<BlockHash>) -> RpcResult<Option<Percent>>;
+
+	/// Check whether `task_id` is still open and past its deadline.
+	#[method(name = "tasks_isOverdue")]
+	fn is_overdue(&self, task_id: u32, at: Option<BlockHash>) -> RpcResult<Option<bool>>;
+
+	/// Get global task counts by status, priority and difficulty band.
+	#[method(name = "tasks_statistics")]
+	fn statistics(&self, at: Option<BlockHash>) -> RpcResult<TaskStatistics>;
+
+	/// Get the lifetime task statistics of `account`.
+	#[method(name = "tasks_accountStatistics")]
+	fn account_statistics(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<AccountStatistics>;
+}
+
+/// Provides the `tasks_*` RPC methods.
+pub struct Tasks<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Tasks<C, Block> {
+	/// Create a new `Tasks` RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> Tasks<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	fn at(&self, at: Option<Block::Hash>) -> Block::Hash {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+fn page(offset: Option<u32>, limit: Option<u32>) -> (u32, u32) {
+	(offset.unwrap_or(0), limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE))
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query tasks.", Some(format!("{:?}", err)))
+}
+
+fn into_info(tasks: Vec<Task<Runtime>>) -> Vec<TaskInfo> {
+	tasks.into_iter().map(Into::into).collect()
+}
+
+impl<C, Block> TasksApiServer<<Block as BlockT>::Hash> for Tasks<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TasksRuntimeApi<Block, AccountId, Task<Runtime>>,
+{
+	fn task(&self, task_id: u32, at: Option<Block::Hash>) -> RpcResult<Option<TaskInfo>> {
+		let task = self.client.runtime_api().task(self.at(at), task_id).map_err(runtime_error)?;
+		Ok(task.map(Into::into))
+	}
+
+	fn tasks_by_creator(
+		&self,
+		creator: AccountId,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<TaskInfo>> {
+		let (offset, limit) = page(offset, limit);
+		self.client
+			.runtime_api()
+			.tasks_by_creator(self.at(at), creator, offset, limit)
+			.map(into_info)
+			.map_err(runtime_error)
+	}
+
+	fn tasks_by_assignee(
+		&self,
+		assignee: AccountId,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<TaskInfo>> {
+		let (offset, limit) = page(offset, limit);
+		self.client
+			.runtime_api()
+			.tasks_by_assignee(self.at(at), assignee, offset, limit)
+			.map(into_info)
+			.map_err(runtime_error)
+	}
+
+	fn tasks_by_status(
+		&self,
+		status: TaskStatus,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<TaskInfo>> {
+		let (offset, limit) = page(offset, limit);
+		self.client
+			.runtime_api()
+			.tasks_by_status(self.at(at), status, offset, limit)
+			.map(into_info)
+			.map_err(runtime_error)
+	}
+
+	fn claimable_tasks(
+		&self,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<TaskInfo>> {
+		let (offset, limit) = page(offset, limit);
+		self.client
+			.runtime_api()
+			.claimable_tasks(self.at(at), offset, limit)
+			.map(into_info)
+			.map_err(runtime_error)
+	}
+
+	fn subtasks(&self, task_id: u32, at: Option<Block::Hash>) -> RpcResult<Vec<TaskInfo>> {
+		self.client
+			.runtime_api()
+			.subtasks(self.at(at), task_id)
+			.map(into_info)
+			.map_err(runtime_error)
+	}
+
+	fn progress(&self, task_id: u32, at: Option<Block::Hash>) -> RpcResult<Option<Percent>> {
+		self.client.runtime_api().task_progress(self.at(at), task_id).map_err(runtime_error)
+	}
+
+	fn is_overdue(&self, task_id: u32, at: Option<Block::Hash>) -> RpcResult<Option<bool>> {
+		self.client.runtime_api().task_overdue(self.at(at), task_id).map_err(runtime_error)
+	}
+
+	fn statistics(&self, at: Option<Block::Hash>) -> RpcResult<TaskStatistics> {
+		self.client.runtime_api().task_statistics(self.at(at)).map_err(runtime_error)
+	}
+
+	fn account_statistics(
+		&self,
+		account: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<AccountStatistics> {
+		let stats = self
+			.client
+			.runtime_api()
+			.account_statistics(self.at(at), account)
+			.map_err(runtime_error)?;
+		Ok(AccountStatistics { on_time_rate: stats.on_time_rate(), stats })
+	}
+}
@@ -0,0 +1,145 @@
+//! Benchmarking setup for pallet-achievements
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Achievements;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use pallet_tasks::{Priority, TaskLifecycleHandler, TaskStatus, TaskSummary};
+use sp_std::vec;
+
+/// 创建 `n` 个生效中的徽章
+fn create_badges<T: Config>(n: u32) -> Result<(), BenchmarkError> {
+    for i in 0..n {
+        Achievements::<T>::do_create_badge(vec![b'a'; 8], BadgeCriterion::Completions(i + 1))
+            .map_err(|_| BenchmarkError::Stop("failed to create badge"))?;
+    }
+    Ok(())
+}
+
+/// 创建 `n` 个长度为一的连续完成徽章，完成任意任务都会全部颁发
+fn create_streak_badges<T: Config>(n: u32) -> Result<(), BenchmarkError> {
+    for _ in 0..n {
+        Achievements::<T>::do_create_badge(
+            vec![b'a'; 8],
+            BadgeCriterion::DifficultyStreak {
+                min_difficulty: 1,
+                length: 1,
+            },
+        )
+        .map_err(|_| BenchmarkError::Stop("failed to create badge"))?;
+    }
+    Ok(())
+}
+
+fn task_summary<T: Config>() -> TaskSummary<T::AccountId> {
+    TaskSummary {
+        task_id: 0,
+        assignee: account("assignee", 0, 0),
+        priority: Priority::Urgent,
+        difficulty: 10,
+        rewarded: true,
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    // 最坏情况：生效的徽章列表只剩一个空位
+    #[benchmark]
+    fn create_badge(n: Linear<0, { T::MaxNameLength::get() }>) -> Result<(), BenchmarkError> {
+        create_badges::<T>(T::MaxBadges::get() - 1)?;
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            vec![b'a'; n as usize],
+            BadgeCriterion::UrgentOnTime(1),
+        );
+
+        assert_eq!(ActiveBadges::<T>::get().len() as u32, T::MaxBadges::get());
+        Ok(())
+    }
+
+    // 要停用的徽章位于列表末尾
+    #[benchmark]
+    fn retire_badge(b: Linear<1, { T::MaxBadges::get() }>) -> Result<(), BenchmarkError> {
+        create_badges::<T>(b)?;
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, b - 1);
+
+        assert_eq!(ActiveBadges::<T>::get().len() as u32, b - 1);
+        Ok(())
+    }
+
+    // 最坏情况：每个徽章都要更新连续次数并颁发
+    #[benchmark]
+    fn on_task_completed(b: Linear<0, { T::MaxBadges::get() }>) -> Result<(), BenchmarkError> {
+        create_streak_badges::<T>(b)?;
+        let summary = task_summary::<T>();
+
+        #[block]
+        {
+            <Achievements<T> as TaskLifecycleHandler<T::AccountId>>::on_task_completed(
+                &summary, true,
+            );
+        }
+
+        assert_eq!(
+            Awarded::<T>::iter_prefix(&summary.assignee).count() as u32,
+            b
+        );
+        Ok(())
+    }
+
+    // 最坏情况：每个徽章都有需要清除的连续次数
+    #[benchmark]
+    fn on_task_cancelled_by_assignee(
+        b: Linear<0, { T::MaxBadges::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        create_streak_badges::<T>(b)?;
+        let summary = task_summary::<T>();
+        for (badge_id, _) in ActiveBadges::<T>::get() {
+            Streaks::<T>::insert(&summary.assignee, badge_id, 1);
+        }
+
+        #[block]
+        {
+            <Achievements<T> as TaskLifecycleHandler<T::AccountId>>::on_task_cancelled_by_assignee(
+                &summary,
+            );
+        }
+
+        assert_eq!(Streaks::<T>::iter_prefix(&summary.assignee).count(), 0);
+        Ok(())
+    }
+
+    // 最坏情况：重新打开的是按时完成的紧急任务，且每个徽章都有需要清除的连续次数
+    #[benchmark]
+    fn on_task_reopened(b: Linear<0, { T::MaxBadges::get() }>) -> Result<(), BenchmarkError> {
+        create_streak_badges::<T>(b)?;
+        let summary = task_summary::<T>();
+        <Achievements<T> as TaskLifecycleHandler<T::AccountId>>::on_task_completed(&summary, true);
+
+        #[block]
+        {
+            <Achievements<T> as TaskLifecycleHandler<T::AccountId>>::on_task_reopened(
+                &summary,
+                &TaskStatus::Completed,
+                true,
+            );
+        }
+
+        assert_eq!(Streaks::<T>::iter_prefix(&summary.assignee).count(), 0);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Achievements, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,409 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// 成就徽章 Pallet
+///
+/// 订阅任务 pallet 的生命周期回调，在账户达到里程碑时自动颁发成就徽章：
+/// - 累计完成一定数量的任务
+/// - 按时完成一定数量的紧急任务
+/// - 连续完成一定数量的高难度任务
+///
+/// 徽章定义由治理来源创建和停用。徽章与账户绑定，不能转让，颁发后也不会收回。
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use codec::{Decode, DecodeWithMemTracking, Encode};
+    use frame_support::{pallet_prelude::*, traits::BuildGenesisConfig};
+    use frame_system::pallet_prelude::*;
+    use pallet_tasks::{
+        Priority, TaskLifecycleHandler, TaskStatus, TaskSummary, VerificationVerdict,
+    };
+    use scale_info::TypeInfo;
+    use serde::{Deserialize, Serialize};
+    use sp_runtime::Saturating;
+    use sp_std::vec::Vec;
+
+    /// 获得徽章的条件
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub enum BadgeCriterion {
+        /// 累计完成的任务数达到 `n`
+        Completions(u32),
+        /// 按时完成的紧急任务数达到 `n`
+        UrgentOnTime(u32),
+        /// 连续完成 `length` 个难度不低于 `min_difficulty` 的任务
+        ///
+        /// 完成一个更简单的任务或主动取消任务都会中断连续记录。
+        DifficultyStreak { min_difficulty: u8, length: u32 },
+    }
+
+    impl BadgeCriterion {
+        /// 条件是否有效：数量不能为零，难度必须在 1-10 之间
+        pub fn is_valid(&self) -> bool {
+            match self {
+                BadgeCriterion::Completions(n) | BadgeCriterion::UrgentOnTime(n) => *n > 0,
+                BadgeCriterion::DifficultyStreak {
+                    min_difficulty,
+                    length,
+                } => (1..=10).contains(min_difficulty) && *length > 0,
+            }
+        }
+    }
+
+    /// 徽章定义
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Badge<T: Config> {
+        /// 徽章名称
+        pub name: BoundedVec<u8, T::MaxNameLength>,
+        /// 获得条件
+        pub criterion: BadgeCriterion,
+        /// 已停用的徽章不再颁发，已颁发的保持不变
+        pub active: bool,
+    }
+
+    /// 账户持有的徽章
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AwardedBadge<T: Config> {
+        /// 达成里程碑的任务
+        pub task_id: u32,
+        /// 颁发时的区块
+        pub awarded_at: BlockNumberFor<T>,
+    }
+
+    /// 账户的里程碑进度
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct AchievementProgress {
+        /// 完成的任务数
+        pub completed: u32,
+        /// 按时完成的紧急任务数
+        pub urgent_on_time: u32,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// 事件类型
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// 可以创建和停用徽章的治理来源
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// 同时生效的徽章数量上限，每次完成任务都会检查全部生效的徽章
+        #[pallet::constant]
+        type MaxBadges: Get<u32>;
+
+        /// 徽章名称最大长度
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        /// 权重信息
+        type WeightInfo: WeightInfo;
+    }
+
+    /// 下一个徽章ID
+    #[pallet::storage]
+    pub type NextBadgeId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 全部徽章定义，包括已停用的
+    #[pallet::storage]
+    pub type Badges<T: Config> = StorageMap<_, Twox64Concat, u32, Badge<T>, OptionQuery>;
+
+    /// 生效中的徽章及其条件，完成任务时逐一检查
+    #[pallet::storage]
+    pub type ActiveBadges<T: Config> =
+        StorageValue<_, BoundedVec<(u32, BadgeCriterion), T::MaxBadges>, ValueQuery>;
+
+    /// 账户的里程碑进度
+    #[pallet::storage]
+    pub type Progress<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, AchievementProgress, ValueQuery>;
+
+    /// 账户在各个连续完成徽章上的当前连续次数
+    #[pallet::storage]
+    pub type Streaks<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u32, u32, ValueQuery>;
+
+    /// 账户持有的徽章
+    #[pallet::storage]
+    pub type Awarded<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        AwardedBadge<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// 创世徽章：(名称, 获得条件)
+        pub badges: Vec<(Vec<u8>, BadgeCriterion)>,
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (name, criterion) in &self.badges {
+                Pallet::<T>::do_create_badge(name.clone(), criterion.clone())
+                    .expect("genesis badges must be valid");
+            }
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// 已创建徽章
+        BadgeCreated {
+            badge_id: u32,
+            criterion: BadgeCriterion,
+        },
+        /// 徽章已停用，不再颁发
+        BadgeRetired { badge_id: u32 },
+        /// 账户获得徽章
+        BadgeAwarded {
+            who: T::AccountId,
+            badge_id: u32,
+            task_id: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// 徽章名称过长
+        NameTooLong,
+        /// 获得条件无效
+        InvalidCriterion,
+        /// 生效的徽章数量达到上限
+        TooManyBadges,
+        /// 徽章不存在
+        BadgeNotFound,
+        /// 徽章已停用
+        AlreadyRetired,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// 创建徽章
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_badge(name.len() as u32))]
+        pub fn create_badge(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            criterion: BadgeCriterion,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::do_create_badge(name, criterion)?;
+            Ok(())
+        }
+
+        /// 停用徽章，已颁发的徽章保持不变
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::retire_badge(T::MaxBadges::get()))]
+        pub fn retire_badge(origin: OriginFor<T>, badge_id: u32) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let mut badge = Badges::<T>::get(badge_id).ok_or(Error::<T>::BadgeNotFound)?;
+            ensure!(badge.active, Error::<T>::AlreadyRetired);
+            badge.active = false;
+            Badges::<T>::insert(badge_id, badge);
+
+            let active = ActiveBadges::<T>::mutate(|badges| {
+                let len = badges.len() as u32;
+                badges.retain(|(id, _)| *id != badge_id);
+                len
+            });
+
+            Self::deposit_event(Event::BadgeRetired { badge_id });
+
+            Ok(Some(T::WeightInfo::retire_badge(active)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 创建徽章的核心逻辑，供 `create_badge` 和创世配置共用，返回新徽章ID
+        pub(crate) fn do_create_badge(
+            name: Vec<u8>,
+            criterion: BadgeCriterion,
+        ) -> Result<u32, DispatchError> {
+            let name: BoundedVec<u8, T::MaxNameLength> =
+                name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+            ensure!(criterion.is_valid(), Error::<T>::InvalidCriterion);
+
+            let badge_id = NextBadgeId::<T>::get();
+            ActiveBadges::<T>::try_mutate(|badges| {
+                badges
+                    .try_push((badge_id, criterion.clone()))
+                    .map_err(|_| Error::<T>::TooManyBadges)
+            })?;
+            Badges::<T>::insert(
+                badge_id,
+                Badge {
+                    name,
+                    criterion: criterion.clone(),
+                    active: true,
+                },
+            );
+            NextBadgeId::<T>::put(badge_id.saturating_add(1));
+
+            Self::deposit_event(Event::BadgeCreated {
+                badge_id,
+                criterion,
+            });
+
+            Ok(badge_id)
+        }
+
+        /// 账户持有的全部徽章ID
+        pub fn badges_of(who: &T::AccountId) -> Vec<u32> {
+            Awarded::<T>::iter_key_prefix(who).collect()
+        }
+
+        /// 中断账户在全部连续完成类徽章上的连续次数
+        fn reset_streaks(who: &T::AccountId) {
+            for (badge_id, criterion) in ActiveBadges::<T>::get() {
+                if matches!(criterion, BadgeCriterion::DifficultyStreak { .. }) {
+                    Streaks::<T>::remove(who, badge_id);
+                }
+            }
+        }
+
+        /// 颁发徽章，已持有的徽章不会重复颁发
+        fn award(who: &T::AccountId, badge_id: u32, task_id: u32) {
+            if Awarded::<T>::contains_key(who, badge_id) {
+                return;
+            }
+            Awarded::<T>::insert(
+                who,
+                badge_id,
+                AwardedBadge {
+                    task_id,
+                    awarded_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::BadgeAwarded {
+                who: who.clone(),
+                badge_id,
+                task_id,
+            });
+        }
+    }
+
+    impl<T: Config> TaskLifecycleHandler<T::AccountId> for Pallet<T> {
+        fn on_task_completed(summary: &TaskSummary<T::AccountId>, on_time: bool) {
+            let who = &summary.assignee;
+            let urgent_on_time = on_time && summary.priority == Priority::Urgent;
+            let progress = Progress::<T>::mutate(who, |progress| {
+                progress.completed.saturating_inc();
+                if urgent_on_time {
+                    progress.urgent_on_time.saturating_inc();
+                }
+                progress.clone()
+            });
+
+            for (badge_id, criterion) in ActiveBadges::<T>::get() {
+                let earned = match criterion {
+                    BadgeCriterion::Completions(n) => progress.completed >= n,
+                    BadgeCriterion::UrgentOnTime(n) => progress.urgent_on_time >= n,
+                    BadgeCriterion::DifficultyStreak {
+                        min_difficulty,
+                        length,
+                    } => {
+                        if summary.difficulty >= min_difficulty {
+                            let streak = Streaks::<T>::mutate(who, badge_id, |streak| {
+                                streak.saturating_inc();
+                                *streak
+                            });
+                            streak >= length
+                        } else {
+                            Streaks::<T>::remove(who, badge_id);
+                            false
+                        }
+                    }
+                };
+                if earned {
+                    Self::award(who, badge_id, summary.task_id);
+                }
+            }
+        }
+
+        fn on_verification_concluded(
+            _summary: &TaskSummary<T::AccountId>,
+            _verdict: &VerificationVerdict,
+        ) {
+        }
+
+        fn on_task_cancelled_by_assignee(summary: &TaskSummary<T::AccountId>) {
+            Self::reset_streaks(&summary.assignee);
+        }
+
+        // 撤销完成计数并中断连续次数，防止反复重新打开和完成同一个任务刷进度或连续次数；
+        // 已颁发的徽章保持不变
+        fn on_task_reopened(
+            summary: &TaskSummary<T::AccountId>,
+            previous_status: &TaskStatus,
+            on_time: bool,
+        ) {
+            if *previous_status != TaskStatus::Completed {
+                return;
+            }
+            let urgent_on_time = on_time && summary.priority == Priority::Urgent;
+            Progress::<T>::mutate(&summary.assignee, |progress| {
+                progress.completed.saturating_dec();
+                if urgent_on_time {
+                    progress.urgent_on_time.saturating_dec();
+                }
+            });
+            Self::reset_streaks(&summary.assignee);
+        }
+
+        fn on_task_deleted(_task_id: u32) {}
+
+        // 这些回调都会遍历全部生效的徽章，按徽章数量上限计费
+        fn on_task_completed_weight() -> Weight {
+            T::WeightInfo::on_task_completed(T::MaxBadges::get())
+        }
+
+        fn on_task_cancelled_by_assignee_weight() -> Weight {
+            T::WeightInfo::on_task_cancelled_by_assignee(T::MaxBadges::get())
+        }
+
+        fn on_task_reopened_weight() -> Weight {
+            T::WeightInfo::on_task_reopened(T::MaxBadges::get())
+        }
+    }
+}
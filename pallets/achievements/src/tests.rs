@@ -0,0 +1,411 @@
+use crate::{
+    mock::*, AchievementProgress, ActiveBadges, Awarded, BadgeCriterion, Badges, Error, Event,
+    GenesisConfig, Progress, Streaks, WeightInfo,
+};
+use frame_support::{assert_noop, assert_ok};
+use pallet_tasks::{NextTaskId, Priority, TaskLifecycleHandler, TaskStatus};
+use sp_runtime::{BuildStorage, DispatchError};
+
+fn create_badge(criterion: BadgeCriterion) -> u32 {
+    let badge_id = crate::NextBadgeId::<Test>::get();
+    assert_ok!(Achievements::create_badge(
+        RuntimeOrigin::root(),
+        b"badge".to_vec(),
+        criterion
+    ));
+    badge_id
+}
+
+/// 创建任务并分配给 BOB
+fn create_assigned_task(priority: Priority, difficulty: u8, deadline: Option<u64>) -> u32 {
+    let task_id = NextTaskId::<Test>::get();
+    assert_ok!(Tasks::create_task(
+        RuntimeOrigin::signed(ALICE),
+        b"title".to_vec(),
+        vec![],
+        priority,
+        difficulty,
+        0,
+        deadline,
+    ));
+    assign_to_bob(task_id);
+    task_id
+}
+
+/// 将任务分配给 BOB，重新打开的任务需要重新分配
+fn assign_to_bob(task_id: u32) {
+    assert_ok!(Tasks::assign_task(
+        RuntimeOrigin::signed(ALICE),
+        task_id,
+        BOB,
+        0
+    ));
+    assert_ok!(Tasks::accept_assignment(
+        RuntimeOrigin::signed(BOB),
+        task_id
+    ));
+}
+
+fn set_status(who: u64, task_id: u32, status: TaskStatus) {
+    assert_ok!(Tasks::change_task_status(
+        RuntimeOrigin::signed(who),
+        task_id,
+        status
+    ));
+}
+
+/// 创建、分配并由 BOB 完成任务
+fn complete_task(priority: Priority, difficulty: u8) -> u32 {
+    let task_id = create_assigned_task(priority, difficulty, None);
+    set_status(ALICE, task_id, TaskStatus::Completed);
+    task_id
+}
+
+fn has_badge(who: u64, badge_id: u32) -> bool {
+    Awarded::<Test>::contains_key(who, badge_id)
+}
+
+#[test]
+fn create_badge_works() {
+    new_test_ext().execute_with(|| {
+        let criterion = BadgeCriterion::Completions(10);
+        assert_ok!(Achievements::create_badge(
+            RuntimeOrigin::root(),
+            b"Ten tasks".to_vec(),
+            criterion.clone()
+        ));
+
+        let badge = Badges::<Test>::get(0).expect("badge exists");
+        assert_eq!(badge.name.to_vec(), b"Ten tasks".to_vec());
+        assert_eq!(badge.criterion, criterion);
+        assert!(badge.active);
+        assert_eq!(
+            ActiveBadges::<Test>::get().to_vec(),
+            vec![(0, criterion.clone())]
+        );
+        System::assert_last_event(
+            Event::BadgeCreated {
+                badge_id: 0,
+                criterion,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_badge_validates_input() {
+    new_test_ext().execute_with(|| {
+        let create = |name: &[u8], criterion| {
+            Achievements::create_badge(RuntimeOrigin::root(), name.to_vec(), criterion)
+        };
+
+        assert_noop!(
+            Achievements::create_badge(
+                RuntimeOrigin::signed(ALICE),
+                b"badge".to_vec(),
+                BadgeCriterion::Completions(1)
+            ),
+            DispatchError::BadOrigin
+        );
+        // MaxNameLength = 16
+        assert_noop!(
+            create(&[b'a'; 17], BadgeCriterion::Completions(1)),
+            Error::<Test>::NameTooLong
+        );
+        for criterion in [
+            BadgeCriterion::Completions(0),
+            BadgeCriterion::UrgentOnTime(0),
+            BadgeCriterion::DifficultyStreak {
+                min_difficulty: 0,
+                length: 3,
+            },
+            BadgeCriterion::DifficultyStreak {
+                min_difficulty: 11,
+                length: 3,
+            },
+            BadgeCriterion::DifficultyStreak {
+                min_difficulty: 8,
+                length: 0,
+            },
+        ] {
+            assert_noop!(create(b"badge", criterion), Error::<Test>::InvalidCriterion);
+        }
+
+        // MaxBadges = 4
+        for n in 1..=4 {
+            assert_ok!(create(b"badge", BadgeCriterion::Completions(n)));
+        }
+        assert_noop!(
+            create(b"badge", BadgeCriterion::Completions(5)),
+            Error::<Test>::TooManyBadges
+        );
+    });
+}
+
+#[test]
+fn retire_badge_works() {
+    new_test_ext().execute_with(|| {
+        let retired = create_badge(BadgeCriterion::Completions(1));
+        let kept = create_badge(BadgeCriterion::Completions(1));
+
+        assert_noop!(
+            Achievements::retire_badge(RuntimeOrigin::signed(ALICE), retired),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Achievements::retire_badge(RuntimeOrigin::root(), 9),
+            Error::<Test>::BadgeNotFound
+        );
+
+        assert_ok!(Achievements::retire_badge(RuntimeOrigin::root(), retired));
+        assert!(!Badges::<Test>::get(retired).expect("badge kept").active);
+        assert_eq!(ActiveBadges::<Test>::get().len(), 1);
+        System::assert_last_event(Event::BadgeRetired { badge_id: retired }.into());
+        assert_noop!(
+            Achievements::retire_badge(RuntimeOrigin::root(), retired),
+            Error::<Test>::AlreadyRetired
+        );
+
+        // 停用的徽章不再颁发，并且腾出了位置
+        complete_task(Priority::Medium, 5);
+        assert!(!has_badge(BOB, retired));
+        assert!(has_badge(BOB, kept));
+        for n in 1..=3 {
+            create_badge(BadgeCriterion::Completions(n));
+        }
+    });
+}
+
+#[test]
+fn first_completion_badge_is_awarded_once() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::Completions(1));
+
+        let task_id = complete_task(Priority::Medium, 5);
+
+        let awarded = Awarded::<Test>::get(BOB, badge_id).expect("badge awarded");
+        assert_eq!(awarded.task_id, task_id);
+        assert_eq!(awarded.awarded_at, 1);
+        assert_eq!(Achievements::badges_of(&BOB), vec![badge_id]);
+        System::assert_has_event(
+            Event::BadgeAwarded {
+                who: BOB,
+                badge_id,
+                task_id,
+            }
+            .into(),
+        );
+
+        // 已持有的徽章不会重复颁发
+        let events = System::events().len();
+        complete_task(Priority::Medium, 5);
+        assert!(System::events()[events..].iter().all(|record| !matches!(
+            record.event,
+            RuntimeEvent::Achievements(Event::BadgeAwarded { .. })
+        )));
+        assert_eq!(
+            Awarded::<Test>::get(BOB, badge_id).map(|awarded| awarded.task_id),
+            Some(task_id)
+        );
+    });
+}
+
+#[test]
+fn completion_milestone_counts_all_completions() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::Completions(3));
+
+        complete_task(Priority::Low, 1);
+        complete_task(Priority::High, 9);
+        assert!(!has_badge(BOB, badge_id));
+
+        let task_id = complete_task(Priority::Medium, 5);
+        assert_eq!(
+            Awarded::<Test>::get(BOB, badge_id).map(|awarded| awarded.task_id),
+            Some(task_id)
+        );
+        assert_eq!(
+            Progress::<Test>::get(BOB),
+            AchievementProgress {
+                completed: 3,
+                urgent_on_time: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn urgent_on_time_badge_ignores_late_and_non_urgent_tasks() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::UrgentOnTime(2));
+
+        complete_task(Priority::Urgent, 5);
+        complete_task(Priority::High, 5);
+
+        // 逾期完成的紧急任务不计入
+        let late = create_assigned_task(Priority::Urgent, 5, Some(START_TIME + 10));
+        Timestamp::set_timestamp(START_TIME + 20);
+        set_status(ALICE, late, TaskStatus::Completed);
+        assert!(!has_badge(BOB, badge_id));
+        assert_eq!(Progress::<Test>::get(BOB).urgent_on_time, 1);
+
+        complete_task(Priority::Urgent, 5);
+        assert!(has_badge(BOB, badge_id));
+    });
+}
+
+#[test]
+fn difficulty_streak_resets_on_easier_task() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::DifficultyStreak {
+            min_difficulty: 8,
+            length: 2,
+        });
+
+        complete_task(Priority::Medium, 8);
+        complete_task(Priority::Medium, 7);
+        assert_eq!(Streaks::<Test>::get(BOB, badge_id), 0);
+
+        complete_task(Priority::Medium, 9);
+        assert!(!has_badge(BOB, badge_id));
+        complete_task(Priority::Medium, 10);
+        assert!(has_badge(BOB, badge_id));
+        assert_eq!(Streaks::<Test>::get(BOB, badge_id), 2);
+    });
+}
+
+#[test]
+fn assignee_cancellation_breaks_streak() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::DifficultyStreak {
+            min_difficulty: 8,
+            length: 2,
+        });
+
+        complete_task(Priority::Medium, 8);
+        let cancelled = create_assigned_task(Priority::Medium, 8, None);
+        set_status(BOB, cancelled, TaskStatus::Cancelled);
+        assert_eq!(Streaks::<Test>::get(BOB, badge_id), 0);
+
+        complete_task(Priority::Medium, 8);
+        assert!(!has_badge(BOB, badge_id));
+    });
+}
+
+#[test]
+fn new_badges_are_awarded_on_next_completion() {
+    new_test_ext().execute_with(|| {
+        complete_task(Priority::Medium, 5);
+        complete_task(Priority::Medium, 5);
+
+        let badge_id = create_badge(BadgeCriterion::Completions(2));
+        assert!(!has_badge(BOB, badge_id));
+
+        complete_task(Priority::Medium, 5);
+        assert!(has_badge(BOB, badge_id));
+    });
+}
+
+#[test]
+fn reopened_tasks_do_not_count_twice() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::Completions(2));
+        let task_id = complete_task(Priority::Urgent, 5);
+        assert_eq!(
+            Progress::<Test>::get(BOB),
+            AchievementProgress {
+                completed: 1,
+                urgent_on_time: 1,
+            }
+        );
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert_eq!(Progress::<Test>::get(BOB), AchievementProgress::default());
+
+        assign_to_bob(task_id);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(Progress::<Test>::get(BOB).completed, 1);
+        assert!(!has_badge(BOB, badge_id));
+    });
+}
+
+#[test]
+fn reopen_complete_loop_does_not_build_streak() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::DifficultyStreak {
+            min_difficulty: 8,
+            length: 2,
+        });
+        let task_id = complete_task(Priority::Medium, 8);
+
+        for _ in 0..3 {
+            assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+            assert_eq!(Streaks::<Test>::get(BOB, badge_id), 0);
+            assign_to_bob(task_id);
+            set_status(ALICE, task_id, TaskStatus::Completed);
+            assert_eq!(Streaks::<Test>::get(BOB, badge_id), 1);
+        }
+        assert!(!has_badge(BOB, badge_id));
+    });
+}
+
+#[test]
+fn unassigned_tasks_do_not_count() {
+    new_test_ext().execute_with(|| {
+        let badge_id = create_badge(BadgeCriterion::Completions(1));
+        let task_id = NextTaskId::<Test>::get();
+        assert_ok!(Tasks::create_task(
+            RuntimeOrigin::signed(ALICE),
+            b"title".to_vec(),
+            vec![],
+            Priority::Medium,
+            5,
+            0,
+            None,
+        ));
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert!(!has_badge(ALICE, badge_id));
+        assert_eq!(Progress::<Test>::get(ALICE), AchievementProgress::default());
+    });
+}
+
+#[test]
+fn genesis_badges_are_created() {
+    let storage = GenesisConfig::<Test> {
+        badges: vec![
+            (b"First task".to_vec(), BadgeCriterion::Completions(1)),
+            (b"Firefighter".to_vec(), BadgeCriterion::UrgentOnTime(5)),
+        ],
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap();
+
+    sp_io::TestExternalities::from(storage).execute_with(|| {
+        assert_eq!(crate::NextBadgeId::<Test>::get(), 2);
+        assert_eq!(
+            Badges::<Test>::get(1).map(|badge| badge.criterion),
+            Some(BadgeCriterion::UrgentOnTime(5))
+        );
+        assert_eq!(ActiveBadges::<Test>::get().len(), 2);
+    });
+}
+
+#[test]
+fn lifecycle_weights_cover_all_badges() {
+    new_test_ext().execute_with(|| {
+        // 回调遍历全部生效的徽章，权重按徽章数量上限计算，并由任务 pallet 的元组实现累加
+        assert_eq!(
+            <Achievements as TaskLifecycleHandler<u64>>::on_task_completed_weight(),
+            <() as WeightInfo>::on_task_completed(4)
+        );
+        assert_eq!(
+            <(Achievements, ()) as TaskLifecycleHandler<u64>>::on_task_cancelled_by_assignee_weight(
+            ),
+            <() as WeightInfo>::on_task_cancelled_by_assignee(4)
+        );
+    });
+}
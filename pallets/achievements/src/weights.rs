@@ -0,0 +1,132 @@
+//! Placeholder weights for pallet_achievements
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI. Database reads/writes follow the storage items listed
+//! above each function, but execution times have not been measured. The benchmarks in
+//! `benchmarking.rs` are complete; run `scripts/benchmark-weights.sh achievements` on reference
+//! hardware to replace this file with generated weights before the runtime is deployed.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_achievements.
+pub trait WeightInfo {
+	fn create_badge(n: u32, ) -> Weight;
+	fn retire_badge(b: u32, ) -> Weight;
+	fn on_task_completed(b: u32, ) -> Weight;
+	fn on_task_cancelled_by_assignee(b: u32, ) -> Weight;
+	fn on_task_reopened(b: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_achievements, scaled by the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Achievements NextBadgeId (r:1 w:1)
+	/// Proof: Achievements NextBadgeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Achievements ActiveBadges (r:1 w:1)
+	/// Proof: Achievements ActiveBadges (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// Storage: Achievements Badges (r:0 w:1)
+	/// Proof: Achievements Badges (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn create_badge(n: u32, ) -> Weight {
+		Weight::from_parts(14_800_000, 1806)
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Achievements Badges (r:1 w:1)
+	/// Proof: Achievements Badges (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Achievements ActiveBadges (r:1 w:1)
+	/// Proof: Achievements ActiveBadges (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 32]`.
+	fn retire_badge(b: u32, ) -> Weight {
+		Weight::from_parts(15_600_000, 3537)
+			.saturating_add(Weight::from_parts(85_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Achievements Progress (r:1 w:1)
+	/// Proof: Achievements Progress (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Achievements ActiveBadges (r:1 w:0)
+	/// Proof: Achievements ActiveBadges (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// Storage: Achievements Streaks (r:32 w:32)
+	/// Proof: Achievements Streaks (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Achievements Awarded (r:32 w:32)
+	/// Proof: Achievements Awarded (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 32]`.
+	fn on_task_completed(b: u32, ) -> Weight {
+		Weight::from_parts(11_200_000, 3521)
+			.saturating_add(Weight::from_parts(14_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 5082).saturating_mul(b.into()))
+	}
+	/// Storage: Achievements ActiveBadges (r:1 w:0)
+	/// Proof: Achievements ActiveBadges (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// Storage: Achievements Streaks (r:0 w:32)
+	/// Proof: Achievements Streaks (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 32]`.
+	fn on_task_cancelled_by_assignee(b: u32, ) -> Weight {
+		Weight::from_parts(4_300_000, 1806)
+			.saturating_add(Weight::from_parts(3_200_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: Achievements Progress (r:1 w:1)
+	/// Proof: Achievements Progress (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Achievements ActiveBadges (r:1 w:0)
+	/// Proof: Achievements ActiveBadges (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// Storage: Achievements Streaks (r:0 w:32)
+	/// Proof: Achievements Streaks (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 32]`.
+	fn on_task_reopened(b: u32, ) -> Weight {
+		Weight::from_parts(9_100_000, 3521)
+			.saturating_add(Weight::from_parts(3_200_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_badge(n: u32, ) -> Weight {
+		Weight::from_parts(14_800_000, 1806)
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn retire_badge(b: u32, ) -> Weight {
+		Weight::from_parts(15_600_000, 3537)
+			.saturating_add(Weight::from_parts(85_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn on_task_completed(b: u32, ) -> Weight {
+		Weight::from_parts(11_200_000, 3521)
+			.saturating_add(Weight::from_parts(14_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 5082).saturating_mul(b.into()))
+	}
+	fn on_task_cancelled_by_assignee(b: u32, ) -> Weight {
+		Weight::from_parts(4_300_000, 1806)
+			.saturating_add(Weight::from_parts(3_200_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	fn on_task_reopened(b: u32, ) -> Weight {
+		Weight::from_parts(9_100_000, 3521)
+			.saturating_add(Weight::from_parts(3_200_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+}
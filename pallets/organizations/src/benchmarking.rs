@@ -0,0 +1,91 @@
+//! Benchmarking setup for pallet-organizations
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Organizations;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_tasks::OrgRole;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// 以 `owner` 为所有者创建组织，成员数量补足到只剩一个空位，返回组织ID
+fn create_org<T: Config>(owner: &T::AccountId) -> Result<u32, BenchmarkError> {
+    let org_id = Organizations::<T>::do_create_organization(owner.clone(), vec![b'a'; 8])
+        .map_err(|_| BenchmarkError::Stop("failed to create organization"))?;
+    for i in 1..T::MaxMembers::get().saturating_sub(1) {
+        let member: T::AccountId = account("member", i, SEED);
+        Organizations::<T>::set_member_role(
+            RawOrigin::Signed(owner.clone()).into(),
+            org_id,
+            member,
+            OrgRole::Viewer,
+        )
+        .map_err(|_| BenchmarkError::Stop("failed to add member"))?;
+    }
+    Ok(org_id)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_organization(n: Linear<0, { T::MaxNameLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), vec![b'a'; n as usize]);
+
+        assert_eq!(Members::<T>::get(0, &caller), Some(OrgRole::Owner));
+    }
+
+    // 最坏情况：添加新成员，需要检查成员数量上限
+    #[benchmark]
+    fn set_member_role() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = whitelisted_caller();
+        let org_id = create_org::<T>(&owner)?;
+        let member: T::AccountId = account("new_member", 0, SEED);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            org_id,
+            member.clone(),
+            OrgRole::Manager,
+        );
+
+        assert_eq!(Members::<T>::get(org_id, &member), Some(OrgRole::Manager));
+        Ok(())
+    }
+
+    // 最坏情况：移除另一个所有者
+    #[benchmark]
+    fn remove_member() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = whitelisted_caller();
+        let org_id = create_org::<T>(&owner)?;
+        let other: T::AccountId = account("owner", 1, SEED);
+        Organizations::<T>::set_member_role(
+            RawOrigin::Signed(owner.clone()).into(),
+            org_id,
+            other.clone(),
+            OrgRole::Owner,
+        )
+        .map_err(|_| BenchmarkError::Stop("failed to add owner"))?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), org_id, other.clone());
+
+        assert_eq!(Members::<T>::get(org_id, &other), None);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        Organizations,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
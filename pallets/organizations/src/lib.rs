@@ -0,0 +1,270 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// 组织 Pallet
+///
+/// 提供由多个账户共同管理任务的组织：
+/// - 任何账户都可以创建组织，并成为组织的第一个所有者
+/// - 组织成员按角色（所有者、管理者、成员、只读成员）获得任务权限
+/// - 所有者可以管理全部成员，管理者只能管理成员和只读成员
+///
+/// 任务 pallet 通过 `OrganizationProvider` 读取成员角色。组织至少保留一个所有者。
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use codec::{Decode, Encode};
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use pallet_tasks::{OrgRole, OrganizationProvider};
+    use scale_info::TypeInfo;
+    use sp_runtime::Saturating;
+    use sp_std::vec::Vec;
+
+    /// 组织信息
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Organization<T: Config> {
+        /// 组织名称
+        pub name: BoundedVec<u8, T::MaxNameLength>,
+        /// 成员数量，包括所有者
+        pub members: u32,
+        /// 所有者数量，至少为一
+        pub owners: u32,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// 事件类型
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// 组织名称最大长度
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        /// 每个组织的成员数量上限，包括所有者
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+
+        /// 权重信息
+        type WeightInfo: WeightInfo;
+    }
+
+    /// 下一个组织ID
+    #[pallet::storage]
+    pub type NextOrgId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 全部组织
+    #[pallet::storage]
+    pub type Organizations<T: Config> =
+        StorageMap<_, Twox64Concat, u32, Organization<T>, OptionQuery>;
+
+    /// 组织成员及其角色
+    #[pallet::storage]
+    pub type Members<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        OrgRole,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// 已创建组织，创建者成为所有者
+        OrganizationCreated { org_id: u32, owner: T::AccountId },
+        /// 已添加成员或更改成员角色
+        MemberRoleSet {
+            org_id: u32,
+            who: T::AccountId,
+            role: OrgRole,
+        },
+        /// 成员已被移除或主动退出
+        MemberRemoved { org_id: u32, who: T::AccountId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// 组织名称过长
+        NameTooLong,
+        /// 组织不存在
+        OrganizationNotFound,
+        /// 账户不是组织成员
+        NotMember,
+        /// 角色不足以执行该操作
+        InsufficientRole,
+        /// 组织成员数量达到上限
+        TooManyMembers,
+        /// 不能移除或降级组织的最后一个所有者
+        LastOwner,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// 创建组织，调用者成为所有者
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_organization(name.len() as u32))]
+        pub fn create_organization(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_create_organization(who, name)?;
+            Ok(())
+        }
+
+        /// 添加成员或更改成员角色
+        ///
+        /// 所有者可以设置任意角色；管理者只能把非管理者账户设置为成员或只读成员。
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_member_role())]
+        pub fn set_member_role(
+            origin: OriginFor<T>,
+            org_id: u32,
+            who: T::AccountId,
+            role: OrgRole,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+
+            let mut org =
+                Organizations::<T>::get(org_id).ok_or(Error::<T>::OrganizationNotFound)?;
+            let current = Members::<T>::get(org_id, &who);
+
+            // 权限检查：按变更前后较高的角色判断
+            Self::ensure_can_manage(org_id, &caller, current.map_or(role, |c| c.max(role)))?;
+
+            match current {
+                None => {
+                    ensure!(
+                        org.members < T::MaxMembers::get(),
+                        Error::<T>::TooManyMembers
+                    );
+                    org.members.saturating_inc();
+                }
+                Some(OrgRole::Owner) if role != OrgRole::Owner => {
+                    ensure!(org.owners > 1, Error::<T>::LastOwner);
+                    org.owners.saturating_dec();
+                }
+                Some(_) => {}
+            }
+            if role == OrgRole::Owner && current != Some(OrgRole::Owner) {
+                org.owners.saturating_inc();
+            }
+
+            Members::<T>::insert(org_id, &who, role);
+            Organizations::<T>::insert(org_id, org);
+
+            Self::deposit_event(Event::MemberRoleSet { org_id, who, role });
+
+            Ok(())
+        }
+
+        /// 移除成员
+        ///
+        /// 成员可以主动退出；其他情况的权限要求与 `set_member_role` 相同。
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove_member())]
+        pub fn remove_member(
+            origin: OriginFor<T>,
+            org_id: u32,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+
+            let mut org =
+                Organizations::<T>::get(org_id).ok_or(Error::<T>::OrganizationNotFound)?;
+            let current = Members::<T>::get(org_id, &who).ok_or(Error::<T>::NotMember)?;
+
+            if caller != who {
+                Self::ensure_can_manage(org_id, &caller, current)?;
+            }
+
+            if current == OrgRole::Owner {
+                ensure!(org.owners > 1, Error::<T>::LastOwner);
+                org.owners.saturating_dec();
+            }
+            org.members.saturating_dec();
+
+            Members::<T>::remove(org_id, &who);
+            Organizations::<T>::insert(org_id, org);
+
+            Self::deposit_event(Event::MemberRemoved { org_id, who });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 创建组织的核心逻辑，返回新组织ID
+        pub(crate) fn do_create_organization(
+            owner: T::AccountId,
+            name: Vec<u8>,
+        ) -> Result<u32, DispatchError> {
+            let name: BoundedVec<u8, T::MaxNameLength> =
+                name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+            let org_id = NextOrgId::<T>::get();
+            NextOrgId::<T>::put(org_id.saturating_add(1));
+
+            Organizations::<T>::insert(
+                org_id,
+                Organization {
+                    name,
+                    members: 1,
+                    owners: 1,
+                },
+            );
+            Members::<T>::insert(org_id, &owner, OrgRole::Owner);
+
+            Self::deposit_event(Event::OrganizationCreated { org_id, owner });
+
+            Ok(org_id)
+        }
+
+        /// 检查调用者能否管理角色为 `role` 的成员：所有者可以管理任何成员，
+        /// 管理者只能管理低于管理者的角色
+        fn ensure_can_manage(org_id: u32, caller: &T::AccountId, role: OrgRole) -> DispatchResult {
+            match Members::<T>::get(org_id, caller) {
+                Some(OrgRole::Owner) => Ok(()),
+                Some(OrgRole::Manager) if role < OrgRole::Manager => Ok(()),
+                Some(_) => Err(Error::<T>::InsufficientRole.into()),
+                None => Err(Error::<T>::NotMember.into()),
+            }
+        }
+    }
+
+    impl<T: Config> OrganizationProvider<T::AccountId> for Pallet<T> {
+        fn role(org_id: u32, who: &T::AccountId) -> Option<OrgRole> {
+            Members::<T>::get(org_id, who)
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn setup_member(who: &T::AccountId, role: OrgRole) -> u32 {
+            let owner: T::AccountId = frame_benchmarking::account("org_owner", 0, 0);
+            let org_id = Self::do_create_organization(owner.clone(), b"org".to_vec())
+                .expect("benchmark organization is valid");
+            Self::set_member_role(
+                frame_system::RawOrigin::Signed(owner).into(),
+                org_id,
+                who.clone(),
+                role,
+            )
+            .expect("owner can set any role");
+            org_id
+        }
+    }
+}
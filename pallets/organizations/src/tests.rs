@@ -0,0 +1,229 @@
+use crate::{mock::*, Error, Event, Members, Organizations as OrganizationStore};
+use frame_support::{assert_noop, assert_ok};
+use pallet_tasks::{OrgRole, OrganizationProvider};
+
+/// ALICE 创建组织，返回组织ID
+fn create_org() -> u32 {
+    let org_id = crate::NextOrgId::<Test>::get();
+    assert_ok!(Organizations::create_organization(
+        RuntimeOrigin::signed(ALICE),
+        b"team".to_vec()
+    ));
+    org_id
+}
+
+fn set_role(caller: u64, org_id: u32, who: u64, role: OrgRole) {
+    assert_ok!(Organizations::set_member_role(
+        RuntimeOrigin::signed(caller),
+        org_id,
+        who,
+        role
+    ));
+}
+
+#[test]
+fn create_organization_works() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org();
+
+        let org = OrganizationStore::<Test>::get(org_id).unwrap();
+        assert_eq!(org.name.to_vec(), b"team".to_vec());
+        assert_eq!(org.members, 1);
+        assert_eq!(org.owners, 1);
+        assert_eq!(Members::<Test>::get(org_id, ALICE), Some(OrgRole::Owner));
+        assert_eq!(crate::NextOrgId::<Test>::get(), org_id + 1);
+        System::assert_last_event(
+            Event::OrganizationCreated {
+                org_id,
+                owner: ALICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_organization_rejects_long_name() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Organizations::create_organization(RuntimeOrigin::signed(ALICE), vec![b'a'; 17]),
+            Error::<Test>::NameTooLong
+        );
+    });
+}
+
+#[test]
+fn owner_can_add_and_change_members() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org();
+
+        set_role(ALICE, org_id, BOB, OrgRole::Member);
+        System::assert_last_event(
+            Event::MemberRoleSet {
+                org_id,
+                who: BOB,
+                role: OrgRole::Member,
+            }
+            .into(),
+        );
+        assert_eq!(Organizations::role(org_id, &BOB), Some(OrgRole::Member));
+
+        set_role(ALICE, org_id, BOB, OrgRole::Owner);
+        let org = OrganizationStore::<Test>::get(org_id).unwrap();
+        assert_eq!(org.members, 2);
+        assert_eq!(org.owners, 2);
+        assert_eq!(Organizations::role(org_id, &CHARLIE), None);
+    });
+}
+
+#[test]
+fn set_member_role_checks_permissions() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org();
+        set_role(ALICE, org_id, BOB, OrgRole::Manager);
+        set_role(ALICE, org_id, CHARLIE, OrgRole::Member);
+
+        // 非成员和普通成员不能管理成员
+        assert_noop!(
+            Organizations::set_member_role(
+                RuntimeOrigin::signed(DAVE),
+                org_id,
+                DAVE,
+                OrgRole::Viewer
+            ),
+            Error::<Test>::NotMember
+        );
+        assert_noop!(
+            Organizations::set_member_role(
+                RuntimeOrigin::signed(CHARLIE),
+                org_id,
+                DAVE,
+                OrgRole::Viewer
+            ),
+            Error::<Test>::InsufficientRole
+        );
+
+        // 管理者不能授予或更改管理者及以上的角色
+        assert_noop!(
+            Organizations::set_member_role(
+                RuntimeOrigin::signed(BOB),
+                org_id,
+                CHARLIE,
+                OrgRole::Manager
+            ),
+            Error::<Test>::InsufficientRole
+        );
+        assert_noop!(
+            Organizations::set_member_role(
+                RuntimeOrigin::signed(BOB),
+                org_id,
+                ALICE,
+                OrgRole::Member
+            ),
+            Error::<Test>::InsufficientRole
+        );
+
+        // 管理者可以管理成员和只读成员
+        set_role(BOB, org_id, CHARLIE, OrgRole::Viewer);
+        assert_eq!(Organizations::role(org_id, &CHARLIE), Some(OrgRole::Viewer));
+    });
+}
+
+#[test]
+fn set_member_role_respects_member_limit() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org();
+        set_role(ALICE, org_id, BOB, OrgRole::Member);
+        set_role(ALICE, org_id, CHARLIE, OrgRole::Member);
+
+        assert_noop!(
+            Organizations::set_member_role(
+                RuntimeOrigin::signed(ALICE),
+                org_id,
+                DAVE,
+                OrgRole::Member
+            ),
+            Error::<Test>::TooManyMembers
+        );
+
+        // 更改已有成员的角色不受上限影响
+        set_role(ALICE, org_id, BOB, OrgRole::Viewer);
+    });
+}
+
+#[test]
+fn last_owner_cannot_be_demoted_or_removed() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org();
+
+        assert_noop!(
+            Organizations::set_member_role(
+                RuntimeOrigin::signed(ALICE),
+                org_id,
+                ALICE,
+                OrgRole::Manager
+            ),
+            Error::<Test>::LastOwner
+        );
+        assert_noop!(
+            Organizations::remove_member(RuntimeOrigin::signed(ALICE), org_id, ALICE),
+            Error::<Test>::LastOwner
+        );
+
+        // 有第二个所有者后可以退出
+        set_role(ALICE, org_id, BOB, OrgRole::Owner);
+        assert_ok!(Organizations::remove_member(
+            RuntimeOrigin::signed(ALICE),
+            org_id,
+            ALICE
+        ));
+        let org = OrganizationStore::<Test>::get(org_id).unwrap();
+        assert_eq!(org.members, 1);
+        assert_eq!(org.owners, 1);
+    });
+}
+
+#[test]
+fn remove_member_works() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org();
+        set_role(ALICE, org_id, BOB, OrgRole::Manager);
+        set_role(ALICE, org_id, CHARLIE, OrgRole::Member);
+
+        // 管理者不能移除其他管理者，但可以移除成员
+        assert_noop!(
+            Organizations::remove_member(RuntimeOrigin::signed(BOB), org_id, ALICE),
+            Error::<Test>::InsufficientRole
+        );
+        assert_ok!(Organizations::remove_member(
+            RuntimeOrigin::signed(BOB),
+            org_id,
+            CHARLIE
+        ));
+        System::assert_last_event(
+            Event::MemberRemoved {
+                org_id,
+                who: CHARLIE,
+            }
+            .into(),
+        );
+        assert_eq!(Organizations::role(org_id, &CHARLIE), None);
+
+        // 成员可以主动退出
+        assert_ok!(Organizations::remove_member(
+            RuntimeOrigin::signed(BOB),
+            org_id,
+            BOB
+        ));
+        assert_eq!(OrganizationStore::<Test>::get(org_id).unwrap().members, 1);
+
+        assert_noop!(
+            Organizations::remove_member(RuntimeOrigin::signed(ALICE), org_id, BOB),
+            Error::<Test>::NotMember
+        );
+        assert_noop!(
+            Organizations::remove_member(RuntimeOrigin::signed(ALICE), org_id + 1, BOB),
+            Error::<Test>::OrganizationNotFound
+        );
+    });
+}
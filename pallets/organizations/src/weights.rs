@@ -0,0 +1,76 @@
+//! Placeholder weights for pallet_organizations
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI. Database reads/writes follow the storage items listed
+//! above each function, but execution times have not been measured. The benchmarks in
+//! `benchmarking.rs` are complete; run `scripts/benchmark-weights.sh organizations` on reference
+//! hardware to replace this file with generated weights before the runtime is deployed.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_organizations.
+pub trait WeightInfo {
+	fn create_organization(n: u32, ) -> Weight;
+	fn set_member_role() -> Weight;
+	fn remove_member() -> Weight;
+}
+
+/// Placeholder weights for pallet_organizations, scaled by the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Organizations NextOrgId (r:1 w:1)
+	/// Proof: Organizations NextOrgId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Organizations Organizations (r:0 w:1)
+	/// Proof: Organizations Organizations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:0 w:1)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn create_organization(n: u32, ) -> Weight {
+		Weight::from_parts(12_700_000, 1489)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Organizations Organizations (r:1 w:1)
+	/// Proof: Organizations Organizations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:2 w:1)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn set_member_role() -> Weight {
+		Weight::from_parts(17_900_000, 6062)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Organizations Organizations (r:1 w:1)
+	/// Proof: Organizations Organizations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:2 w:1)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn remove_member() -> Weight {
+		Weight::from_parts(17_600_000, 6062)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_organization(n: u32, ) -> Weight {
+		Weight::from_parts(12_700_000, 1489)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn set_member_role() -> Weight {
+		Weight::from_parts(17_900_000, 6062)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn remove_member() -> Weight {
+		Weight::from_parts(17_600_000, 6062)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
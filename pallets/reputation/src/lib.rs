@@ -0,0 +1,336 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// 声誉 Pallet
+///
+/// 订阅任务 pallet 的生命周期回调，为任务执行者维护声誉分：
+/// - 完成任务加分，按时完成额外加分
+/// - 社区验证通过加分，未通过扣分
+/// - 执行者主动取消任务扣分
+/// - 创建者重新打开任务时撤销完成和验证通过的加分，或退回取消扣分
+///
+/// 每次变动都按任务的难度和优先级加权。没有奖励的任务不加分，
+/// 否则创建者可以无成本地给其他账户发布任务刷分。
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// 声誉查询接口，其他 pallet 可以用它按声誉限制操作
+pub trait ReputationProvider<AccountId> {
+    /// 账户当前的声誉分
+    fn reputation(who: &AccountId) -> u64;
+
+    /// 账户的声誉分是否达到 `min`
+    fn has_reputation(who: &AccountId, min: u64) -> bool {
+        Self::reputation(who) >= min
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::ReputationProvider;
+    use codec::{Decode, DecodeWithMemTracking, Encode};
+    use frame_support::pallet_prelude::*;
+    use pallet_tasks::{
+        Priority, TaskLifecycleHandler, TaskStatus, TaskSummary, VerificationVerdict,
+    };
+    use scale_info::TypeInfo;
+    use serde::{Deserialize, Serialize};
+    use sp_runtime::{PerThing, Percent, Saturating};
+
+    /// 声誉变动原因
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum ReputationReason {
+        /// 完成任务
+        TaskCompleted,
+        /// 验证通过
+        VerificationApproved,
+        /// 验证未通过
+        VerificationRejected,
+        /// 执行者主动取消任务
+        TaskCancelled,
+        /// 已完成的任务被重新打开，撤销完成加分
+        CompletionReverted,
+        /// 执行者取消的任务被重新打开，退回取消扣分
+        CancellationReverted,
+        /// 验证通过的任务被重新打开，撤销验证加分
+        ApprovalReverted,
+    }
+
+    impl ReputationReason {
+        /// 该原因是否扣分
+        pub fn is_penalty(&self) -> bool {
+            matches!(
+                self,
+                ReputationReason::VerificationRejected
+                    | ReputationReason::TaskCancelled
+                    | ReputationReason::CompletionReverted
+                    | ReputationReason::ApprovalReverted
+            )
+        }
+    }
+
+    /// 账户的声誉记录
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        Default,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub struct ReputationRecord {
+        /// 声誉分，扣分不会低于零
+        pub score: u64,
+        /// 完成的任务数
+        pub completed: u32,
+        /// 按时完成的任务数
+        pub completed_on_time: u32,
+        /// 验证通过的次数
+        pub approved: u32,
+        /// 验证未通过的次数
+        pub rejected: u32,
+        /// 主动取消的任务数
+        pub cancelled: u32,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// 事件类型
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// 单位任务权重（难度 × 优先级）对应的基础分
+        #[pallet::constant]
+        type PointsPerWeight: Get<u64>;
+
+        /// 按时完成的额外加分，占基础分的比例
+        #[pallet::constant]
+        type OnTimeBonus: Get<Percent>;
+
+        /// 验证通过的加分，占基础分的比例
+        #[pallet::constant]
+        type ApprovalBonus: Get<Percent>;
+
+        /// 验证未通过的扣分，占基础分的比例
+        #[pallet::constant]
+        type RejectionPenalty: Get<Percent>;
+
+        /// 执行者主动取消任务的扣分，占基础分的比例
+        #[pallet::constant]
+        type CancellationPenalty: Get<Percent>;
+    }
+
+    /// 账户的声誉记录
+    #[pallet::storage]
+    pub type Reputations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ReputationRecord, ValueQuery>;
+
+    /// 执行者主动取消的任务实际扣除的分数，任务重新打开时退回
+    #[pallet::storage]
+    pub type CancellationPenalties<T: Config> = StorageMap<_, Twox64Concat, u32, u64, OptionQuery>;
+
+    /// 验证通过的任务实际增加的分数，任务重新打开时撤销
+    #[pallet::storage]
+    pub type ApprovalBonuses<T: Config> = StorageMap<_, Twox64Concat, u32, u64, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// 声誉分变动
+        ReputationChanged {
+            who: T::AccountId,
+            task_id: u32,
+            reason: ReputationReason,
+            old_score: u64,
+            new_score: u64,
+        },
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 账户的声誉记录
+        pub fn reputation_of(who: &T::AccountId) -> ReputationRecord {
+            Reputations::<T>::get(who)
+        }
+
+        /// 任务的基础分：难度 × 优先级 × `PointsPerWeight`
+        pub fn base_points(priority: &Priority, difficulty: u8) -> u64 {
+            let weight = u64::from(difficulty).saturating_mul(priority.clone() as u64);
+            weight.saturating_mul(T::PointsPerWeight::get())
+        }
+
+        /// 完成任务获得的分数，按时完成另加 `OnTimeBonus`
+        fn completion_points(summary: &TaskSummary<T::AccountId>, on_time: bool) -> u64 {
+            let base = Self::base_points(&summary.priority, summary.difficulty);
+            if on_time {
+                base.saturating_add(T::OnTimeBonus::get().mul_floor(base))
+            } else {
+                base
+            }
+        }
+
+        /// 按原因给执行者加分或扣分，并更新计数，返回实际变动的分数
+        fn record(
+            summary: &TaskSummary<T::AccountId>,
+            reason: ReputationReason,
+            points: u64,
+            update: impl FnOnce(&mut ReputationRecord),
+        ) -> u64 {
+            let (old_score, new_score) = Reputations::<T>::mutate(&summary.assignee, |record| {
+                let old_score = record.score;
+                record.score = if reason.is_penalty() {
+                    old_score.saturating_sub(points)
+                } else {
+                    old_score.saturating_add(points)
+                };
+                update(record);
+                (old_score, record.score)
+            });
+
+            Self::deposit_event(Event::ReputationChanged {
+                who: summary.assignee.clone(),
+                task_id: summary.task_id,
+                reason,
+                old_score,
+                new_score,
+            });
+            old_score.abs_diff(new_score)
+        }
+    }
+
+    impl<T: Config> TaskLifecycleHandler<T::AccountId> for Pallet<T> {
+        fn on_task_completed(summary: &TaskSummary<T::AccountId>, on_time: bool) {
+            if !summary.rewarded {
+                return;
+            }
+            let points = Self::completion_points(summary, on_time);
+            Self::record(summary, ReputationReason::TaskCompleted, points, |record| {
+                record.completed.saturating_inc();
+                if on_time {
+                    record.completed_on_time.saturating_inc();
+                }
+            });
+        }
+
+        fn on_verification_concluded(
+            summary: &TaskSummary<T::AccountId>,
+            verdict: &VerificationVerdict,
+        ) {
+            let base = Self::base_points(&summary.priority, summary.difficulty);
+            match verdict {
+                VerificationVerdict::Approved => {
+                    if !summary.rewarded {
+                        return;
+                    }
+                    let added = Self::record(
+                        summary,
+                        ReputationReason::VerificationApproved,
+                        T::ApprovalBonus::get().mul_floor(base),
+                        |record| record.approved.saturating_inc(),
+                    );
+                    ApprovalBonuses::<T>::insert(summary.task_id, added);
+                }
+                VerificationVerdict::Rejected => {
+                    Self::record(
+                        summary,
+                        ReputationReason::VerificationRejected,
+                        T::RejectionPenalty::get().mul_floor(base),
+                        |record| record.rejected.saturating_inc(),
+                    );
+                }
+                // 投票不足不说明工作质量，不影响声誉
+                VerificationVerdict::Inconclusive => {}
+            }
+        }
+
+        fn on_task_cancelled_by_assignee(summary: &TaskSummary<T::AccountId>) {
+            let base = Self::base_points(&summary.priority, summary.difficulty);
+            let deducted = Self::record(
+                summary,
+                ReputationReason::TaskCancelled,
+                T::CancellationPenalty::get().mul_floor(base),
+                |record| record.cancelled.saturating_inc(),
+            );
+            CancellationPenalties::<T>::insert(summary.task_id, deducted);
+        }
+
+        fn on_task_reopened(
+            summary: &TaskSummary<T::AccountId>,
+            previous_status: &TaskStatus,
+            on_time: bool,
+        ) {
+            match previous_status {
+                // 已结算的任务不能修改奖励，是否带有奖励与完成时一致
+                TaskStatus::Completed => {
+                    if let Some(added) = ApprovalBonuses::<T>::take(summary.task_id) {
+                        Self::record(
+                            summary,
+                            ReputationReason::ApprovalReverted,
+                            added,
+                            |record| record.approved.saturating_dec(),
+                        );
+                    }
+                    if !summary.rewarded {
+                        return;
+                    }
+                    let points = Self::completion_points(summary, on_time);
+                    Self::record(
+                        summary,
+                        ReputationReason::CompletionReverted,
+                        points,
+                        |record| {
+                            record.completed.saturating_dec();
+                            if on_time {
+                                record.completed_on_time.saturating_dec();
+                            }
+                        },
+                    );
+                }
+                // 只退回执行者主动取消时实际扣除的分数
+                TaskStatus::Cancelled => {
+                    if let Some(deducted) = CancellationPenalties::<T>::take(summary.task_id) {
+                        Self::record(
+                            summary,
+                            ReputationReason::CancellationReverted,
+                            deducted,
+                            |record| record.cancelled.saturating_dec(),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        fn on_task_deleted(task_id: u32) {
+            CancellationPenalties::<T>::remove(task_id);
+            ApprovalBonuses::<T>::remove(task_id);
+        }
+    }
+
+    impl<T: Config> ReputationProvider<T::AccountId> for Pallet<T> {
+        fn reputation(who: &T::AccountId) -> u64 {
+            Reputations::<T>::get(who).score
+        }
+    }
+}
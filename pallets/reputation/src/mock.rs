@@ -0,0 +1,165 @@
+use crate as pallet_reputation;
+use codec::Encode;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Randomness},
+};
+use sp_core::H256;
+use sp_runtime::{BuildStorage, Percent};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+/// 测试开始时的区块时间（毫秒）
+pub const START_TIME: u64 = 1_000;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Timestamp = pallet_timestamp::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(3)]
+    pub type Tasks = pallet_tasks::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
+    pub type Reputation = pallet_reputation::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+/// 由区块号和 subject 派生的确定性随机数
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let block_number = System::block_number();
+        let seed = (subject, block_number).using_encoded(sp_io::hashing::blake2_256);
+        (H256::from(seed), block_number)
+    }
+}
+
+parameter_types! {
+    pub const VerificationThreshold: Percent = Percent::from_percent(60);
+    pub const UnrevealedVotePenalty: Percent = Percent::from_percent(50);
+    pub const OnTimeBonus: Percent = Percent::from_percent(20);
+    pub const ApprovalBonus: Percent = Percent::from_percent(50);
+    pub const RejectionPenalty: Percent = Percent::from_percent(50);
+    pub const CancellationPenalty: Percent = Percent::from_percent(25);
+}
+
+impl pallet_tasks::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type Moment = u64;
+    type TimeProvider = Timestamp;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MaxTitleLength = ConstU32<32>;
+    type MaxDescriptionLength = ConstU32<128>;
+    type MaxTasksPerUser = ConstU32<5>;
+    type ExpiryBucketSize = ConstU64<10>;
+    type MaxTasksPerExpiryBucket = ConstU32<4>;
+    type MaxExpiriesPerBlock = ConstU32<3>;
+    type MaxTaskDepth = ConstU32<2>;
+    type MaxSubtasks = ConstU32<3>;
+    type MaxDependencies = ConstU32<3>;
+    type MaxDependencySearch = ConstU32<4>;
+    type CommitPeriod = ConstU64<5>;
+    type RevealPeriod = ConstU64<3>;
+    type UnrevealedVotePenalty = UnrevealedVotePenalty;
+    type VerificationThreshold = VerificationThreshold;
+    type MinVerificationVotes = ConstU32<2>;
+    type MaxVerifiers = ConstU32<3>;
+    type MaxVerificationsPerBlock = ConstU32<2>;
+    type MinJurorStake = ConstU64<10>;
+    type MaxJurors = ConstU32<5>;
+    type Randomness = TestRandomness;
+    type ReopenWindow = ConstU64<100>;
+    type AssignmentOfferPeriod = ConstU64<50>;
+    type MaxCoAssignees = ConstU32<3>;
+    type MaxMilestones = ConstU32<3>;
+    type BondBeneficiary = ();
+    type ResignationPenalty = ConstU64<20>;
+    type ResignationPenaltyWindow = ConstU64<50>;
+    type MaxApplicants = ConstU32<3>;
+    type MaxApplicationNoteLength = ConstU32<16>;
+    type Organizations = ();
+    type LifecycleHandler = Reputation;
+    type WeightInfo = ();
+}
+
+impl pallet_reputation::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type PointsPerWeight = ConstU64<10>;
+    type OnTimeBonus = OnTimeBonus;
+    type ApprovalBonus = ApprovalBonus;
+    type RejectionPenalty = RejectionPenalty;
+    type CancellationPenalty = CancellationPenalty;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, INITIAL_BALANCE),
+            (BOB, INITIAL_BALANCE),
+            (CHARLIE, INITIAL_BALANCE),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+        Timestamp::set_timestamp(START_TIME);
+    });
+    ext
+}
@@ -0,0 +1,381 @@
+use crate::{
+    mock::*, ApprovalBonuses, CancellationPenalties, Event, ReputationProvider, ReputationReason,
+    ReputationRecord, Reputations,
+};
+use frame_support::assert_ok;
+use pallet_tasks::{
+    NextTaskId, Priority, TaskLifecycleHandler, TaskStatus, TaskSummary, VerificationVerdict,
+};
+
+/// 创建任务并分配给 BOB
+fn create_assigned_task(priority: Priority, difficulty: u8, deadline: Option<u64>) -> u32 {
+    let task_id = NextTaskId::<Test>::get();
+    assert_ok!(Tasks::create_task(
+        RuntimeOrigin::signed(ALICE),
+        b"title".to_vec(),
+        b"description".to_vec(),
+        priority,
+        difficulty,
+        10,
+        deadline,
+    ));
+    assert_ok!(Tasks::assign_task(
+        RuntimeOrigin::signed(ALICE),
+        task_id,
+        BOB,
+        0
+    ));
+    assert_ok!(Tasks::accept_assignment(
+        RuntimeOrigin::signed(BOB),
+        task_id
+    ));
+    task_id
+}
+
+fn set_status(who: u64, task_id: u32, status: TaskStatus) {
+    assert_ok!(Tasks::change_task_status(
+        RuntimeOrigin::signed(who),
+        task_id,
+        status
+    ));
+}
+
+/// 由执行者开始任务，再由创建者确认完成
+fn complete(task_id: u32) {
+    set_status(BOB, task_id, TaskStatus::InProgress);
+    set_status(ALICE, task_id, TaskStatus::Completed);
+}
+
+fn score(who: u64) -> u64 {
+    Reputation::reputation(&who)
+}
+
+#[test]
+fn base_points_are_weighted_by_difficulty_and_priority() {
+    new_test_ext().execute_with(|| {
+        // PointsPerWeight = 10
+        assert_eq!(Reputation::base_points(&Priority::Low, 1), 10);
+        assert_eq!(Reputation::base_points(&Priority::Medium, 5), 100);
+        assert_eq!(Reputation::base_points(&Priority::Urgent, 10), 400);
+    });
+}
+
+#[test]
+fn completing_on_time_awards_bonus() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::High, 4, Some(START_TIME + 100));
+
+        complete(task_id);
+
+        // 基础分 3 × 4 × 10 = 120，按时完成另加 20%
+        assert_eq!(
+            Reputation::reputation_of(&BOB),
+            ReputationRecord {
+                score: 144,
+                completed: 1,
+                completed_on_time: 1,
+                ..Default::default()
+            }
+        );
+        System::assert_has_event(
+            Event::ReputationChanged {
+                who: BOB,
+                task_id,
+                reason: ReputationReason::TaskCompleted,
+                old_score: 0,
+                new_score: 144,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn late_completion_earns_no_bonus() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::High, 4, Some(START_TIME + 10));
+        set_status(BOB, task_id, TaskStatus::InProgress);
+
+        Timestamp::set_timestamp(START_TIME + 20);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        let record = Reputation::reputation_of(&BOB);
+        assert_eq!(record.score, 120);
+        assert_eq!(record.completed, 1);
+        assert_eq!(record.completed_on_time, 0);
+    });
+}
+
+#[test]
+fn unassigned_tasks_do_not_change_reputation() {
+    new_test_ext().execute_with(|| {
+        let task_id = NextTaskId::<Test>::get();
+        assert_ok!(Tasks::create_task(
+            RuntimeOrigin::signed(ALICE),
+            b"title".to_vec(),
+            vec![],
+            Priority::Urgent,
+            10,
+            0,
+            None,
+        ));
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert!(!Reputations::<Test>::contains_key(ALICE));
+    });
+}
+
+#[test]
+fn assignee_cancellation_is_penalised() {
+    new_test_ext().execute_with(|| {
+        // 基础分 2 × 5 × 10 = 100，没有截止时间视为按时
+        let completed = create_assigned_task(Priority::Medium, 5, None);
+        complete(completed);
+        assert_eq!(score(BOB), 120);
+
+        let cancelled = create_assigned_task(Priority::Medium, 5, None);
+        set_status(BOB, cancelled, TaskStatus::Cancelled);
+
+        // 扣除基础分的 25%
+        let record = Reputation::reputation_of(&BOB);
+        assert_eq!(record.score, 95);
+        assert_eq!(record.cancelled, 1);
+        System::assert_last_event(
+            Event::ReputationChanged {
+                who: BOB,
+                task_id: cancelled,
+                reason: ReputationReason::TaskCancelled,
+                old_score: 120,
+                new_score: 95,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn creator_cancellation_does_not_penalise_assignee() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::Medium, 5, None);
+
+        set_status(ALICE, task_id, TaskStatus::Cancelled);
+
+        assert!(!Reputations::<Test>::contains_key(BOB));
+    });
+}
+
+#[test]
+fn penalties_saturate_at_zero() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::Urgent, 10, None);
+
+        set_status(BOB, task_id, TaskStatus::Cancelled);
+
+        assert_eq!(score(BOB), 0);
+        assert_eq!(Reputation::reputation_of(&BOB).cancelled, 1);
+    });
+}
+
+#[test]
+fn verification_verdicts_adjust_reputation() {
+    new_test_ext().execute_with(|| {
+        let summary = TaskSummary {
+            task_id: 0,
+            assignee: BOB,
+            priority: Priority::High,
+            difficulty: 4,
+            rewarded: true,
+        };
+        let conclude = |verdict| {
+            <Reputation as TaskLifecycleHandler<u64>>::on_verification_concluded(&summary, &verdict)
+        };
+
+        // 基础分 120，通过加 50%，未通过扣 50%
+        conclude(VerificationVerdict::Approved);
+        assert_eq!(score(BOB), 60);
+
+        conclude(VerificationVerdict::Approved);
+        conclude(VerificationVerdict::Rejected);
+        assert_eq!(score(BOB), 60);
+
+        // 投票不足不影响声誉
+        let events = System::events().len();
+        conclude(VerificationVerdict::Inconclusive);
+        assert_eq!(System::events().len(), events);
+
+        let record = Reputation::reputation_of(&BOB);
+        assert_eq!(record.approved, 2);
+        assert_eq!(record.rejected, 1);
+    });
+}
+
+#[test]
+fn reputation_provider_gates_on_score() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::Low, 3, None);
+        complete(task_id);
+
+        // 基础分 30，按时加 6
+        assert_eq!(score(BOB), 36);
+        assert!(Reputation::has_reputation(&BOB, 36));
+        assert!(!Reputation::has_reputation(&BOB, 37));
+        assert!(Reputation::has_reputation(&CHARLIE, 0));
+        assert!(!Reputation::has_reputation(&CHARLIE, 1));
+    });
+}
+
+#[test]
+fn reopening_completed_task_reverts_completion_points() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::High, 4, Some(START_TIME + 100));
+        complete(task_id);
+        assert_eq!(score(BOB), 144);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert_eq!(Reputation::reputation_of(&BOB), ReputationRecord::default());
+        System::assert_has_event(
+            Event::ReputationChanged {
+                who: BOB,
+                task_id,
+                reason: ReputationReason::CompletionReverted,
+                old_score: 144,
+                new_score: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reopening_cancelled_task_refunds_actual_penalty() {
+    new_test_ext().execute_with(|| {
+        let completed = create_assigned_task(Priority::Low, 1, None);
+        complete(completed);
+        assert_eq!(score(BOB), 12);
+
+        // 扣分 2 × 5 × 10 × 25% = 25，但声誉分只剩 12
+        let cancelled = create_assigned_task(Priority::Medium, 5, None);
+        set_status(BOB, cancelled, TaskStatus::Cancelled);
+        assert_eq!(score(BOB), 0);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), cancelled));
+
+        let record = Reputation::reputation_of(&BOB);
+        assert_eq!(record.score, 12);
+        assert_eq!(record.cancelled, 0);
+        assert!(!CancellationPenalties::<Test>::contains_key(cancelled));
+    });
+}
+
+#[test]
+fn reopening_task_cancelled_by_creator_changes_nothing() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::Medium, 5, None);
+        set_status(ALICE, task_id, TaskStatus::Cancelled);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert!(!Reputations::<Test>::contains_key(BOB));
+    });
+}
+
+#[test]
+fn zero_reward_tasks_earn_no_points() {
+    new_test_ext().execute_with(|| {
+        let task_id = NextTaskId::<Test>::get();
+        assert_ok!(Tasks::create_task(
+            RuntimeOrigin::signed(ALICE),
+            b"title".to_vec(),
+            vec![],
+            Priority::Urgent,
+            10,
+            0,
+            None,
+        ));
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            0
+        ));
+        assert_ok!(Tasks::accept_assignment(
+            RuntimeOrigin::signed(BOB),
+            task_id
+        ));
+        complete(task_id);
+
+        let summary = TaskSummary {
+            task_id,
+            assignee: BOB,
+            priority: Priority::Urgent,
+            difficulty: 10,
+            rewarded: false,
+        };
+        <Reputation as TaskLifecycleHandler<u64>>::on_verification_concluded(
+            &summary,
+            &VerificationVerdict::Approved,
+        );
+
+        assert!(!Reputations::<Test>::contains_key(BOB));
+        assert!(!ApprovalBonuses::<Test>::contains_key(task_id));
+    });
+}
+
+#[test]
+fn reopening_approved_task_reverts_approval_bonus() {
+    new_test_ext().execute_with(|| {
+        let summary = TaskSummary {
+            task_id: 0,
+            assignee: BOB,
+            priority: Priority::High,
+            difficulty: 4,
+            rewarded: true,
+        };
+        let complete_and_reopen = || {
+            <Reputation as TaskLifecycleHandler<u64>>::on_task_completed(&summary, true);
+            <Reputation as TaskLifecycleHandler<u64>>::on_verification_concluded(
+                &summary,
+                &VerificationVerdict::Approved,
+            );
+            <Reputation as TaskLifecycleHandler<u64>>::on_task_reopened(
+                &summary,
+                &TaskStatus::Completed,
+                true,
+            );
+        };
+
+        // 基础分 120：完成加 144，验证通过加 60，重新打开时全部撤销
+        complete_and_reopen();
+        complete_and_reopen();
+
+        assert_eq!(Reputation::reputation_of(&BOB), ReputationRecord::default());
+        assert!(!ApprovalBonuses::<Test>::contains_key(0));
+        System::assert_has_event(
+            Event::ReputationChanged {
+                who: BOB,
+                task_id: 0,
+                reason: ReputationReason::ApprovalReverted,
+                old_score: 204,
+                new_score: 144,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn deleting_task_clears_recorded_points() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(Priority::Medium, 5, None);
+        set_status(BOB, task_id, TaskStatus::Cancelled);
+        ApprovalBonuses::<Test>::insert(task_id, 10);
+        assert!(CancellationPenalties::<Test>::contains_key(task_id));
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert!(!CancellationPenalties::<Test>::contains_key(task_id));
+        assert!(!ApprovalBonuses::<Test>::contains_key(task_id));
+    });
+}
@@ -0,0 +1,1034 @@
+//! Benchmarking setup for pallet-tasks
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Tasks;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{
+        fungible::{Inspect, Mutate},
+        Get, Time,
+    },
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{
+    traits::{One, Saturating, Zero},
+    Percent,
+};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// 创建一个余额充足的账户
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    T::Currency::set_balance(&who, T::Currency::minimum_balance() * 1_000_000u32.into());
+    who
+}
+
+/// 基准测试使用的奖励金额，1% 的份额恰好等于存在性押金
+fn reward<T: Config>() -> T::Balance {
+    T::Currency::minimum_balance() * 100u32.into()
+}
+
+/// 一个未来的截止时间，确保会写入截止时间索引
+fn future_deadline<T: Config>() -> T::Moment {
+    T::TimeProvider::now().saturating_add(T::ExpiryBucketSize::get())
+}
+
+/// 以最长的标题和描述创建任务，返回任务ID
+fn create_full_task<T: Config>(creator: &T::AccountId, deadline: Option<T::Moment>) -> u32 {
+    Tasks::<T>::do_create_task(
+        creator.clone(),
+        vec![b'a'; T::MaxTitleLength::get() as usize],
+        vec![b'b'; T::MaxDescriptionLength::get() as usize],
+        Priority::High,
+        5,
+        reward::<T>(),
+        deadline,
+        None,
+        None,
+    )
+    .expect("benchmark task is valid")
+}
+
+/// 在 `parent_id` 下创建子任务，返回任务ID
+fn add_subtask<T: Config>(creator: &T::AccountId, parent_id: u32) -> u32 {
+    let task_id = NextTaskId::<T>::get();
+    Tasks::<T>::create_subtask(
+        RawOrigin::Signed(creator.clone()).into(),
+        parent_id,
+        vec![b'a'; T::MaxTitleLength::get() as usize],
+        vec![],
+        Priority::Medium,
+        5,
+        reward::<T>(),
+        None,
+    )
+    .expect("benchmark subtask is valid");
+    task_id
+}
+
+/// 创建位于最深层级的子任务，返回任务ID
+fn nested_task<T: Config>(creator: &T::AccountId) -> u32 {
+    let mut task_id = create_full_task::<T>(creator, None);
+    for _ in 0..T::MaxTaskDepth::get() {
+        task_id = add_subtask::<T>(creator, task_id);
+    }
+    task_id
+}
+
+/// 创建 `d` 个依赖 `blocker_id` 的任务，每个任务由不同账户创建
+fn add_dependents<T: Config>(blocker_id: u32, d: u32) -> Result<(), BenchmarkError> {
+    for i in 0..d {
+        let creator = funded_account::<T>("dependent", i);
+        let task_id = create_full_task::<T>(&creator, None);
+        Tasks::<T>::add_dependency(RawOrigin::Signed(creator).into(), task_id, blocker_id)
+            .map_err(|e| e.error)?;
+    }
+    Ok(())
+}
+
+/// 登记 `n` 名陪审员，每人质押相同金额
+fn register_jurors<T: Config>(n: u32) -> Result<(), BenchmarkError> {
+    for i in 0..n {
+        let juror = funded_account::<T>("juror", i);
+        Tasks::<T>::register_juror(RawOrigin::Signed(juror).into(), juror_stake::<T>())
+            .map_err(|e| e.error)?;
+    }
+    Ok(())
+}
+
+/// 基准测试使用的陪审员质押金额
+fn juror_stake<T: Config>() -> T::Balance {
+    T::MinJurorStake::get().max(T::Currency::minimum_balance())
+}
+
+/// 邀请 `assignee` 并由其接受，完成任务分配
+fn assign<T: Config>(
+    creator: &T::AccountId,
+    task_id: u32,
+    assignee: &T::AccountId,
+) -> Result<(), BenchmarkError> {
+    Tasks::<T>::assign_task(
+        RawOrigin::Signed(creator.clone()).into(),
+        task_id,
+        assignee.clone(),
+        reward::<T>(),
+    )?;
+    Tasks::<T>::accept_assignment(RawOrigin::Signed(assignee.clone()).into(), task_id)?;
+    Ok(())
+}
+
+/// 为第 `index` 个任务添加 `n` 名已接受邀请的协作执行者，每人份额 1%
+fn add_crew<T: Config>(
+    creator: &T::AccountId,
+    task_id: u32,
+    index: u32,
+    n: u32,
+) -> Result<(), BenchmarkError> {
+    let co_assignees: Vec<(T::AccountId, Percent)> = (0..n)
+        .map(|i| {
+            let who = funded_account::<T>(
+                "co-assignee",
+                index
+                    .saturating_mul(T::MaxCoAssignees::get())
+                    .saturating_add(i),
+            );
+            (who, Percent::from_percent(1))
+        })
+        .collect();
+    Tasks::<T>::set_co_assignees(
+        RawOrigin::Signed(creator.clone()).into(),
+        task_id,
+        co_assignees.clone(),
+    )
+    .map_err(|e| e.error)?;
+    for (co_assignee, _) in co_assignees {
+        Tasks::<T>::accept_co_assignment(RawOrigin::Signed(co_assignee).into(), task_id)?;
+    }
+    Ok(())
+}
+
+/// 创建一个已分配（带有最多的协作执行者）并提交验证、尚未抽取验证小组的任务，返回任务ID
+fn submitted_task<T: Config>(index: u32) -> Result<u32, BenchmarkError> {
+    let creator = funded_account::<T>("creator", index);
+    let assignee = funded_account::<T>("assignee", index);
+    let task_id = create_full_task::<T>(&creator, None);
+    assign::<T>(&creator, task_id, &assignee)?;
+    add_crew::<T>(&creator, task_id, index, T::MaxCoAssignees::get())?;
+    Tasks::<T>::change_task_status(
+        RawOrigin::Signed(assignee).into(),
+        task_id,
+        TaskStatus::PendingVerification,
+    )
+    .map_err(|e| e.error)?;
+    Ok(task_id)
+}
+
+/// 下一个区块，提交的验证在该区块抽取验证小组
+fn draw_block<T: Config>() -> BlockNumberFor<T> {
+    frame_system::Pallet::<T>::block_number().saturating_add(One::one())
+}
+
+/// 创建一个已提交验证并抽取了验证小组的任务，返回任务ID
+fn verifying_task<T: Config>(index: u32) -> Result<u32, BenchmarkError> {
+    let task_id = submitted_task::<T>(index)?;
+    Tasks::<T>::process_panel_draws(draw_block::<T>());
+    Ok(task_id)
+}
+
+/// 基准测试使用的投票盐
+const SALT: [u8; 32] = [7u8; 32];
+
+/// 由验证小组的前 `v` 名成员提交赞成票的承诺，返回小组成员
+fn add_commitments<T: Config>(task_id: u32, v: u32) -> Vec<T::AccountId> {
+    VerificationRounds::<T>::mutate(task_id, |round| {
+        let round = round.as_mut().expect("task is under verification");
+        for juror in round.panel.iter().take(v as usize) {
+            let commitment = Tasks::<T>::vote_commitment(juror, task_id, true, &SALT);
+            round
+                .commitments
+                .try_push((juror.clone(), commitment))
+                .expect("the panel fits in MaxVerifiers");
+        }
+        round.panel.to_vec()
+    })
+}
+
+/// 以不同账户提交 `a` 个申请，返回最后一个申请者
+fn add_applications<T: Config>(
+    task_id: u32,
+    a: u32,
+) -> Result<Option<T::AccountId>, BenchmarkError> {
+    let mut last = None;
+    for i in 0..a {
+        let applicant = funded_account::<T>("applicant", i);
+        Tasks::<T>::apply_for_task(
+            RawOrigin::Signed(applicant.clone()).into(),
+            task_id,
+            Some(reward::<T>() * 2u32.into()),
+            vec![0u8; T::MaxApplicationNoteLength::get() as usize],
+        )
+        .map_err(|e| e.error)?;
+        last = Some(applicant);
+    }
+    Ok(last)
+}
+
+/// 为任务设置 `m` 个描述最长的里程碑，第一个里程碑的金额为 `first`，其余为零
+fn add_milestones<T: Config>(
+    creator: &T::AccountId,
+    task_id: u32,
+    m: u32,
+    first: T::Balance,
+) -> Result<(), BenchmarkError> {
+    let milestones = (0..m)
+        .map(|i| {
+            let amount = if i == 0 { first } else { Zero::zero() };
+            (vec![b'm'; T::MaxDescriptionLength::get() as usize], amount)
+        })
+        .collect();
+    Tasks::<T>::set_milestones(
+        RawOrigin::Signed(creator.clone()).into(),
+        task_id,
+        milestones,
+    )
+    .map_err(|e| e.error)?;
+    Ok(())
+}
+
+/// 创建一个已分配、设置了最多里程碑且第一个里程碑已提交的任务，返回任务ID
+fn submitted_milestone<T: Config>(
+    creator: &T::AccountId,
+    assignee: &T::AccountId,
+) -> Result<u32, BenchmarkError> {
+    let task_id = create_full_task::<T>(creator, None);
+    assign::<T>(creator, task_id, assignee)?;
+    add_milestones::<T>(creator, task_id, T::MaxMilestones::get(), reward::<T>())?;
+    Tasks::<T>::submit_milestone(RawOrigin::Signed(assignee.clone()).into(), task_id, 0)?;
+    Ok(task_id)
+}
+
+/// 用占位任务ID把列表填充到 `len` 个元素
+fn fill_list<S: Get<u32>>(list: &mut BoundedVec<u32, S>, len: u32) {
+    let mut filler = u32::MAX;
+    while (list.len() as u32) < len {
+        list.try_push(filler).expect("len is within the list bound");
+        filler = filler.saturating_sub(1);
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_task(
+        t: Linear<0, { T::MaxTitleLength::get() }>,
+        d: Linear<0, { T::MaxDescriptionLength::get() }>,
+    ) {
+        let caller = funded_account::<T>("caller", 0);
+        UserCreatedTasks::<T>::mutate(&caller, |list| {
+            fill_list(list, T::MaxTasksPerUser::get() - 1)
+        });
+        let title = vec![b'a'; t as usize];
+        let description = vec![b'b'; d as usize];
+
+        #[extrinsic_call]
+        create_task(
+            RawOrigin::Signed(caller.clone()),
+            title,
+            description,
+            Priority::High,
+            5,
+            reward::<T>(),
+            Some(future_deadline::<T>()),
+        );
+
+        assert_eq!(
+            UserCreatedTasks::<T>::get(&caller).len() as u32,
+            T::MaxTasksPerUser::get()
+        );
+    }
+
+    #[benchmark]
+    fn update_task(
+        t: Linear<0, { T::MaxTitleLength::get() }>,
+        d: Linear<0, { T::MaxDescriptionLength::get() }>,
+    ) {
+        let caller = funded_account::<T>("caller", 0);
+        let task_id = create_full_task::<T>(&caller, Some(future_deadline::<T>()));
+        let new_reward = reward::<T>().saturating_mul(2u32.into());
+        let new_deadline = future_deadline::<T>().saturating_add(T::ExpiryBucketSize::get());
+
+        #[extrinsic_call]
+        update_task(
+            RawOrigin::Signed(caller),
+            task_id,
+            Some(vec![b'c'; t as usize]),
+            Some(vec![b'd'; d as usize]),
+            Some(Priority::Urgent),
+            Some(9),
+            Some(new_reward),
+            Some(Some(new_deadline)),
+        );
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.reward, new_reward);
+        assert_eq!(task.deadline, Some(new_deadline));
+    }
+
+    // 最坏情况：完成拥有 `c` 个已完成子任务、被 `d` 个任务依赖的任务，并把托管奖励按份额支付给
+    // 执行者和最多的协作执行者
+    #[benchmark]
+    fn change_task_status(
+        c: Linear<0, { T::MaxSubtasks::get() }>,
+        d: Linear<0, { T::MaxDependencies::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        add_crew::<T>(&creator, task_id, 0, T::MaxCoAssignees::get())?;
+        for _ in 0..c {
+            let child_id = add_subtask::<T>(&creator, task_id);
+            Tasks::<T>::change_task_status(
+                RawOrigin::Signed(creator.clone()).into(),
+                child_id,
+                TaskStatus::Completed,
+            )
+            .map_err(|e| e.error)?;
+        }
+        add_dependents::<T>(task_id, d)?;
+
+        #[extrinsic_call]
+        change_task_status(RawOrigin::Signed(creator), task_id, TaskStatus::Completed);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert!(TaskDependents::<T>::get(task_id).is_empty());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn assign_task() {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+
+        #[extrinsic_call]
+        assign_task(
+            RawOrigin::Signed(creator),
+            task_id,
+            assignee.clone(),
+            reward::<T>(),
+        );
+
+        let offer = AssignmentOffers::<T>::get(task_id).expect("offer exists");
+        assert_eq!(offer.assignee, assignee);
+    }
+
+    #[benchmark]
+    fn accept_assignment() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        Tasks::<T>::assign_task(
+            RawOrigin::Signed(creator).into(),
+            task_id,
+            assignee.clone(),
+            reward::<T>(),
+        )?;
+        UserAssignedTasks::<T>::mutate(&assignee, |list| {
+            fill_list(list, T::MaxTasksPerUser::get() - 1)
+        });
+
+        #[extrinsic_call]
+        accept_assignment(RawOrigin::Signed(assignee.clone()), task_id);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.assignee, Some(assignee));
+        assert_eq!(task.bond, reward::<T>());
+        assert!(!AssignmentOffers::<T>::contains_key(task_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn decline_assignment() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        Tasks::<T>::assign_task(
+            RawOrigin::Signed(creator).into(),
+            task_id,
+            assignee.clone(),
+            reward::<T>(),
+        )?;
+
+        #[extrinsic_call]
+        decline_assignment(RawOrigin::Signed(assignee), task_id);
+
+        assert!(!AssignmentOffers::<T>::contains_key(task_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unassign_task(a: Linear<1, { T::MaxTasksPerUser::get() }>) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        // 目标任务位于列表开头，retain 需要扫描整个列表
+        UserAssignedTasks::<T>::mutate(&assignee, |list| fill_list(list, a));
+
+        #[extrinsic_call]
+        unassign_task(RawOrigin::Signed(creator), task_id);
+
+        assert_eq!(UserAssignedTasks::<T>::get(&assignee).len() as u32, a - 1);
+        Ok(())
+    }
+
+    // 临近截止时间辞去进行中的任务，需要支付违约金并回退状态
+    #[benchmark]
+    fn resign_from_task(a: Linear<1, { T::MaxTasksPerUser::get() }>) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let deadline = T::TimeProvider::now().saturating_add(T::ResignationPenaltyWindow::get());
+        let task_id = create_full_task::<T>(&creator, Some(deadline));
+        assign::<T>(&creator, task_id, &assignee)?;
+        Tasks::<T>::change_task_status(
+            RawOrigin::Signed(assignee.clone()).into(),
+            task_id,
+            TaskStatus::InProgress,
+        )
+        .map_err(|e| e.error)?;
+        UserAssignedTasks::<T>::mutate(&assignee, |list| fill_list(list, a));
+
+        #[extrinsic_call]
+        resign_from_task(RawOrigin::Signed(assignee.clone()), task_id);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert!(task.assignee.is_none());
+        assert_eq!(UserAssignedTasks::<T>::get(&assignee).len() as u32, a - 1);
+        Ok(())
+    }
+
+    // 一半已接受的协作执行者被替换为新邀请的账户，`n` 为涉及的账户总数
+    #[benchmark]
+    fn set_co_assignees(
+        n: Linear<0, { T::MaxCoAssignees::get().saturating_mul(2) }>,
+    ) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        add_crew::<T>(&creator, task_id, 0, n / 2)?;
+        let co_assignees: Vec<(T::AccountId, Percent)> = (0..n - n / 2)
+            .map(|i| (account("next", i, SEED), Percent::from_percent(1)))
+            .collect();
+
+        #[extrinsic_call]
+        set_co_assignees(RawOrigin::Signed(creator), task_id, co_assignees);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert!(task.co_assignees.is_empty());
+        assert_eq!(CoAssigneeOffers::<T>::get(task_id).len() as u32, n - n / 2);
+        Ok(())
+    }
+
+    // 接受最后一份邀请，协作执行者的任务列表只剩一个空位
+    #[benchmark]
+    fn accept_co_assignment() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let co_assignee = funded_account::<T>("co-assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        Tasks::<T>::set_co_assignees(
+            RawOrigin::Signed(creator).into(),
+            task_id,
+            vec![(co_assignee.clone(), Percent::from_percent(1))],
+        )
+        .map_err(|e| e.error)?;
+        UserAssignedTasks::<T>::mutate(&co_assignee, |list| {
+            fill_list(list, T::MaxTasksPerUser::get() - 1)
+        });
+
+        #[extrinsic_call]
+        accept_co_assignment(RawOrigin::Signed(co_assignee.clone()), task_id);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.co_assignees.len(), 1);
+        assert!(!CoAssigneeOffers::<T>::contains_key(task_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn decline_co_assignment() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let co_assignee = funded_account::<T>("co-assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        Tasks::<T>::set_co_assignees(
+            RawOrigin::Signed(creator).into(),
+            task_id,
+            vec![(co_assignee.clone(), Percent::from_percent(1))],
+        )
+        .map_err(|e| e.error)?;
+
+        #[extrinsic_call]
+        decline_co_assignment(RawOrigin::Signed(co_assignee), task_id);
+
+        assert!(!CoAssigneeOffers::<T>::contains_key(task_id));
+        Ok(())
+    }
+
+    // 替换已有的最多里程碑
+    #[benchmark]
+    fn set_milestones(m: Linear<0, { T::MaxMilestones::get() }>) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        add_milestones::<T>(&creator, task_id, T::MaxMilestones::get(), Zero::zero())?;
+        let milestones: Vec<(Vec<u8>, T::Balance)> = (0..m)
+            .map(|_| {
+                (
+                    vec![b'n'; T::MaxDescriptionLength::get() as usize],
+                    Zero::zero(),
+                )
+            })
+            .collect();
+
+        #[extrinsic_call]
+        set_milestones(RawOrigin::Signed(creator), task_id, milestones);
+
+        assert_eq!(Milestones::<T>::get(task_id).len() as u32, m);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_milestone() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        add_milestones::<T>(&creator, task_id, T::MaxMilestones::get(), reward::<T>())?;
+
+        #[extrinsic_call]
+        submit_milestone(RawOrigin::Signed(assignee), task_id, 0);
+
+        assert_eq!(
+            Milestones::<T>::get(task_id)[0].status,
+            MilestoneStatus::Submitted
+        );
+        Ok(())
+    }
+
+    // 里程碑金额按份额支付给执行者和 `c` 个协作执行者
+    #[benchmark]
+    fn approve_milestone(c: Linear<0, { T::MaxCoAssignees::get() }>) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = submitted_milestone::<T>(&creator, &assignee)?;
+        add_crew::<T>(&creator, task_id, 0, c)?;
+
+        #[extrinsic_call]
+        approve_milestone(RawOrigin::Signed(creator), task_id, 0);
+
+        assert_eq!(
+            Milestones::<T>::get(task_id)[0].status,
+            MilestoneStatus::Approved
+        );
+        assert!(Tasks::<T>::tasks(task_id)
+            .expect("task exists")
+            .reward
+            .is_zero());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reject_milestone() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = submitted_milestone::<T>(&creator, &assignee)?;
+
+        #[extrinsic_call]
+        reject_milestone(RawOrigin::Signed(creator), task_id, 0);
+
+        assert_eq!(
+            Milestones::<T>::get(task_id)[0].status,
+            MilestoneStatus::Pending
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_org_task(
+        t: Linear<0, { T::MaxTitleLength::get() }>,
+        d: Linear<0, { T::MaxDescriptionLength::get() }>,
+    ) {
+        let caller = funded_account::<T>("caller", 0);
+        let org_id = T::Organizations::setup_member(&caller, OrgRole::Member);
+        UserCreatedTasks::<T>::mutate(&caller, |list| {
+            fill_list(list, T::MaxTasksPerUser::get() - 1)
+        });
+        let title = vec![b'a'; t as usize];
+        let description = vec![b'b'; d as usize];
+        let task_id = NextTaskId::<T>::get();
+
+        #[extrinsic_call]
+        create_org_task(
+            RawOrigin::Signed(caller),
+            org_id,
+            title,
+            description,
+            Priority::High,
+            5,
+            reward::<T>(),
+            Some(future_deadline::<T>()),
+        );
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.org, Some(org_id));
+    }
+
+    #[benchmark]
+    fn delete_task(
+        c: Linear<1, { T::MaxTasksPerUser::get() }>,
+        a: Linear<1, { T::MaxTasksPerUser::get() }>,
+        d: Linear<0, { T::MaxDependencies::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, Some(future_deadline::<T>()));
+        assign::<T>(&creator, task_id, &assignee)?;
+        add_dependents::<T>(task_id, d)?;
+        UserCreatedTasks::<T>::mutate(&creator, |list| fill_list(list, c));
+        UserAssignedTasks::<T>::mutate(&assignee, |list| fill_list(list, a));
+
+        #[extrinsic_call]
+        delete_task(RawOrigin::Signed(creator.clone()), task_id);
+
+        assert!(Tasks::<T>::tasks(task_id).is_none());
+        assert_eq!(UserCreatedTasks::<T>::get(&creator).len() as u32, c - 1);
+        assert_eq!(UserAssignedTasks::<T>::get(&assignee).len() as u32, a - 1);
+        Ok(())
+    }
+
+    // `n` 个任务位于同一个已过期分桶中，全部在一次调用内过期
+    #[benchmark]
+    fn process_expiries(n: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+        let deadline = future_deadline::<T>();
+        let mut task_ids = vec![];
+        for i in 0..n {
+            let creator = funded_account::<T>("creator", i);
+            task_ids.push(create_full_task::<T>(&creator, Some(deadline)));
+        }
+        let now = deadline.saturating_add(T::ExpiryBucketSize::get().saturating_mul(2u32.into()));
+
+        #[block]
+        {
+            Tasks::<T>::process_expiries(now, n + 1);
+        }
+
+        for task_id in task_ids {
+            let task = Tasks::<T>::tasks(task_id).expect("task exists");
+            assert_eq!(task.status, TaskStatus::Expired);
+        }
+    }
+
+    // 最坏情况：父任务位于最大深度的上一层，且子任务列表只剩一个空位
+    #[benchmark]
+    fn create_subtask(
+        t: Linear<0, { T::MaxTitleLength::get() }>,
+        d: Linear<0, { T::MaxDescriptionLength::get() }>,
+    ) {
+        // 父任务属于组织，需要检查调用者的组织角色
+        let caller = funded_account::<T>("caller", 0);
+        let org_id = T::Organizations::setup_member(&caller, OrgRole::Member);
+        let mut parent_id = Tasks::<T>::do_create_task(
+            caller.clone(),
+            vec![b'a'; T::MaxTitleLength::get() as usize],
+            vec![b'b'; T::MaxDescriptionLength::get() as usize],
+            Priority::High,
+            5,
+            reward::<T>(),
+            None,
+            None,
+            Some(org_id),
+        )
+        .expect("benchmark task is valid");
+        for _ in 1..T::MaxTaskDepth::get() {
+            parent_id = add_subtask::<T>(&caller, parent_id);
+        }
+        TaskChildren::<T>::mutate(parent_id, |children| {
+            fill_list(children, T::MaxSubtasks::get() - 1)
+        });
+        let title = vec![b'a'; t as usize];
+        let description = vec![b'b'; d as usize];
+
+        #[extrinsic_call]
+        create_subtask(
+            RawOrigin::Signed(caller),
+            parent_id,
+            title,
+            description,
+            Priority::High,
+            5,
+            reward::<T>(),
+            Some(future_deadline::<T>()),
+        );
+
+        assert_eq!(
+            TaskChildren::<T>::get(parent_id).len() as u32,
+            T::MaxSubtasks::get()
+        );
+    }
+
+    // 前置任务位于一条长度为 `n` 的依赖链开头，环检测需要访问整条链；
+    // 两个任务都位于最深的层级，层级检查需要遍历全部祖先
+    #[benchmark]
+    fn add_dependency(
+        n: Linear<1, { T::MaxDependencySearch::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller = funded_account::<T>("caller", 0);
+        let task_id = nested_task::<T>(&caller);
+        let chain: Vec<u32> = (0..n)
+            .map(|i| {
+                let creator = funded_account::<T>("chain", i);
+                if i == 0 {
+                    nested_task::<T>(&creator)
+                } else {
+                    create_full_task::<T>(&creator, None)
+                }
+            })
+            .collect();
+        for (i, pair) in chain.windows(2).enumerate() {
+            let creator: T::AccountId = account("chain", i as u32, SEED);
+            Tasks::<T>::add_dependency(RawOrigin::Signed(creator).into(), pair[0], pair[1])
+                .map_err(|e| e.error)?;
+        }
+
+        #[extrinsic_call]
+        add_dependency(RawOrigin::Signed(caller), task_id, chain[0]);
+
+        assert_eq!(TaskDependencies::<T>::get(task_id).to_vec(), vec![chain[0]]);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_dependency() -> Result<(), BenchmarkError> {
+        let caller = funded_account::<T>("caller", 0);
+        let task_id = create_full_task::<T>(&caller, None);
+        let blocker_id = create_full_task::<T>(&caller, None);
+        Tasks::<T>::add_dependency(
+            RawOrigin::Signed(caller.clone()).into(),
+            task_id,
+            blocker_id,
+        )
+        .map_err(|e| e.error)?;
+
+        #[extrinsic_call]
+        remove_dependency(RawOrigin::Signed(caller), task_id, blocker_id);
+
+        assert!(TaskDependencies::<T>::get(task_id).is_empty());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn commit_vote(v: Linear<0, { T::MaxVerifiers::get() - 1 }>) -> Result<(), BenchmarkError> {
+        register_jurors::<T>(T::MaxJurors::get())?;
+        let task_id = verifying_task::<T>(0)?;
+        let panel = add_commitments::<T>(task_id, v);
+        let caller = panel[v as usize].clone();
+        let commitment = Tasks::<T>::vote_commitment(&caller, task_id, true, &SALT);
+
+        #[extrinsic_call]
+        commit_vote(RawOrigin::Signed(caller), task_id, commitment);
+
+        let round = VerificationRounds::<T>::get(task_id).expect("round exists");
+        assert_eq!(round.commitments.len() as u32, v + 1);
+        Ok(())
+    }
+
+    // 整个小组都已提交承诺，其中 `v` 人已揭示
+    #[benchmark]
+    fn reveal_vote(v: Linear<0, { T::MaxVerifiers::get() - 1 }>) -> Result<(), BenchmarkError> {
+        register_jurors::<T>(T::MaxJurors::get())?;
+        let task_id = verifying_task::<T>(0)?;
+        let panel = add_commitments::<T>(task_id, T::MaxVerifiers::get());
+        VerificationRounds::<T>::mutate(task_id, |round| {
+            let round = round.as_mut().expect("task is under verification");
+            for juror in panel.iter().take(v as usize) {
+                round
+                    .votes
+                    .try_push((juror.clone(), true))
+                    .expect("the panel fits in MaxVerifiers");
+            }
+            frame_system::Pallet::<T>::set_block_number(round.commit_ends);
+        });
+        let caller = panel[v as usize].clone();
+
+        #[extrinsic_call]
+        reveal_vote(RawOrigin::Signed(caller), task_id, true, SALT);
+
+        let round = VerificationRounds::<T>::get(task_id).expect("round exists");
+        assert_eq!(round.votes.len() as u32, v + 1);
+        Ok(())
+    }
+
+    // `n` 个验证轮次在同一区块结束，每轮整个小组都提交了承诺，只有 `MinVerificationVotes` 人
+    // 揭示了赞成票：未揭示的陪审员被罚没，任务通过验证并支付奖励、触发生命周期回调
+    #[benchmark]
+    fn process_verifications(
+        n: Linear<0, { T::MaxVerificationsPerBlock::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        register_jurors::<T>(T::MaxJurors::get())?;
+        let mut task_ids = vec![];
+        for i in 0..n {
+            let task_id = verifying_task::<T>(i)?;
+            let panel = add_commitments::<T>(task_id, T::MaxVerifiers::get());
+            VerificationRounds::<T>::mutate(task_id, |round| {
+                let round = round.as_mut().expect("task is under verification");
+                for juror in panel.iter().take(T::MinVerificationVotes::get() as usize) {
+                    round
+                        .votes
+                        .try_push((juror.clone(), true))
+                        .expect("the panel fits in MaxVerifiers");
+                }
+            });
+            task_ids.push(task_id);
+        }
+        let ends_at = draw_block::<T>()
+            .saturating_add(T::CommitPeriod::get().max(One::one()))
+            .saturating_add(T::RevealPeriod::get().max(One::one()));
+
+        #[block]
+        {
+            Tasks::<T>::process_verifications(ends_at);
+        }
+
+        for task_id in task_ids {
+            let task = Tasks::<T>::tasks(task_id).expect("task exists");
+            assert_eq!(task.status, TaskStatus::Completed);
+        }
+        Ok(())
+    }
+
+    // 提交验证时登记验证轮次和验证小组抽取索引
+    #[benchmark]
+    fn start_verification() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        assign::<T>(&creator, task_id, &assignee)?;
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+
+        #[block]
+        {
+            Tasks::<T>::start_verification(&task)?;
+        }
+
+        let round = VerificationRounds::<T>::get(task_id).expect("round exists");
+        assert!(round.panel.is_empty());
+        Ok(())
+    }
+
+    // `n` 个验证轮次在同一区块抽取验证小组，陪审员池已满
+    #[benchmark]
+    fn process_panel_draws(
+        n: Linear<0, { T::MaxVerificationsPerBlock::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        register_jurors::<T>(T::MaxJurors::get())?;
+        let task_ids = (0..n)
+            .map(|i| submitted_task::<T>(i))
+            .collect::<Result<Vec<_>, _>>()?;
+        let draw_at = draw_block::<T>();
+
+        #[block]
+        {
+            Tasks::<T>::process_panel_draws(draw_at);
+        }
+
+        for task_id in task_ids {
+            let round = VerificationRounds::<T>::get(task_id).expect("round exists");
+            assert_eq!(
+                round.panel.len() as u32,
+                T::MaxJurors::get().min(T::MaxVerifiers::get())
+            );
+        }
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_juror(j: Linear<0, { T::MaxJurors::get() - 1 }>) -> Result<(), BenchmarkError> {
+        register_jurors::<T>(j)?;
+        let caller = funded_account::<T>("caller", 0);
+
+        #[extrinsic_call]
+        register_juror(RawOrigin::Signed(caller), juror_stake::<T>());
+
+        assert_eq!(Jurors::<T>::get().len() as u32, j + 1);
+        Ok(())
+    }
+
+    // 要退出的陪审员位于池的末尾
+    #[benchmark]
+    fn unregister_juror(j: Linear<1, { T::MaxJurors::get() }>) -> Result<(), BenchmarkError> {
+        register_jurors::<T>(j)?;
+        let caller: T::AccountId = account("juror", j - 1, SEED);
+
+        #[extrinsic_call]
+        unregister_juror(RawOrigin::Signed(caller));
+
+        assert_eq!(Jurors::<T>::get().len() as u32, j - 1);
+        Ok(())
+    }
+
+    // 最坏情况：重新打开已支付奖励、设置了截止时间的任务，需要收回奖励、移除执行者和协作执行者
+    // 并重新登记截止时间
+    #[benchmark]
+    fn reopen_task() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let assignee = funded_account::<T>("assignee", 0);
+        let task_id = create_full_task::<T>(&creator, Some(future_deadline::<T>()));
+        assign::<T>(&creator, task_id, &assignee)?;
+        add_crew::<T>(&creator, task_id, 0, T::MaxCoAssignees::get())?;
+        // 目标任务位于列表开头，retain 需要扫描整个列表
+        UserAssignedTasks::<T>::mutate(&assignee, |list| {
+            fill_list(list, T::MaxTasksPerUser::get())
+        });
+        Tasks::<T>::change_task_status(
+            RawOrigin::Signed(creator.clone()).into(),
+            task_id,
+            TaskStatus::Completed,
+        )
+        .map_err(|e| e.error)?;
+
+        #[extrinsic_call]
+        reopen_task(RawOrigin::Signed(creator), task_id);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert!(task.assignee.is_none());
+        assert!(task.closed_at.is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_task_open() {
+        let creator = funded_account::<T>("creator", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+
+        #[extrinsic_call]
+        set_task_open(RawOrigin::Signed(creator), task_id, true);
+
+        assert!(ClaimableTasks::<T>::contains_key(task_id));
+    }
+
+    #[benchmark]
+    fn claim_task() -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let claimant = funded_account::<T>("claimant", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        Tasks::<T>::set_task_open(RawOrigin::Signed(creator).into(), task_id, true)?;
+        UserAssignedTasks::<T>::mutate(&claimant, |list| {
+            fill_list(list, T::MaxTasksPerUser::get() - 1)
+        });
+
+        #[extrinsic_call]
+        claim_task(RawOrigin::Signed(claimant.clone()), task_id);
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.assignee, Some(claimant));
+        assert!(!ClaimableTasks::<T>::contains_key(task_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn apply_for_task(
+        a: Linear<0, { T::MaxApplicants::get() - 1 }>,
+        n: Linear<0, { T::MaxApplicationNoteLength::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        add_applications::<T>(task_id, a)?;
+        let applicant = funded_account::<T>("applicant", a);
+
+        #[extrinsic_call]
+        apply_for_task(
+            RawOrigin::Signed(applicant),
+            task_id,
+            Some(reward::<T>()),
+            vec![0u8; n as usize],
+        );
+
+        assert_eq!(Applications::<T>::decode_len(task_id), Some(a as usize + 1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn accept_application(a: Linear<1, { T::MaxApplicants::get() }>) -> Result<(), BenchmarkError> {
+        let creator = funded_account::<T>("creator", 0);
+        let task_id = create_full_task::<T>(&creator, None);
+        // 接受最后一个申请，需要扫描整个列表；提高报酬需要追加托管
+        let applicant = add_applications::<T>(task_id, a)?.expect("a >= 1");
+        UserAssignedTasks::<T>::mutate(&applicant, |list| {
+            fill_list(list, T::MaxTasksPerUser::get() - 1)
+        });
+
+        #[extrinsic_call]
+        accept_application(RawOrigin::Signed(creator), task_id, applicant.clone());
+
+        let task = Tasks::<T>::tasks(task_id).expect("task exists");
+        assert_eq!(task.assignee, Some(applicant));
+        assert!(!Applications::<T>::contains_key(task_id));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Tasks, crate::mock::new_test_ext(), crate::mock::Test);
+}
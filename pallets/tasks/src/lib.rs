@@ -10,21 +10,62 @@
 /// - 任务优先级和难度评估
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod traits;
+pub mod weights;
+pub use traits::*;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use codec::{Decode, Encode};
+    use crate::{
+        traits::{OrganizationProvider, TaskLifecycleHandler, TaskSummary},
+        weights::WeightInfo,
+    };
+    use codec::{Decode, DecodeWithMemTracking, Encode};
     use frame_support::{
-        dispatch::{DispatchError, DispatchResult},
+        dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
         pallet_prelude::*,
-        traits::{Get, Randomness},
+        storage::with_storage_layer,
+        traits::{
+            fungible::{Mutate, MutateHold},
+            tokens::{Fortitude, Precision, Preservation, Restriction},
+            BuildGenesisConfig, Get, Randomness, Time,
+        },
     };
     use frame_system::pallet_prelude::*;
     use scale_info::TypeInfo;
-    use sp_runtime::traits::{Saturating, Zero};
+    use serde::{Deserialize, Serialize};
+    use sp_runtime::{
+        traits::{
+            AtLeast32BitUnsigned, Hash as HashT, MaybeSerializeDeserialize, One,
+            SaturatedConversion, Saturating, TrailingZeroInput, Zero,
+        },
+        PerThing, Percent, Permill,
+    };
     use sp_std::vec::Vec;
 
     /// 任务状态枚举
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
     pub enum TaskStatus {
         /// 待处理
         Pending,
@@ -36,6 +77,8 @@ pub mod pallet {
         Cancelled,
         /// 需要验证
         PendingVerification,
+        /// 已过期（截止时间已过仍未完成，由链上自动设置）
+        Expired,
     }
 
     impl Default for TaskStatus {
@@ -44,8 +87,40 @@ pub mod pallet {
         }
     }
 
+    impl TaskStatus {
+        /// 任务是否仍未结束（待处理、进行中或待验证）
+        pub fn is_open(&self) -> bool {
+            matches!(
+                self,
+                TaskStatus::Pending | TaskStatus::InProgress | TaskStatus::PendingVerification
+            )
+        }
+
+        /// 全部任务状态
+        pub const ALL: [TaskStatus; 6] = [
+            TaskStatus::Pending,
+            TaskStatus::InProgress,
+            TaskStatus::Completed,
+            TaskStatus::Cancelled,
+            TaskStatus::PendingVerification,
+            TaskStatus::Expired,
+        ];
+    }
+
     /// 任务优先级
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
     pub enum Priority {
         Low = 1,
         Medium = 2,
@@ -59,6 +134,279 @@ pub mod pallet {
         }
     }
 
+    impl Priority {
+        /// 全部优先级
+        pub const ALL: [Priority; 4] = [
+            Priority::Low,
+            Priority::Medium,
+            Priority::High,
+            Priority::Urgent,
+        ];
+    }
+
+    /// 组织成员角色，按权限从低到高排列
+    ///
+    /// - `Viewer`：只读成员
+    /// - `Member`：可以以组织名义创建任务
+    /// - `Manager`：可以编辑、分配组织的任务并更改其状态
+    /// - `Owner`：可以删除组织的任务
+    ///
+    /// 组织任务的权限只由角色决定，任务创建者没有额外的权限。
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    pub enum OrgRole {
+        Viewer,
+        Member,
+        Manager,
+        Owner,
+    }
+
+    /// 难度分段，用于统计
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    pub enum DifficultyBand {
+        /// 简单（1-3）
+        Easy,
+        /// 中等（4-7）
+        Medium,
+        /// 困难（8-10）
+        Hard,
+    }
+
+    impl DifficultyBand {
+        /// 全部难度分段
+        pub const ALL: [DifficultyBand; 3] = [
+            DifficultyBand::Easy,
+            DifficultyBand::Medium,
+            DifficultyBand::Hard,
+        ];
+
+        /// 难度等级所属的分段
+        pub fn of(difficulty: u8) -> Self {
+            match difficulty {
+                0..=3 => DifficultyBand::Easy,
+                4..=7 => DifficultyBand::Medium,
+                _ => DifficultyBand::Hard,
+            }
+        }
+    }
+
+    /// 全局任务统计，只统计尚未删除的任务
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        Default,
+        RuntimeDebug,
+        TypeInfo,
+        Serialize,
+        Deserialize,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub struct TaskStatistics {
+        /// 任务总数
+        pub total: u32,
+        /// 按状态统计的任务数量
+        pub by_status: Vec<(TaskStatus, u32)>,
+        /// 按优先级统计的任务数量
+        pub by_priority: Vec<(Priority, u32)>,
+        /// 按难度分段统计的任务数量
+        pub by_difficulty: Vec<(DifficultyBand, u32)>,
+    }
+
+    /// 账户的累计任务统计，删除任务不会回退这些计数
+    ///
+    /// 完成和取消计入任务的执行者；任务没有执行者时计入创建者。
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        Default,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountTaskStats {
+        /// 创建的任务数量
+        pub created: u32,
+        /// 被分配的任务数量
+        pub assigned: u32,
+        /// 完成的任务数量
+        pub completed: u32,
+        /// 在截止时间前完成的任务数量（没有截止时间的任务视为按时完成）
+        pub completed_on_time: u32,
+        /// 被取消的任务数量
+        pub cancelled: u32,
+    }
+
+    impl AccountTaskStats {
+        /// 按时完成率；没有完成任何任务时为零
+        pub fn on_time_rate(&self) -> Permill {
+            if self.completed == 0 {
+                return Permill::zero();
+            }
+            Permill::from_rational(self.completed_on_time, self.completed)
+        }
+    }
+
+    /// 验证轮次的结论
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    pub enum VerificationVerdict {
+        /// 赞成票达到阈值，任务完成
+        Approved,
+        /// 赞成票未达到阈值，任务退回进行中
+        Rejected,
+        /// 投票数不足，任务退回进行中
+        Inconclusive,
+    }
+
+    /// 最近一次验证轮次的结果，记录在任务上
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Serialize,
+        Deserialize,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub struct VerificationOutcome {
+        /// 结论
+        pub verdict: VerificationVerdict,
+        /// 赞成票数
+        pub approvals: u32,
+        /// 反对票数
+        pub rejections: u32,
+    }
+
+    /// 进行中的验证轮次
+    ///
+    /// 先是承诺阶段，陪审员只提交投票的哈希；承诺阶段结束后进入揭示阶段，
+    /// 陪审员公开意见和盐；揭示阶段结束时只统计已揭示的投票。
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct VerificationRound<T: Config> {
+        /// 承诺阶段结束、揭示阶段开始的区块
+        pub commit_ends: BlockNumberFor<T>,
+        /// 揭示阶段结束的区块，在该区块开始时统计结果
+        pub ends_at: BlockNumberFor<T>,
+        /// 抽中的陪审员，只有他们可以投票
+        pub panel: BoundedVec<T::AccountId, T::MaxVerifiers>,
+        /// 陪审员提交的投票承诺，见 [`Pallet::vote_commitment`]
+        pub commitments: BoundedVec<(T::AccountId, T::Hash), T::MaxVerifiers>,
+        /// 已揭示的投票及其意见（`true` 为赞成）
+        pub votes: BoundedVec<(T::AccountId, bool), T::MaxVerifiers>,
+    }
+
+    /// 陪审员登记信息
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Juror<T: Config> {
+        /// 陪审员账户
+        pub account: T::AccountId,
+        /// 冻结的质押金额，也是被抽中的权重
+        pub stake: T::Balance,
+        /// 所在的进行中验证小组数量，为零时才能退出
+        pub panels: u32,
+    }
+
+    /// 待执行者接受的分配邀请
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AssignmentOffer<T: Config> {
+        /// 被邀请的执行者
+        pub assignee: T::AccountId,
+        /// 邀请的过期时间，过期后无法再接受
+        pub expires_at: T::Moment,
+        /// 接受邀请时需要从执行者账户冻结的保证金
+        pub bond: T::Balance,
+    }
+
+    /// 任务申请
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Application<T: Config> {
+        /// 申请者
+        pub applicant: T::AccountId,
+        /// 申请者提出的报酬，`None` 表示接受任务当前的奖励
+        pub reward: Option<T::Balance>,
+        /// 附言
+        pub note: BoundedVec<u8, T::MaxApplicationNoteLength>,
+        /// 申请时间
+        pub applied_at: T::Moment,
+    }
+
+    /// 里程碑状态
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum MilestoneStatus {
+        /// 等待执行者提交
+        Pending,
+        /// 执行者已提交，等待创建者批准
+        Submitted,
+        /// 已批准，对应的奖励已支付
+        Approved,
+    }
+
+    /// 任务里程碑
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Milestone<T: Config> {
+        /// 里程碑描述
+        pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+        /// 批准时从托管奖励中支付的金额
+        pub amount: T::Balance,
+        /// 当前状态
+        pub status: MilestoneStatus,
+    }
+
     /// 任务结构体
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -87,11 +435,56 @@ pub mod pallet {
         pub updated_at: T::Moment,
         /// 截止时间（可选）
         pub deadline: Option<T::Moment>,
+        /// 父任务ID（可选），顶层任务为 `None`
+        pub parent: Option<u32>,
+        /// 最近一次验证轮次的结果，从未验证过时为 `None`
+        pub verification: Option<VerificationOutcome>,
+        /// 结束（完成、取消或过期）的时间，任务未结束时为 `None`
+        pub closed_at: Option<T::Moment>,
+        /// 最近一次提交验证的时间，是否按时完成以此为准；未提交或验证被驳回后为 `None`
+        pub submitted_at: Option<T::Moment>,
+        /// 是否公开：公开且未分配的任务可以被任何账户认领
+        pub open: bool,
+        /// 执行者冻结的保证金，按时完成时释放，逾期或放弃时罚没
+        pub bond: T::Balance,
+        /// 协作执行者及其奖励份额，执行者获得其余部分
+        pub co_assignees: BoundedVec<(T::AccountId, Percent), T::MaxCoAssignees>,
+        /// 任务所属的组织（可选），组织成员按角色获得管理权限
+        pub org: Option<u32>,
+    }
+
+    impl<T: Config> Task<T> {
+        /// 任务是否已逾期：设置了截止时间、仍未结束，且截止前没有提交验证
+        pub fn is_overdue(&self, now: T::Moment) -> bool {
+            let finished_at = self.submitted_at.unwrap_or(now);
+            self.status.is_open()
+                && self
+                    .deadline
+                    .map_or(false, |deadline| finished_at > deadline)
+        }
+
+        /// 任务当前是否可以认领：公开、未分配，且处于待处理或进行中
+        pub fn is_claimable(&self) -> bool {
+            self.open
+                && self.assignee.is_none()
+                && matches!(self.status, TaskStatus::Pending | TaskStatus::InProgress)
+        }
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    /// 本 pallet 冻结资金的原因
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// 任务奖励托管，创建任务时从创建者账户冻结
+        TaskReward,
+        /// 陪审员质押，登记为陪审员时冻结，退出时释放
+        JurorStake,
+        /// 执行者保证金，接受分配时从执行者账户冻结
+        AssigneeBond,
+    }
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -99,11 +492,27 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// The balance type
-        type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+        type Balance: Member
+            + Parameter
+            + AtLeast32BitUnsigned
+            + Default
+            + Copy
+            + MaxEncodedLen
+            + MaybeSerializeDeserialize;
 
         /// The moment type for timestamps
         type Moment: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
 
+        /// 链上时间来源，用于记录任务时间戳和校验截止时间
+        type TimeProvider: Time<Moment = Self::Moment>;
+
+        /// 用于托管任务奖励的货币
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = Self::Balance>;
+
+        /// 运行时的资金冻结原因
+        type RuntimeHoldReason: From<HoldReason>;
+
         /// 任务标题最大长度
         #[pallet::constant]
         type MaxTitleLength: Get<u32>;
@@ -116,8 +525,118 @@ pub mod pallet {
         #[pallet::constant]
         type MaxTasksPerUser: Get<u32>;
 
-        /// 随机数生成器
+        /// 截止时间索引的分桶跨度，任务最多会在截止时间后一个分桶跨度内过期
+        #[pallet::constant]
+        type ExpiryBucketSize: Get<Self::Moment>;
+
+        /// 每个截止时间分桶最多容纳的任务数量
+        #[pallet::constant]
+        type MaxTasksPerExpiryBucket: Get<u32>;
+
+        /// 每个区块最多处理的过期步骤数（读取一个分桶或过期一个任务各计一步）
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+
+        /// 任务层级的最大深度，顶层任务深度为 0
+        #[pallet::constant]
+        type MaxTaskDepth: Get<u32>;
+
+        /// 每个任务最多包含的直接子任务数量
+        #[pallet::constant]
+        type MaxSubtasks: Get<u32>;
+
+        /// 每个任务最多的前置任务数量，也是最多被多少个任务依赖的数量
+        #[pallet::constant]
+        type MaxDependencies: Get<u32>;
+
+        /// 添加依赖时环检测最多访问的任务数量，超过时拒绝添加
+        #[pallet::constant]
+        type MaxDependencySearch: Get<u32>;
+
+        /// 验证轮次承诺阶段的时长（区块数），至少为一个区块
+        #[pallet::constant]
+        type CommitPeriod: Get<BlockNumberFor<Self>>;
+
+        /// 验证轮次揭示阶段的时长（区块数），至少为一个区块
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// 提交承诺却没有揭示的陪审员被罚没的质押比例
+        #[pallet::constant]
+        type UnrevealedVotePenalty: Get<Percent>;
+
+        /// 验证通过所需的赞成票占全部投票的比例
+        #[pallet::constant]
+        type VerificationThreshold: Get<Percent>;
+
+        /// 验证结果有效所需的最少投票数，不足时任务退回进行中
+        #[pallet::constant]
+        type MinVerificationVotes: Get<u32>;
+
+        /// 每个验证轮次抽取的陪审员数量，也是最多接受的投票数
+        #[pallet::constant]
+        type MaxVerifiers: Get<u32>;
+
+        /// 登记为陪审员所需的最低质押
+        #[pallet::constant]
+        type MinJurorStake: Get<Self::Balance>;
+
+        /// 陪审员池的最大人数
+        #[pallet::constant]
+        type MaxJurors: Get<u32>;
+
+        /// 同一区块最多结束的验证轮次数量
+        #[pallet::constant]
+        type MaxVerificationsPerBlock: Get<u32>;
+
+        /// 随机数生成器，用于抽取验证小组
+        ///
+        /// 验证小组在提交验证之后的区块抽取，提交时使用的随机数对抽取结果没有影响。
         type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// 任务完成或取消后，创建者可以重新打开任务的时间窗口（毫秒）
+        #[pallet::constant]
+        type ReopenWindow: Get<Self::Moment>;
+
+        /// 分配邀请的有效期（毫秒），过期后执行者无法再接受
+        #[pallet::constant]
+        type AssignmentOfferPeriod: Get<Self::Moment>;
+
+        /// 每个任务最多同时存在的申请数量
+        #[pallet::constant]
+        type MaxApplicants: Get<u32>;
+
+        /// 申请附言的最大长度
+        #[pallet::constant]
+        type MaxApplicationNoteLength: Get<u32>;
+
+        /// 每个任务最多的协作执行者数量
+        #[pallet::constant]
+        type MaxCoAssignees: Get<u32>;
+
+        /// 每个任务最多的里程碑数量
+        #[pallet::constant]
+        type MaxMilestones: Get<u32>;
+
+        /// 罚没的执行者保证金转入的账户，为 `None` 时转给任务创建者
+        type BondBeneficiary: Get<Option<Self::AccountId>>;
+
+        /// 执行者临近截止时间辞去任务时向创建者支付的违约金，为零时不收取
+        #[pallet::constant]
+        type ResignationPenalty: Get<Self::Balance>;
+
+        /// 距离截止时间不足该时长（毫秒）或已逾期时辞去任务需要支付违约金，没有截止时间的任务不收取
+        #[pallet::constant]
+        type ResignationPenaltyWindow: Get<Self::Moment>;
+
+        /// 组织成员角色查询，用于组织任务的权限检查
+        type Organizations: OrganizationProvider<Self::AccountId>;
+
+        /// 任务生命周期回调，例如声誉系统
+        type LifecycleHandler: TaskLifecycleHandler<Self::AccountId>;
+
+        /// 本 pallet 各调用的权重
+        type WeightInfo: WeightInfo;
     }
 
     /// 任务存储映射
@@ -147,6 +666,67 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// 可认领任务索引：当前公开且未分配的未结束任务ID
+    #[pallet::storage]
+    pub type ClaimableTasks<T: Config> = StorageMap<_, Twox64Concat, u32, (), OptionQuery>;
+
+    /// 分配邀请：任务ID => 待执行者接受的邀请
+    ///
+    /// 执行者接受或拒绝、任务被分配、结束或删除时移除；过期的邀请保留到被替换或移除为止。
+    #[pallet::storage]
+    pub type AssignmentOffers<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, AssignmentOffer<T>, OptionQuery>;
+
+    /// 协作邀请：任务ID => 待接受的协作执行者及其份额
+    ///
+    /// 协作执行者接受后移入任务的协作执行者列表；创建者重新设置协作执行者、执行者离开、
+    /// 任务结束或删除时移除。
+    #[pallet::storage]
+    pub type CoAssigneeOffers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<(T::AccountId, Percent), T::MaxCoAssignees>,
+        ValueQuery,
+    >;
+
+    /// 任务里程碑：任务ID => 按顺序排列的里程碑
+    ///
+    /// 里程碑按顺序提交和批准，任务删除时移除。
+    #[pallet::storage]
+    pub type Milestones<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<Milestone<T>, T::MaxMilestones>,
+        ValueQuery,
+    >;
+
+    /// 任务完成时实际支付给协作执行者的份额：任务ID => (协作执行者, 金额) 列表
+    ///
+    /// 低于存在性押金的份额是否归入执行者取决于支付时账户是否存在，重新打开时按这里记录的拆分收回。
+    /// 任务重新打开或删除时移除。
+    #[pallet::storage]
+    pub type PaidShares<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<(T::AccountId, T::Balance), T::MaxCoAssignees>,
+        ValueQuery,
+    >;
+
+    /// 任务申请：任务ID => 按申请顺序排列的申请列表
+    ///
+    /// 任务被分配、结束或删除时清空。
+    #[pallet::storage]
+    pub type Applications<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<Application<T>, T::MaxApplicants>,
+        ValueQuery,
+    >;
+
     /// 下一个任务ID
     #[pallet::storage]
     #[pallet::getter(fn next_task_id)]
@@ -157,6 +737,108 @@ pub mod pallet {
     #[pallet::getter(fn task_count_by_status)]
     pub type TaskCountByStatus<T> = StorageMap<_, Blake2_128Concat, TaskStatus, u32, ValueQuery>;
 
+    /// 按优先级统计的任务数量
+    #[pallet::storage]
+    pub type TaskCountByPriority<T> = StorageMap<_, Blake2_128Concat, Priority, u32, ValueQuery>;
+
+    /// 按难度分段统计的任务数量
+    #[pallet::storage]
+    pub type TaskCountByDifficulty<T> =
+        StorageMap<_, Blake2_128Concat, DifficultyBand, u32, ValueQuery>;
+
+    /// 每个账户的累计任务统计
+    #[pallet::storage]
+    pub type AccountStats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, AccountTaskStats, ValueQuery>;
+
+    /// 子任务索引：父任务ID => 直接子任务ID列表
+    #[pallet::storage]
+    pub type TaskChildren<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BoundedVec<u32, T::MaxSubtasks>, ValueQuery>;
+
+    /// 依赖索引：任务ID => 阻塞它的前置任务ID列表
+    ///
+    /// 只保存双方都未结束的依赖，任何一方结束或被删除时依赖随即移除，
+    /// 因此列表非空即表示任务仍被阻塞。
+    #[pallet::storage]
+    pub type TaskDependencies<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BoundedVec<u32, T::MaxDependencies>, ValueQuery>;
+
+    /// 反向依赖索引：前置任务ID => 依赖它的任务ID列表
+    #[pallet::storage]
+    pub type TaskDependents<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BoundedVec<u32, T::MaxDependencies>, ValueQuery>;
+
+    /// 截止时间索引：分桶编号 (deadline / ExpiryBucketSize) => 任务ID列表
+    #[pallet::storage]
+    pub type ExpiryQueue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Moment,
+        BoundedVec<u32, T::MaxTasksPerExpiryBucket>,
+        ValueQuery,
+    >;
+
+    /// 下一个待处理的截止时间分桶，早于它的分桶都已处理完毕
+    #[pallet::storage]
+    pub type NextExpiryBucket<T: Config> = StorageValue<_, T::Moment, OptionQuery>;
+
+    /// 进行中的验证轮次：任务ID => 轮次
+    #[pallet::storage]
+    pub type VerificationRounds<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, VerificationRound<T>>;
+
+    /// 陪审员池，按登记顺序排列
+    #[pallet::storage]
+    pub type Jurors<T: Config> = StorageValue<_, BoundedVec<Juror<T>, T::MaxJurors>, ValueQuery>;
+
+    /// 验证截止索引：截止区块 => 在该区块统计结果的任务ID列表
+    #[pallet::storage]
+    pub type VerificationQueue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u32, T::MaxVerificationsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// 验证小组抽取索引：抽取区块 => 在该区块开始时抽取验证小组的任务ID列表
+    #[pallet::storage]
+    pub type PanelDrawQueue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u32, T::MaxVerificationsPerBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// 创世任务：(创建者, 标题, 描述, 优先级, 难度, 奖励)
+        pub tasks: Vec<(T::AccountId, Vec<u8>, Vec<u8>, Priority, u8, T::Balance)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (creator, title, description, priority, difficulty, reward) in &self.tasks {
+                Pallet::<T>::do_create_task(
+                    creator.clone(),
+                    title.clone(),
+                    description.clone(),
+                    priority.clone(),
+                    *difficulty,
+                    *reward,
+                    None,
+                    None,
+                    None,
+                )
+                .expect("genesis tasks must be valid");
+            }
+        }
+    }
+
     // Pallets use events to inform users when important changes are made.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -175,11 +857,77 @@ pub mod pallet {
             old_status: TaskStatus,
             new_status: TaskStatus,
         },
-        /// 任务已分配给执行者
+        /// 创建者已邀请执行者接受任务
+        AssignmentOffered {
+            task_id: u32,
+            assignee: T::AccountId,
+            expires_at: T::Moment,
+            bond: T::Balance,
+        },
+        /// 执行者已拒绝分配邀请
+        AssignmentDeclined {
+            task_id: u32,
+            assignee: T::AccountId,
+        },
+        /// 执行者已接受分配邀请，任务已分配给执行者
         TaskAssigned {
             task_id: u32,
             assignee: T::AccountId,
         },
+        /// 任务已被执行者自行认领
+        TaskClaimed {
+            task_id: u32,
+            assignee: T::AccountId,
+        },
+        /// 任务的公开状态已更改
+        TaskOpenChanged { task_id: u32, open: bool },
+        /// 已申请执行任务
+        ApplicationSubmitted {
+            task_id: u32,
+            applicant: T::AccountId,
+            reward: Option<T::Balance>,
+        },
+        /// 创建者已接受申请，任务分配给申请者
+        ApplicationAccepted {
+            task_id: u32,
+            applicant: T::AccountId,
+            reward: T::Balance,
+        },
+        /// 执行者已辞去任务，`penalty` 为支付给创建者的违约金
+        TaskResigned {
+            task_id: u32,
+            assignee: T::AccountId,
+            penalty: T::Balance,
+        },
+        /// 任务的里程碑已更新，`count` 为里程碑数量
+        MilestonesSet { task_id: u32, count: u32 },
+        /// 执行者已提交里程碑
+        MilestoneSubmitted { task_id: u32, index: u32 },
+        /// 创建者已批准里程碑，`amount` 已从托管奖励中支付
+        MilestoneApproved {
+            task_id: u32,
+            index: u32,
+            amount: T::Balance,
+        },
+        /// 创建者退回了已提交的里程碑
+        MilestoneRejected { task_id: u32, index: u32 },
+        /// 已以组织名义创建任务
+        OrgTaskCreated { task_id: u32, org_id: u32 },
+        /// 协作执行者及其奖励份额已更新，新加入的账户需要接受邀请后才成为协作执行者
+        CoAssigneesSet {
+            task_id: u32,
+            co_assignees: BoundedVec<(T::AccountId, Percent), T::MaxCoAssignees>,
+        },
+        /// 协作执行者已接受邀请
+        CoAssignmentAccepted {
+            task_id: u32,
+            co_assignee: T::AccountId,
+        },
+        /// 协作执行者已拒绝邀请
+        CoAssignmentDeclined {
+            task_id: u32,
+            co_assignee: T::AccountId,
+        },
         /// 任务分配已取消
         TaskUnassigned {
             task_id: u32,
@@ -190,6 +938,97 @@ pub mod pallet {
             task_id: u32,
             deleted_by: T::AccountId,
         },
+        /// 奖励已从创建者账户冻结托管
+        RewardHeld {
+            task_id: u32,
+            who: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 托管奖励已支付给执行者
+        RewardPaid {
+            task_id: u32,
+            to: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 托管奖励已退还给创建者
+        RewardRefunded {
+            task_id: u32,
+            to: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 执行者的保证金已释放
+        BondReleased {
+            task_id: u32,
+            assignee: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 执行者的保证金已罚没并转入 `to`
+        BondSlashed {
+            task_id: u32,
+            assignee: T::AccountId,
+            to: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 任务重新打开，已支付的奖励已从执行者收回并重新托管
+        RewardReclaimed {
+            task_id: u32,
+            from: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 任务已超过截止时间，自动过期
+        TaskExpired { task_id: u32 },
+        /// 已在父任务下创建子任务
+        SubtaskCreated { parent_id: u32, task_id: u32 },
+        /// 已添加任务依赖：`task_id` 需要等待 `blocker_id` 结束
+        DependencyAdded { task_id: u32, blocker_id: u32 },
+        /// 已移除任务依赖
+        DependencyRemoved { task_id: u32, blocker_id: u32 },
+        /// 任务的全部前置任务都已结束，可以开始
+        TaskUnblocked { task_id: u32 },
+        /// 已为待验证的任务抽取验证小组，陪审员可以提交投票承诺
+        VerificationStarted {
+            task_id: u32,
+            commit_ends: BlockNumberFor<T>,
+            ends_at: BlockNumberFor<T>,
+            panel: Vec<T::AccountId>,
+        },
+        /// 没有可抽取的陪审员，任务需要由创建者确认完成
+        NoJurorsAvailable { task_id: u32 },
+        /// 陪审员已登记并冻结质押
+        JurorRegistered {
+            who: T::AccountId,
+            stake: T::Balance,
+        },
+        /// 陪审员已退出，质押已释放
+        JurorUnregistered {
+            who: T::AccountId,
+            stake: T::Balance,
+        },
+        /// 陪审员已提交投票承诺
+        VoteCommitted { task_id: u32, juror: T::AccountId },
+        /// 陪审员已揭示投票
+        VoteRevealed {
+            task_id: u32,
+            juror: T::AccountId,
+            approve: bool,
+        },
+        /// 陪审员没有揭示投票，部分质押已被罚没
+        JurorSlashed {
+            task_id: u32,
+            juror: T::AccountId,
+            amount: T::Balance,
+        },
+        /// 验证轮次已结束，任务已完成或退回进行中
+        VerificationConcluded {
+            task_id: u32,
+            outcome: VerificationOutcome,
+        },
+        /// 已完成或已取消的任务已重新打开
+        TaskReopened {
+            task_id: u32,
+            previous_status: TaskStatus,
+            new_status: TaskStatus,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -215,6 +1054,145 @@ pub mod pallet {
         TaskNotAssigned,
         /// 不能分配给自己创建的任务
         CannotAssignToSelf,
+        /// 截止时间早于当前区块时间
+        DeadlineInPast,
+        /// 任务奖励已结算，不能再修改
+        RewardAlreadySettled,
+        /// 该截止时间所在分桶已满，请选择其他截止时间
+        ExpiryQueueFull,
+        /// 父任务已结束，不能再添加子任务
+        ParentNotOpen,
+        /// 任务层级超过最大深度
+        MaxDepthExceeded,
+        /// 子任务数量达到上限
+        TooManySubtasks,
+        /// 仍有未结束的子任务，不能完成父任务
+        ChildrenIncomplete,
+        /// 任务仍有子任务，不能删除
+        TaskHasSubtasks,
+        /// 任务不能依赖自身
+        SelfDependency,
+        /// 依赖已存在
+        DependencyAlreadyExists,
+        /// 依赖不存在
+        DependencyNotFound,
+        /// 添加该依赖会形成循环
+        CyclicDependency,
+        /// 依赖数量达到上限
+        TooManyDependencies,
+        /// 依赖关系过于复杂，环检测超过访问上限
+        DependencyGraphTooLarge,
+        /// 前置任务已结束，无需依赖
+        BlockerNotOpen,
+        /// 任务已经开始，不能再添加前置任务
+        TaskAlreadyStarted,
+        /// 前置任务尚未结束，任务不能开始
+        BlockedByDependencies,
+        /// 任务没有进行中的验证轮次
+        NotUnderVerification,
+        /// 验证轮次进行中，任务状态由投票结果决定
+        VerificationInProgress,
+        /// 任务的创建者、执行者和协作执行者不能参与验证
+        CannotVerifyOwnTask,
+        /// 不在该任务的验证小组中
+        NotOnPanel,
+        /// 已经登记为陪审员
+        AlreadyJuror,
+        /// 不是陪审员
+        NotJuror,
+        /// 陪审员池已满
+        TooManyJurors,
+        /// 质押低于最低要求
+        StakeTooLow,
+        /// 陪审员仍在进行中的验证小组中，不能退出
+        JurorOnPanel,
+        /// 已经提交过投票承诺
+        AlreadyCommitted,
+        /// 承诺阶段已结束
+        CommitPhaseClosed,
+        /// 揭示阶段尚未开始
+        RevealPhaseNotStarted,
+        /// 没有提交过投票承诺
+        NoCommitment,
+        /// 已经揭示过投票
+        AlreadyRevealed,
+        /// 揭示的意见和盐与承诺不符
+        InvalidReveal,
+        /// 验证轮次的投票数达到上限
+        TooManyVotes,
+        /// 该区块结束的验证轮次已满，请稍后再提交验证
+        VerificationQueueFull,
+        /// 只有已完成或已取消的任务可以重新打开
+        NotReopenable,
+        /// 任务结束已超过重新打开的时间窗口
+        ReopenWindowExpired,
+        /// 任务已结束
+        TaskNotOpen,
+        /// 任务未公开、已分配或不处于可认领的状态
+        TaskNotClaimable,
+        /// 申请附言过长
+        NoteTooLong,
+        /// 已经申请过该任务
+        AlreadyApplied,
+        /// 任务的申请数量达到上限
+        TooManyApplications,
+        /// 申请不存在
+        ApplicationNotFound,
+        /// 任务有尚未过期的分配邀请
+        AssignmentPending,
+        /// 调用者没有该任务的分配邀请
+        NoAssignmentOffer,
+        /// 分配邀请已过期
+        AssignmentOfferExpired,
+        /// 协作执行者数量超过上限
+        TooManyCoAssignees,
+        /// 协作执行者重复或与执行者相同
+        DuplicateCoAssignee,
+        /// 份额为零或份额总和超过 100%
+        InvalidShares,
+        /// 里程碑数量超过上限
+        TooManyMilestones,
+        /// 未支付的里程碑金额总和超过托管奖励
+        MilestonesExceedReward,
+        /// 已有里程碑提交或批准，不能再修改里程碑
+        MilestonesLocked,
+        /// 里程碑不存在
+        MilestoneNotFound,
+        /// 里程碑必须按顺序提交和批准
+        MilestoneOutOfOrder,
+        /// 里程碑尚未提交
+        MilestoneNotSubmitted,
+        /// 里程碑已提交，等待创建者处理
+        MilestoneAlreadySubmitted,
+        /// 调用者不是组织成员，或角色不足以以组织名义创建任务
+        NotOrgMember,
+        /// 任务不能依赖自己的祖先或后代任务
+        RelatedTaskDependency,
+        /// 任务已分配或有待接受的邀请，截止时间不能提前
+        DeadlineMovedEarlier,
+        /// 调用者没有该任务的协作邀请
+        NoCoAssignmentOffer,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// 处理已过截止时间的任务，为上一个区块提交的验证抽取验证小组，
+        /// 并统计在本区块结束的验证轮次
+        ///
+        /// 在时间戳 inherent 之前执行，因此使用的是上一个区块的时间。
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let steps =
+                Self::process_expiries(T::TimeProvider::now(), T::MaxExpiriesPerBlock::get());
+            let draws = Self::process_panel_draws(n);
+            let rounds = Self::process_verifications(n);
+            T::WeightInfo::process_expiries(steps)
+                .saturating_add(T::WeightInfo::process_panel_draws(draws))
+                .saturating_add(T::WeightInfo::process_verifications(rounds))
+                // 验证通过的任务会触发完成回调
+                .saturating_add(
+                    T::LifecycleHandler::on_task_completed_weight().saturating_mul(rounds.into()),
+                )
+        }
     }
 
     // Dispatchable functions allow users to interact with the pallet and invoke state changes.
@@ -222,7 +1200,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// 创建新任务
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::create_task(title.len() as u32, description.len() as u32))]
         pub fn create_task(
             origin: OriginFor<T>,
             title: Vec<u8>,
@@ -233,84 +1211,28 @@ pub mod pallet {
             deadline: Option<T::Moment>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            // 验证输入参数
-            ensure!(
-                title.len() <= T::MaxTitleLength::get() as usize,
-                Error::<T>::TitleTooLong
-            );
-            ensure!(
-                description.len() <= T::MaxDescriptionLength::get() as usize,
-                Error::<T>::DescriptionTooLong
-            );
-            ensure!(
-                difficulty >= 1 && difficulty <= 10,
-                Error::<T>::InvalidDifficulty
-            );
-
-            // 检查用户任务数量限制
-            let user_tasks = UserCreatedTasks::<T>::get(&who);
-            ensure!(
-                user_tasks.len() < T::MaxTasksPerUser::get() as usize,
-                Error::<T>::TooManyTasks
-            );
-
-            // 生成任务ID
-            let task_id = NextTaskId::<T>::get();
-            NextTaskId::<T>::put(task_id.saturating_add(1));
-
-            // 获取当前时间戳（这里简化处理，实际应该从时间戳 pallet 获取）
-            let now = T::Moment::zero(); // 实际应该替换为真实时间戳
-
-            // 创建任务
-            let task = Task {
-                id: task_id,
-                creator: who.clone(),
-                assignee: None,
-                title: title
-                    .clone()
-                    .try_into()
-                    .map_err(|_| Error::<T>::TitleTooLong)?,
-                description: description
-                    .try_into()
-                    .map_err(|_| Error::<T>::DescriptionTooLong)?,
-                status: TaskStatus::Pending,
-                priority,
-                difficulty,
-                reward,
-                created_at: now,
-                updated_at: now,
-                deadline,
-            };
-
-            // 存储任务
-            Tasks::<T>::insert(&task_id, &task);
-
-            // 更新用户创建的任务列表
-            UserCreatedTasks::<T>::try_mutate(&who, |tasks| {
-                tasks
-                    .try_push(task_id)
-                    .map_err(|_| Error::<T>::TooManyTasks)
-            })?;
-
-            // 更新状态统计
-            TaskCountByStatus::<T>::mutate(TaskStatus::Pending, |count| {
-                *count = count.saturating_add(1);
-            });
-
-            // 触发事件
-            Self::deposit_event(Event::TaskCreated {
-                task_id,
-                creator: who,
-                title,
-            });
-
-            Ok(())
-        }
+            Self::do_create_task(
+                who,
+                title,
+                description,
+                priority,
+                difficulty,
+                reward,
+                deadline,
+                None,
+                None,
+            )?;
+            Ok(())
+        }
 
         /// 更新任务信息
+        ///
+        /// 已分配的任务只能延后或取消截止时间。
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_task(
+            title.as_ref().map_or(0, |t| t.len() as u32),
+            description.as_ref().map_or(0, |d| d.len() as u32),
+        ))]
         pub fn update_task(
             origin: OriginFor<T>,
             task_id: u32,
@@ -326,8 +1248,14 @@ pub mod pallet {
             // 获取任务
             let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
 
-            // 权限检查：只有创建者可以更新任务
-            ensure!(task.creator == who, Error::<T>::NotAuthorized);
+            // 权限检查：个人任务的创建者或组织任务的管理者可以更新任务
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            let old_priority = task.priority.clone();
+            let old_difficulty = task.difficulty;
 
             // 更新字段
             if let Some(new_title) = title {
@@ -361,15 +1289,51 @@ pub mod pallet {
             }
 
             if let Some(new_reward) = reward {
+                // 奖励托管在创建者账户中，只有创建者可以修改
+                ensure!(task.creator == who, Error::<T>::NotAuthorized);
+                ensure!(
+                    Self::is_reward_held(&task.status),
+                    Error::<T>::RewardAlreadySettled
+                );
+                Self::adjust_reward_hold(&task, new_reward)?;
                 task.reward = new_reward;
             }
 
+            let now = T::TimeProvider::now();
+
             if let Some(new_deadline) = deadline {
+                if let Some(new_deadline) = new_deadline {
+                    ensure!(new_deadline > now, Error::<T>::DeadlineInPast);
+                }
+                // 已分配或有待接受邀请的任务不能提前截止时间（包括新增截止时间），
+                // 否则执行者会因任务过期被罚没保证金
+                if task.assignee.is_some() || AssignmentOffers::<T>::contains_key(task_id) {
+                    let moved_earlier = match (task.deadline, new_deadline) {
+                        (_, None) => false,
+                        (None, Some(_)) => true,
+                        (Some(old_deadline), Some(new_deadline)) => new_deadline < old_deadline,
+                    };
+                    ensure!(!moved_earlier, Error::<T>::DeadlineMovedEarlier);
+                }
+                if let Some(old_deadline) = task.deadline {
+                    Self::unschedule_expiry(task_id, old_deadline);
+                }
+                if let Some(new_deadline) = new_deadline {
+                    Self::schedule_expiry(task_id, new_deadline)?;
+                }
                 task.deadline = new_deadline;
             }
 
             // 更新时间戳
-            task.updated_at = T::Moment::zero(); // 实际应该替换为真实时间戳
+            task.updated_at = now;
+
+            // 更新优先级和难度统计
+            if task.priority != old_priority
+                || DifficultyBand::of(task.difficulty) != DifficultyBand::of(old_difficulty)
+            {
+                Self::track_attributes(&old_priority, old_difficulty, false);
+                Self::track_attributes(&task.priority, task.difficulty, true);
+            }
 
             // 保存任务
             Tasks::<T>::insert(&task_id, &task);
@@ -385,201 +1349,2614 @@ pub mod pallet {
 
         /// 更改任务状态
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(
+            T::WeightInfo::change_task_status(
+                T::MaxSubtasks::get(),
+                T::MaxDependencies::get().saturating_mul(2),
+            )
+            .saturating_add(T::WeightInfo::start_verification())
+            .saturating_add(
+                T::LifecycleHandler::on_task_completed_weight()
+                    .max(T::LifecycleHandler::on_task_cancelled_by_assignee_weight())
+            )
+        )]
         pub fn change_task_status(
             origin: OriginFor<T>,
             task_id: u32,
             new_status: TaskStatus,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             // 获取任务
             let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
 
-            // 权限检查：创建者或分配者可以更改状态
+            // 权限检查：创建者、组织管理者或分配者可以更改状态
             ensure!(
-                task.creator == who || task.assignee == Some(who.clone()),
+                Self::can_manage(&task, &who, OrgRole::Manager)
+                    || task.assignee == Some(who.clone()),
                 Error::<T>::NotAuthorized
             );
 
-            let old_status = task.status.clone();
+            // 验证轮次进行中时，状态只能由投票结果改变
+            ensure!(
+                !VerificationRounds::<T>::contains_key(task_id),
+                Error::<T>::VerificationInProgress
+            );
+
+            // 完成任务会把托管奖励支付给执行者，只能由个人任务的创建者或组织任务的管理者确认；
+            // 执行者只能提交验证，由验证结果或创建者决定是否完成
+            if new_status == TaskStatus::Completed {
+                ensure!(
+                    Self::can_manage(&task, &who, OrgRole::Manager),
+                    Error::<T>::NotAuthorized
+                );
+            }
 
             // 验证状态转换的合法性
-            Self::validate_status_transition(&old_status, &new_status)?;
+            Self::validate_status_transition(&task.status, &new_status)?;
 
-            // 更新统计
-            TaskCountByStatus::<T>::mutate(&old_status, |count| {
-                *count = count.saturating_sub(1);
-            });
-            TaskCountByStatus::<T>::mutate(&new_status, |count| {
-                *count = count.saturating_add(1);
-            });
+            // 前置任务全部结束之前，任务只能保持待处理或被取消
+            if task.status == TaskStatus::Pending
+                && !matches!(new_status, TaskStatus::Pending | TaskStatus::Cancelled)
+            {
+                ensure!(
+                    TaskDependencies::<T>::get(task_id).is_empty(),
+                    Error::<T>::BlockedByDependencies
+                );
+            }
 
-            // 更新任务状态和时间戳
-            task.status = new_status.clone();
-            task.updated_at = T::Moment::zero(); // 实际应该替换为真实时间戳
+            // 父任务只有在所有子任务结束后才能完成或提交验证
+            let children = if matches!(
+                new_status,
+                TaskStatus::Completed | TaskStatus::PendingVerification
+            ) {
+                Self::ensure_children_closed(task_id)?
+            } else {
+                0
+            };
+            let edges = Self::dependency_edges(task_id);
+            let mut weight = T::WeightInfo::change_task_status(children, edges);
+            if new_status == TaskStatus::PendingVerification {
+                weight = weight.saturating_add(T::WeightInfo::start_verification());
+            }
+            if new_status == TaskStatus::Completed {
+                weight = weight.saturating_add(T::LifecycleHandler::on_task_completed_weight());
+            }
+
+            // 执行者主动取消任务
+            let cancelled_by_assignee =
+                new_status == TaskStatus::Cancelled && task.assignee.as_ref() == Some(&who);
+            if cancelled_by_assignee {
+                Self::slash_bond(&mut task)?;
+                weight = weight
+                    .saturating_add(T::LifecycleHandler::on_task_cancelled_by_assignee_weight());
+            }
+
+            // 更新状态、统计并结算托管奖励
+            Self::do_transition(&mut task, new_status)?;
+
+            if cancelled_by_assignee {
+                if let Some(summary) = Self::summary(&task) {
+                    T::LifecycleHandler::on_task_cancelled_by_assignee(&summary);
+                }
+            }
 
             // 保存任务
             Tasks::<T>::insert(&task_id, &task);
 
-            // 触发事件
-            Self::deposit_event(Event::TaskStatusChanged {
-                task_id,
-                old_status,
-                new_status,
-            });
-
-            Ok(())
+            Ok(Some(weight).into())
         }
 
-        /// 分配任务给执行者
+        /// 邀请执行者接受任务
+        ///
+        /// 执行者在 `AssignmentOfferPeriod` 内调用 `accept_assignment` 后任务才会分配给执行者。
+        /// 已过期的邀请可以被新的邀请替换。`bond` 为接受时需要冻结的保证金，为零时不要求保证金。
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::assign_task())]
         pub fn assign_task(
             origin: OriginFor<T>,
             task_id: u32,
             assignee: T::AccountId,
+            bond: T::Balance,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             // 获取任务
-            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
 
-            // 权限检查：只有创建者可以分配任务
-            ensure!(task.creator == who, Error::<T>::NotAuthorized);
+            // 权限检查：个人任务的创建者或组织任务的管理者可以分配任务
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            // 不能分配给创建者或调用者自己
+            ensure!(
+                task.creator != assignee && who != assignee,
+                Error::<T>::CannotAssignToSelf
+            );
 
-            // 不能分配给自己
-            ensure!(task.creator != assignee, Error::<T>::CannotAssignToSelf);
+            // 只能分配未结束且不在验证中的任务
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::TaskNotOpen
+            );
 
             // 检查任务是否已经分配
             ensure!(task.assignee.is_none(), Error::<T>::TaskAlreadyAssigned);
 
-            // 检查分配者的任务数量限制
-            let assignee_tasks = UserAssignedTasks::<T>::get(&assignee);
+            // 同一时间只能有一个有效的邀请
+            let now = T::TimeProvider::now();
             ensure!(
-                assignee_tasks.len() < T::MaxTasksPerUser::get() as usize,
-                Error::<T>::TooManyTasks
+                !Self::has_pending_offer(task_id, now),
+                Error::<T>::AssignmentPending
             );
 
-            // 分配任务
-            task.assignee = Some(assignee.clone());
-            task.updated_at = T::Moment::zero(); // 实际应该替换为真实时间戳
+            let expires_at = now.saturating_add(T::AssignmentOfferPeriod::get());
+            AssignmentOffers::<T>::insert(
+                task_id,
+                AssignmentOffer {
+                    assignee: assignee.clone(),
+                    expires_at,
+                    bond,
+                },
+            );
 
-            // 保存任务
-            Tasks::<T>::insert(&task_id, &task);
+            // 触发事件
+            Self::deposit_event(Event::AssignmentOffered {
+                task_id,
+                assignee,
+                expires_at,
+                bond,
+            });
 
-            // 更新分配者的任务列表
-            UserAssignedTasks::<T>::try_mutate(&assignee, |tasks| {
-                tasks
-                    .try_push(task_id)
-                    .map_err(|_| Error::<T>::TooManyTasks)
-            })?;
+            Ok(())
+        }
+
+        /// 接受分配邀请，任务分配给调用者
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::accept_assignment())]
+        pub fn accept_assignment(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务和邀请
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+            let offer = AssignmentOffers::<T>::get(task_id)
+                .filter(|offer| offer.assignee == who)
+                .ok_or(Error::<T>::NoAssignmentOffer)?;
+            ensure!(
+                T::TimeProvider::now() <= offer.expires_at,
+                Error::<T>::AssignmentOfferExpired
+            );
+            ensure!(task.assignee.is_none(), Error::<T>::TaskAlreadyAssigned);
+            // 邀请发出后任务可能已经结束
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::TaskNotOpen
+            );
+
+            // 冻结邀请要求的保证金
+            if !offer.bond.is_zero() {
+                T::Currency::hold(&HoldReason::AssigneeBond.into(), &who, offer.bond)?;
+            }
+            task.bond = offer.bond;
+
+            // 分配任务，同时移除邀请
+            Self::do_assign(&mut task, &who)?;
 
             // 触发事件
-            Self::deposit_event(Event::TaskAssigned { task_id, assignee });
+            Self::deposit_event(Event::TaskAssigned {
+                task_id,
+                assignee: who,
+            });
 
             Ok(())
         }
 
-        /// 取消任务分配
-        #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
-        pub fn unassign_task(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+        /// 拒绝分配邀请，过期的邀请也可以拒绝
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::decline_assignment())]
+        pub fn decline_assignment(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            AssignmentOffers::<T>::get(task_id)
+                .filter(|offer| offer.assignee == who)
+                .ok_or(Error::<T>::NoAssignmentOffer)?;
+            AssignmentOffers::<T>::remove(task_id);
+
+            // 触发事件
+            Self::deposit_event(Event::AssignmentDeclined {
+                task_id,
+                assignee: who,
+            });
+
+            Ok(())
+        }
+
+        /// 执行者辞去任务，任务回到待处理状态；协作执行者辞去时只退出协作，任务保持分配
+        ///
+        /// 临近截止时间辞去时向创建者支付 `ResignationPenalty`，余额不足时支付全部可用余额。
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::resign_from_task(T::MaxTasksPerUser::get())
+            .saturating_add(T::WeightInfo::set_co_assignees(T::MaxCoAssignees::get())))]
+        pub fn resign_from_task(origin: OriginFor<T>, task_id: u32) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             // 获取任务
             let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
 
-            // 权限检查：只有创建者可以取消分配
-            ensure!(task.creator == who, Error::<T>::NotAuthorized);
+            // 权限检查：只有执行者或协作执行者可以辞去任务
+            let co_index = task
+                .co_assignees
+                .iter()
+                .position(|(account, _)| account == &who);
+            ensure!(
+                task.assignee.as_ref() == Some(&who) || co_index.is_some(),
+                Error::<T>::NotAuthorized
+            );
 
-            // 检查任务是否已分配
-            let previous_assignee = task.assignee.take().ok_or(Error::<T>::TaskNotAssigned)?;
+            // 验证中或已结束的任务不能辞去
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::InvalidStatusTransition
+            );
 
-            // 更新时间戳
-            task.updated_at = T::Moment::zero(); // 实际应该替换为真实时间戳
+            let now = T::TimeProvider::now();
+            let late = task.deadline.map_or(false, |deadline| {
+                now.saturating_add(T::ResignationPenaltyWindow::get()) >= deadline
+            });
+            let penalty = if late {
+                Self::charge_resignation_penalty(&who, &task.creator)?
+            } else {
+                Zero::zero()
+            };
 
-            // 保存任务
+            let removed = match co_index {
+                // 协作执行者退出，其份额归还执行者
+                Some(index) => {
+                    task.co_assignees.remove(index);
+                    0
+                }
+                // 放弃任务，罚没保证金，取消分配并回到待处理状态
+                None => {
+                    Self::slash_bond(&mut task)?;
+                    task.assignee = None;
+                    if task.status != TaskStatus::Pending {
+                        Self::do_transition(&mut task, TaskStatus::Pending)?;
+                    }
+                    Self::withdraw_submitted_milestone(task_id);
+                    Self::remove_co_assignees(&mut task)
+                }
+            };
+            task.updated_at = now;
+
+            // 保存任务，公开任务重新变为可认领
             Tasks::<T>::insert(&task_id, &task);
+            Self::update_claimable(&task);
 
-            // 从分配者的任务列表中移除
-            UserAssignedTasks::<T>::mutate(&previous_assignee, |tasks| {
+            // 从执行者的任务列表中移除
+            let assigned_len = UserAssignedTasks::<T>::mutate(&who, |tasks| {
+                let len = tasks.len() as u32;
                 tasks.retain(|&id| id != task_id);
+                len
             });
 
             // 触发事件
-            Self::deposit_event(Event::TaskUnassigned {
+            Self::deposit_event(Event::TaskResigned {
                 task_id,
-                previous_assignee,
+                assignee: who,
+                penalty,
             });
 
-            Ok(())
+            Ok(Some(
+                T::WeightInfo::resign_from_task(assigned_len)
+                    .saturating_add(T::WeightInfo::set_co_assignees(removed)),
+            )
+            .into())
         }
 
-        /// 删除任务
-        #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
-        pub fn delete_task(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+        /// 设置协作执行者及其奖励份额，传入空列表会移除全部协作执行者
+        ///
+        /// 个人任务的创建者或组织任务的管理者可以设置，任务必须已分配且处于待处理或进行中。
+        /// 已接受的协作执行者保留并使用新的份额，新加入的账户收到协作邀请，
+        /// 调用 `accept_co_assignment` 后才加入任务；
+        /// 未列出的协作执行者和邀请被移除。任务完成时协作执行者按各自份额分得奖励，
+        /// 执行者获得其余部分（包括尚未接受的邀请的份额）。
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_co_assignees(T::MaxCoAssignees::get().saturating_mul(2)))]
+        pub fn set_co_assignees(
+            origin: OriginFor<T>,
+            task_id: u32,
+            co_assignees: Vec<(T::AccountId, Percent)>,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             // 获取任务
-            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
 
-            // 权限检查：只有创建者可以删除任务
-            ensure!(task.creator == who, Error::<T>::NotAuthorized);
+            // 权限检查：个人任务的创建者或组织任务的管理者可以设置协作执行者
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
 
-            // 从存储中删除任务
-            Tasks::<T>::remove(&task_id);
+            // 验证中或已结束的任务不能调整分配
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::InvalidStatusTransition
+            );
+            let assignee = task.assignee.clone().ok_or(Error::<T>::TaskNotAssigned)?;
+
+            // 验证协作执行者和份额
+            let co_assignees: BoundedVec<_, T::MaxCoAssignees> = co_assignees
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyCoAssignees)?;
+            let mut total: u32 = 0;
+            for (index, (account, share)) in co_assignees.iter().enumerate() {
+                ensure!(*account != task.creator, Error::<T>::CannotAssignToSelf);
+                ensure!(
+                    *account != assignee
+                        && !co_assignees
+                            .iter()
+                            .take(index)
+                            .any(|(other, _)| other == account),
+                    Error::<T>::DuplicateCoAssignee
+                );
+                ensure!(!share.is_zero(), Error::<T>::InvalidShares);
+                total = total.saturating_add(share.deconstruct().into());
+            }
+            ensure!(total <= 100, Error::<T>::InvalidShares);
+
+            // 已接受的协作执行者更新份额，其余账户需要接受邀请
+            let previous = sp_std::mem::take(&mut task.co_assignees);
+            let mut offers = BoundedVec::<_, T::MaxCoAssignees>::new();
+            for (account, share) in co_assignees.iter() {
+                let accepted = previous.iter().any(|(other, _)| other == account);
+                let list = if accepted {
+                    &mut task.co_assignees
+                } else {
+                    &mut offers
+                };
+                list.try_push((account.clone(), *share))
+                    .map_err(|_| Error::<T>::TooManyCoAssignees)?;
+            }
 
-            // 更新统计
-            TaskCountByStatus::<T>::mutate(&task.status, |count| {
-                *count = count.saturating_sub(1);
-            });
+            // 从被移除的协作执行者的任务列表中移除
+            for (account, _) in previous.iter() {
+                if !co_assignees.iter().any(|(other, _)| other == account) {
+                    UserAssignedTasks::<T>::mutate(account, |tasks| {
+                        tasks.retain(|&id| id != task_id)
+                    });
+                }
+            }
+            if offers.is_empty() {
+                CoAssigneeOffers::<T>::remove(task_id);
+            } else {
+                CoAssigneeOffers::<T>::insert(task_id, offers);
+            }
 
-            // 从创建者的任务列表中移除
-            UserCreatedTasks::<T>::mutate(&task.creator, |tasks| {
-                tasks.retain(|&id| id != task_id);
+            // 保存任务
+            task.updated_at = T::TimeProvider::now();
+            Tasks::<T>::insert(&task_id, &task);
+
+            let changed = previous.len().saturating_add(co_assignees.len()) as u32;
+
+            // 触发事件
+            Self::deposit_event(Event::CoAssigneesSet {
+                task_id,
+                co_assignees,
             });
 
-            // 如果有分配者，也从分配者的任务列表中移除
-            if let Some(assignee) = &task.assignee {
-                UserAssignedTasks::<T>::mutate(assignee, |tasks| {
-                    tasks.retain(|&id| id != task_id);
-                });
+            Ok(Some(T::WeightInfo::set_co_assignees(changed)).into())
+        }
+
+        /// 接受协作邀请，按邀请中的份额成为任务的协作执行者
+        ///
+        /// 任务必须仍处于待处理或进行中。
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::accept_co_assignment())]
+        pub fn accept_co_assignment(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务和邀请
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+            let mut offers = CoAssigneeOffers::<T>::get(task_id);
+            let index = offers
+                .iter()
+                .position(|(account, _)| account == &who)
+                .ok_or(Error::<T>::NoCoAssignmentOffer)?;
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::TaskNotOpen
+            );
+
+            // 加入协作执行者列表，检查任务数量上限
+            task.co_assignees
+                .try_push(offers.remove(index))
+                .map_err(|_| Error::<T>::TooManyCoAssignees)?;
+            UserAssignedTasks::<T>::try_mutate(&who, |tasks| {
+                tasks
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::TooManyTasks)
+            })?;
+
+            // 保存任务和剩余的邀请
+            task.updated_at = T::TimeProvider::now();
+            Tasks::<T>::insert(&task_id, &task);
+            if offers.is_empty() {
+                CoAssigneeOffers::<T>::remove(task_id);
+            } else {
+                CoAssigneeOffers::<T>::insert(task_id, offers);
             }
 
             // 触发事件
-            Self::deposit_event(Event::TaskDeleted {
+            Self::deposit_event(Event::CoAssignmentAccepted {
                 task_id,
-                deleted_by: who,
+                co_assignee: who,
             });
 
             Ok(())
         }
-    }
 
-    // Helper functions
-    impl<T: Config> Pallet<T> {
-        /// 验证任务状态转换的合法性
-        fn validate_status_transition(
-            old_status: &TaskStatus,
-            new_status: &TaskStatus,
-        ) -> Result<(), Error<T>> {
-            use TaskStatus::*;
+        /// 拒绝协作邀请
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::decline_co_assignment())]
+        pub fn decline_co_assignment(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-            let valid = match (old_status, new_status) {
-                // 从 Pending 可以转换到任何状态
-                (Pending, _) => true,
-                // 从 InProgress 可以转换到 Completed, Cancelled, PendingVerification
-                (InProgress, Completed) => true,
-                (InProgress, Cancelled) => true,
-                (InProgress, PendingVerification) => true,
-                // 从 PendingVerification 可以转换到 Completed 或 InProgress
-                (PendingVerification, Completed) => true,
+            CoAssigneeOffers::<T>::try_mutate_exists(task_id, |offers| -> DispatchResult {
+                let list = offers.as_mut().ok_or(Error::<T>::NoCoAssignmentOffer)?;
+                let index = list
+                    .iter()
+                    .position(|(account, _)| account == &who)
+                    .ok_or(Error::<T>::NoCoAssignmentOffer)?;
+                list.remove(index);
+                if list.is_empty() {
+                    *offers = None;
+                }
+                Ok(())
+            })?;
+
+            // 触发事件
+            Self::deposit_event(Event::CoAssignmentDeclined {
+                task_id,
+                co_assignee: who,
+            });
+
+            Ok(())
+        }
+
+        /// 把任务奖励拆分为按顺序排列的里程碑，传入空列表会移除全部里程碑
+        ///
+        /// 个人任务的创建者或组织任务的管理者可以设置，任务必须处于待处理或进行中，
+        /// 且没有已提交或已批准的里程碑。里程碑金额总和不能超过托管奖励，
+        /// 未分配给里程碑的部分在任务完成时支付。
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::set_milestones(T::MaxMilestones::get()))]
+        pub fn set_milestones(
+            origin: OriginFor<T>,
+            task_id: u32,
+            milestones: Vec<(Vec<u8>, T::Balance)>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以设置里程碑
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            // 验证中或已结束的任务不能调整里程碑
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::InvalidStatusTransition
+            );
+
+            // 已有里程碑提交或批准后不能再修改
+            ensure!(
+                Milestones::<T>::get(task_id)
+                    .iter()
+                    .all(|milestone| milestone.status == MilestoneStatus::Pending),
+                Error::<T>::MilestonesLocked
+            );
+
+            // 验证里程碑
+            ensure!(
+                milestones.len() <= T::MaxMilestones::get() as usize,
+                Error::<T>::TooManyMilestones
+            );
+            let mut total: T::Balance = Zero::zero();
+            let mut bounded = BoundedVec::<Milestone<T>, T::MaxMilestones>::new();
+            for (description, amount) in milestones {
+                total = total.saturating_add(amount);
+                let milestone = Milestone {
+                    description: description
+                        .try_into()
+                        .map_err(|_| Error::<T>::DescriptionTooLong)?,
+                    amount,
+                    status: MilestoneStatus::Pending,
+                };
+                bounded
+                    .try_push(milestone)
+                    .map_err(|_| Error::<T>::TooManyMilestones)?;
+            }
+            ensure!(total <= task.reward, Error::<T>::MilestonesExceedReward);
+
+            // 保存里程碑
+            let count = bounded.len() as u32;
+            if bounded.is_empty() {
+                Milestones::<T>::remove(task_id);
+            } else {
+                Milestones::<T>::insert(task_id, bounded);
+            }
+
+            // 触发事件
+            Self::deposit_event(Event::MilestonesSet { task_id, count });
+
+            Ok(Some(T::WeightInfo::set_milestones(count)).into())
+        }
+
+        /// 执行者提交下一个里程碑，等待创建者批准
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::submit_milestone())]
+        pub fn submit_milestone(origin: OriginFor<T>, task_id: u32, index: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：只有执行者可以提交里程碑
+            ensure!(
+                task.assignee.as_ref() == Some(&who),
+                Error::<T>::NotAuthorized
+            );
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::InvalidStatusTransition
+            );
+
+            Milestones::<T>::try_mutate(task_id, |milestones| -> DispatchResult {
+                let current = Self::current_milestone(milestones);
+                let milestone = milestones
+                    .get_mut(index as usize)
+                    .ok_or(Error::<T>::MilestoneNotFound)?;
+                ensure!(
+                    current == Some(index as usize),
+                    Error::<T>::MilestoneOutOfOrder
+                );
+                ensure!(
+                    milestone.status == MilestoneStatus::Pending,
+                    Error::<T>::MilestoneAlreadySubmitted
+                );
+                milestone.status = MilestoneStatus::Submitted;
+                Ok(())
+            })?;
+
+            // 触发事件
+            Self::deposit_event(Event::MilestoneSubmitted { task_id, index });
+
+            Ok(())
+        }
+
+        /// 批准已提交的里程碑，从托管奖励中按份额支付该里程碑的金额
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::approve_milestone(T::MaxCoAssignees::get()))]
+        pub fn approve_milestone(
+            origin: OriginFor<T>,
+            task_id: u32,
+            index: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以批准里程碑
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::InvalidStatusTransition
+            );
+            let assignee = task.assignee.clone().ok_or(Error::<T>::TaskNotAssigned)?;
+
+            let mut milestones = Milestones::<T>::get(task_id);
+            let current = Self::current_milestone(&milestones);
+            let milestone = milestones
+                .get_mut(index as usize)
+                .ok_or(Error::<T>::MilestoneNotFound)?;
+            ensure!(
+                current == Some(index as usize),
+                Error::<T>::MilestoneOutOfOrder
+            );
+            ensure!(
+                milestone.status == MilestoneStatus::Submitted,
+                Error::<T>::MilestoneNotSubmitted
+            );
+            milestone.status = MilestoneStatus::Approved;
+            let amount = milestone.amount;
+
+            // 从托管奖励中支付
+            for (to, paid) in Self::reward_shares(&task, &assignee, amount) {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::TaskReward.into(),
+                    &task.creator,
+                    &to,
+                    paid,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+                Self::deposit_event(Event::RewardPaid {
+                    task_id,
+                    to,
+                    amount: paid,
+                });
+            }
+            task.reward = task.reward.saturating_sub(amount);
+            task.updated_at = T::TimeProvider::now();
+
+            // 保存任务和里程碑
+            Tasks::<T>::insert(&task_id, &task);
+            Milestones::<T>::insert(task_id, milestones);
+
+            // 触发事件
+            Self::deposit_event(Event::MilestoneApproved {
+                task_id,
+                index,
+                amount,
+            });
+
+            Ok(Some(T::WeightInfo::approve_milestone(
+                task.co_assignees.len() as u32
+            ))
+            .into())
+        }
+
+        /// 退回已提交的里程碑，执行者可以重新提交
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::reject_milestone())]
+        pub fn reject_milestone(origin: OriginFor<T>, task_id: u32, index: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以退回里程碑
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            Milestones::<T>::try_mutate(task_id, |milestones| -> DispatchResult {
+                let milestone = milestones
+                    .get_mut(index as usize)
+                    .ok_or(Error::<T>::MilestoneNotFound)?;
+                ensure!(
+                    milestone.status == MilestoneStatus::Submitted,
+                    Error::<T>::MilestoneNotSubmitted
+                );
+                milestone.status = MilestoneStatus::Pending;
+                Ok(())
+            })?;
+
+            // 触发事件
+            Self::deposit_event(Event::MilestoneRejected { task_id, index });
+
+            Ok(())
+        }
+
+        /// 以组织名义创建任务
+        ///
+        /// 调用者必须是组织中角色不低于 `Member` 的成员。任务的创建者仍为调用者，奖励从调用者账户托管；
+        /// 组织管理者可以编辑、分配任务和更改任务状态，组织所有者可以删除任务。
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::create_org_task(
+            title.len() as u32,
+            description.len() as u32,
+        ))]
+        pub fn create_org_task(
+            origin: OriginFor<T>,
+            org_id: u32,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            priority: Priority,
+            difficulty: u8,
+            reward: T::Balance,
+            deadline: Option<T::Moment>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 权限检查：组织的正式成员才能以组织名义创建任务
+            ensure!(
+                Self::has_org_role(org_id, &who, OrgRole::Member),
+                Error::<T>::NotOrgMember
+            );
+
+            let task_id = Self::do_create_task(
+                who,
+                title,
+                description,
+                priority,
+                difficulty,
+                reward,
+                deadline,
+                None,
+                Some(org_id),
+            )?;
+
+            // 触发事件
+            Self::deposit_event(Event::OrgTaskCreated { task_id, org_id });
+
+            Ok(())
+        }
+
+        /// 取消任务分配，协作执行者一并移除
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::unassign_task(T::MaxTasksPerUser::get())
+            .saturating_add(T::WeightInfo::set_co_assignees(T::MaxCoAssignees::get())))]
+        pub fn unassign_task(origin: OriginFor<T>, task_id: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以取消分配
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            // 验证中或已结束的任务不能调整分配
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::InvalidStatusTransition
+            );
+
+            // 检查任务是否已分配，取消分配时释放保证金
+            let previous_assignee = task.assignee.clone().ok_or(Error::<T>::TaskNotAssigned)?;
+            Self::release_bond(&mut task)?;
+            task.assignee = None;
+            let removed = Self::remove_co_assignees(&mut task);
+            Self::withdraw_submitted_milestone(task_id);
+
+            // 更新时间戳
+            task.updated_at = T::TimeProvider::now();
+
+            // 保存任务，公开任务重新变为可认领
+            Tasks::<T>::insert(&task_id, &task);
+            Self::update_claimable(&task);
+
+            // 从分配者的任务列表中移除
+            let assigned_len = UserAssignedTasks::<T>::mutate(&previous_assignee, |tasks| {
+                let len = tasks.len() as u32;
+                tasks.retain(|&id| id != task_id);
+                len
+            });
+
+            // 触发事件
+            Self::deposit_event(Event::TaskUnassigned {
+                task_id,
+                previous_assignee,
+            });
+
+            Ok(Some(
+                T::WeightInfo::unassign_task(assigned_len)
+                    .saturating_add(T::WeightInfo::set_co_assignees(removed)),
+            )
+            .into())
+        }
+
+        /// 删除任务
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::delete_task(
+            T::MaxTasksPerUser::get(),
+            T::MaxTasksPerUser::get(),
+            T::MaxDependencies::get().saturating_mul(2),
+        )
+        .saturating_add(T::WeightInfo::set_co_assignees(T::MaxCoAssignees::get())))]
+        pub fn delete_task(origin: OriginFor<T>, task_id: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的所有者可以删除任务
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Owner),
+                Error::<T>::NotAuthorized
+            );
+
+            // 必须先删除全部子任务
+            ensure!(
+                TaskChildren::<T>::get(task_id).is_empty(),
+                Error::<T>::TaskHasSubtasks
+            );
+
+            // 退还尚未结算的托管奖励
+            if Self::is_reward_held(&task.status) {
+                Self::refund_reward(&task)?;
+            }
+            Self::release_bond(&mut task)?;
+
+            // 从截止时间索引中移除
+            if let Some(deadline) = task.deadline {
+                Self::unschedule_expiry(task_id, deadline);
+            }
+
+            // 移除依赖关系，依赖它的任务可能因此解除阻塞
+            let edges = Self::dependency_edges(task_id);
+            Self::clear_dependencies(task_id);
+
+            // 结束验证轮次，截止索引中的条目会在到期时跳过
+            if let Some(round) = VerificationRounds::<T>::take(task_id) {
+                Self::release_panel(&round.panel);
+            }
+
+            // 从存储中删除任务
+            Tasks::<T>::remove(&task_id);
+            ClaimableTasks::<T>::remove(task_id);
+            Applications::<T>::remove(task_id);
+            AssignmentOffers::<T>::remove(task_id);
+            Milestones::<T>::remove(task_id);
+            PaidShares::<T>::remove(task_id);
+            T::LifecycleHandler::on_task_deleted(task_id);
+
+            // 从父任务的子任务列表中移除
+            if let Some(parent_id) = task.parent {
+                TaskChildren::<T>::mutate(parent_id, |children| {
+                    children.retain(|&id| id != task_id)
+                });
+            }
+
+            // 更新统计
+            TaskCountByStatus::<T>::mutate(&task.status, |count| {
+                *count = count.saturating_sub(1);
+            });
+            Self::track_attributes(&task.priority, task.difficulty, false);
+
+            // 从创建者的任务列表中移除
+            let created_len = UserCreatedTasks::<T>::mutate(&task.creator, |tasks| {
+                let len = tasks.len() as u32;
+                tasks.retain(|&id| id != task_id);
+                len
+            });
+
+            // 如果有分配者，也从分配者的任务列表中移除
+            let assigned_len = match &task.assignee {
+                Some(assignee) => UserAssignedTasks::<T>::mutate(assignee, |tasks| {
+                    let len = tasks.len() as u32;
+                    tasks.retain(|&id| id != task_id);
+                    len
+                }),
+                None => 0,
+            };
+            let removed = Self::remove_co_assignees(&mut task);
+
+            // 触发事件
+            Self::deposit_event(Event::TaskDeleted {
+                task_id,
+                deleted_by: who,
+            });
+
+            Ok(Some(
+                T::WeightInfo::delete_task(created_len, assigned_len, edges)
+                    .saturating_add(T::WeightInfo::set_co_assignees(removed)),
+            )
+            .into())
+        }
+
+        /// 在已有任务下创建子任务
+        ///
+        /// 父任务的创建者或执行者可以拆分子任务，子任务的创建者为调用者，并继承父任务所属的组织。
+        /// 父任务属于组织时，调用者必须是该组织的正式成员。
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::create_subtask(
+            title.len() as u32,
+            description.len() as u32,
+        ))]
+        pub fn create_subtask(
+            origin: OriginFor<T>,
+            parent_id: u32,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            priority: Priority,
+            difficulty: u8,
+            reward: T::Balance,
+            deadline: Option<T::Moment>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取父任务
+            let parent = Tasks::<T>::get(&parent_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：父任务的创建者或执行者可以创建子任务
+            ensure!(
+                parent.creator == who || parent.assignee.as_ref() == Some(&who),
+                Error::<T>::NotAuthorized
+            );
+
+            // 子任务继承父任务所属的组织，与以组织名义创建任务的要求相同
+            if let Some(org_id) = parent.org {
+                ensure!(
+                    Self::has_org_role(org_id, &who, OrgRole::Member),
+                    Error::<T>::NotOrgMember
+                );
+            }
+
+            // 父任务必须仍未结束，且不在验证中
+            ensure!(parent.status.is_open(), Error::<T>::ParentNotOpen);
+            ensure!(
+                !VerificationRounds::<T>::contains_key(parent_id),
+                Error::<T>::VerificationInProgress
+            );
+
+            // 检查层级深度
+            ensure!(
+                Self::task_depth(&parent) < T::MaxTaskDepth::get(),
+                Error::<T>::MaxDepthExceeded
+            );
+
+            let task_id = Self::do_create_task(
+                who,
+                title,
+                description,
+                priority,
+                difficulty,
+                reward,
+                deadline,
+                Some(parent_id),
+                parent.org,
+            )?;
+
+            // 更新子任务索引
+            TaskChildren::<T>::try_mutate(parent_id, |children| {
+                children
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::TooManySubtasks)
+            })?;
+
+            // 触发事件
+            Self::deposit_event(Event::SubtaskCreated { parent_id, task_id });
+
+            Ok(())
+        }
+
+        /// 添加任务依赖：`task_id` 在 `blocker_id` 结束之前不能开始
+        ///
+        /// 个人任务的创建者或组织任务的管理者可以添加依赖，任务必须仍处于待处理状态。
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::add_dependency(T::MaxDependencySearch::get()))]
+        pub fn add_dependency(
+            origin: OriginFor<T>,
+            task_id: u32,
+            blocker_id: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务和前置任务
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+            let blocker = Tasks::<T>::get(&blocker_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以修改任务依赖
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            ensure!(task_id != blocker_id, Error::<T>::SelfDependency);
+            ensure!(
+                task.status == TaskStatus::Pending,
+                Error::<T>::TaskAlreadyStarted
+            );
+            ensure!(blocker.status.is_open(), Error::<T>::BlockerNotOpen);
+
+            // 父任务要等待子任务结束，层级内的依赖会形成死锁
+            ensure!(
+                !Self::is_ancestor(task_id, &blocker) && !Self::is_ancestor(blocker_id, &task),
+                Error::<T>::RelatedTaskDependency
+            );
+
+            let mut blockers = TaskDependencies::<T>::get(task_id);
+            ensure!(
+                !blockers.contains(&blocker_id),
+                Error::<T>::DependencyAlreadyExists
+            );
+
+            // 环检测：前置任务不能（直接或间接）依赖当前任务
+            let visited = Self::ensure_no_cycle(task_id, blocker_id)?;
+
+            // 更新双向索引
+            blockers
+                .try_push(blocker_id)
+                .map_err(|_| Error::<T>::TooManyDependencies)?;
+            TaskDependencies::<T>::insert(task_id, blockers);
+            TaskDependents::<T>::try_mutate(blocker_id, |dependents| {
+                dependents
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::TooManyDependencies)
+            })?;
+
+            // 触发事件
+            Self::deposit_event(Event::DependencyAdded {
+                task_id,
+                blocker_id,
+            });
+
+            Ok(Some(T::WeightInfo::add_dependency(visited)).into())
+        }
+
+        /// 移除任务依赖
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_dependency())]
+        pub fn remove_dependency(
+            origin: OriginFor<T>,
+            task_id: u32,
+            blocker_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以修改任务依赖
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            let mut blockers = TaskDependencies::<T>::get(task_id);
+            let len = blockers.len();
+            blockers.retain(|&id| id != blocker_id);
+            ensure!(blockers.len() < len, Error::<T>::DependencyNotFound);
+
+            TaskDependents::<T>::mutate(blocker_id, |dependents| {
+                dependents.retain(|&id| id != task_id)
+            });
+
+            // 触发事件
+            Self::deposit_event(Event::DependencyRemoved {
+                task_id,
+                blocker_id,
+            });
+
+            if blockers.is_empty() {
+                TaskDependencies::<T>::remove(task_id);
+                Self::deposit_event(Event::TaskUnblocked { task_id });
+            } else {
+                TaskDependencies::<T>::insert(task_id, blockers);
+            }
+
+            Ok(())
+        }
+
+        /// 在承诺阶段提交投票承诺
+        ///
+        /// `commitment` 由 [`Pallet::vote_commitment`] 计算，意见和盐在揭示阶段之前保密。
+        /// 只有抽中的陪审员可以投票，每人每轮只能提交一次。
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::commit_vote(T::MaxVerifiers::get()))]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            task_id: u32,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务和验证轮次
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+            let mut round =
+                VerificationRounds::<T>::get(task_id).ok_or(Error::<T>::NotUnderVerification)?;
+
+            // 资格检查：只有抽中的陪审员可以投票，任务的参与者不能验证自己的任务
+            ensure!(
+                !Self::is_participant(&task, &who),
+                Error::<T>::CannotVerifyOwnTask
+            );
+            ensure!(round.panel.contains(&who), Error::<T>::NotOnPanel);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < round.commit_ends,
+                Error::<T>::CommitPhaseClosed
+            );
+            ensure!(
+                !round.commitments.iter().any(|(juror, _)| juror == &who),
+                Error::<T>::AlreadyCommitted
+            );
+
+            // 记录承诺
+            let commitments = round.commitments.len() as u32;
+            round
+                .commitments
+                .try_push((who.clone(), commitment))
+                .map_err(|_| Error::<T>::TooManyVotes)?;
+            VerificationRounds::<T>::insert(task_id, round);
+
+            // 触发事件
+            Self::deposit_event(Event::VoteCommitted {
+                task_id,
+                juror: who,
+            });
+
+            Ok(Some(T::WeightInfo::commit_vote(commitments)).into())
+        }
+
+        /// 登记为陪审员并冻结 `stake` 作为质押
+        ///
+        /// 质押越高，被抽入验证小组的概率越大。
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::register_juror(T::MaxJurors::get()))]
+        pub fn register_juror(
+            origin: OriginFor<T>,
+            stake: T::Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 质押至少为 MinJurorStake，且不能为零
+            ensure!(
+                stake >= T::MinJurorStake::get().max(One::one()),
+                Error::<T>::StakeTooLow
+            );
+
+            let mut jurors = Jurors::<T>::get();
+            let len = jurors.len() as u32;
+            ensure!(
+                !jurors.iter().any(|juror| juror.account == who),
+                Error::<T>::AlreadyJuror
+            );
+            jurors
+                .try_push(Juror {
+                    account: who.clone(),
+                    stake,
+                    panels: 0,
+                })
+                .map_err(|_| Error::<T>::TooManyJurors)?;
+
+            // 冻结质押
+            T::Currency::hold(&HoldReason::JurorStake.into(), &who, stake)?;
+            Jurors::<T>::put(jurors);
+
+            // 触发事件
+            Self::deposit_event(Event::JurorRegistered { who, stake });
+
+            Ok(Some(T::WeightInfo::register_juror(len)).into())
+        }
+
+        /// 退出陪审员池并释放质押
+        ///
+        /// 仍在进行中的验证小组中时不能退出。
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::unregister_juror(T::MaxJurors::get()))]
+        pub fn unregister_juror(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let mut jurors = Jurors::<T>::get();
+            let len = jurors.len() as u32;
+            let index = jurors
+                .iter()
+                .position(|juror| juror.account == who)
+                .ok_or(Error::<T>::NotJuror)?;
+            ensure!(jurors[index].panels == 0, Error::<T>::JurorOnPanel);
+            let juror = jurors.remove(index);
+
+            // 释放质押
+            T::Currency::release(
+                &HoldReason::JurorStake.into(),
+                &who,
+                juror.stake,
+                Precision::Exact,
+            )?;
+            Jurors::<T>::put(jurors);
+
+            // 触发事件
+            Self::deposit_event(Event::JurorUnregistered {
+                who,
+                stake: juror.stake,
+            });
+
+            Ok(Some(T::WeightInfo::unregister_juror(len)).into())
+        }
+
+        /// 在揭示阶段公开投票，`approve` 和 `salt` 必须与提交的承诺一致
+        ///
+        /// 只有揭示的投票才会计入结果，没有揭示的陪审员会被罚没部分质押。
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::reveal_vote(T::MaxVerifiers::get()))]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            task_id: u32,
+            approve: bool,
+            salt: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取验证轮次
+            let mut round =
+                VerificationRounds::<T>::get(task_id).ok_or(Error::<T>::NotUnderVerification)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= round.commit_ends,
+                Error::<T>::RevealPhaseNotStarted
+            );
+
+            // 校验承诺
+            let (_, commitment) = round
+                .commitments
+                .iter()
+                .find(|(juror, _)| juror == &who)
+                .ok_or(Error::<T>::NoCommitment)?;
+            ensure!(
+                !round.votes.iter().any(|(juror, _)| juror == &who),
+                Error::<T>::AlreadyRevealed
+            );
+            ensure!(
+                *commitment == Self::vote_commitment(&who, task_id, approve, &salt),
+                Error::<T>::InvalidReveal
+            );
+
+            // 记录揭示的投票
+            let revealed = round.votes.len() as u32;
+            round
+                .votes
+                .try_push((who.clone(), approve))
+                .map_err(|_| Error::<T>::TooManyVotes)?;
+            VerificationRounds::<T>::insert(task_id, round);
+
+            // 触发事件
+            Self::deposit_event(Event::VoteRevealed {
+                task_id,
+                juror: who,
+                approve,
+            });
+
+            Ok(Some(T::WeightInfo::reveal_vote(revealed)).into())
+        }
+
+        /// 重新打开已完成或已取消的任务
+        ///
+        /// 个人任务的创建者或组织任务的管理者可以在任务结束后的 `ReopenWindow` 内重新打开。
+        /// 任务回到待处理；已支付的奖励从执行者收回，已退还的奖励重新托管，状态计数和账户统计一并撤销。
+        /// 执行者的保证金已在任务结束时释放或罚没，因此执行者和协作执行者一并被移除，
+        /// 需要重新分配并由执行者冻结新的保证金。任务结束时清除的依赖关系不会恢复。
+        ///
+        /// 截止时间已过的任务需要先通过 `update_task` 设置新的截止时间。
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::reopen_task()
+            .saturating_add(T::LifecycleHandler::on_task_reopened_weight()))]
+        pub fn reopen_task(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以重新打开任务
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            // 只有时间窗口内的已完成或已取消任务可以重新打开
+            ensure!(
+                matches!(task.status, TaskStatus::Completed | TaskStatus::Cancelled),
+                Error::<T>::NotReopenable
+            );
+            let closed_at = task.closed_at.ok_or(Error::<T>::NotReopenable)?;
+            let now = T::TimeProvider::now();
+            ensure!(
+                now <= closed_at.saturating_add(T::ReopenWindow::get()),
+                Error::<T>::ReopenWindowExpired
+            );
+            if let Some(deadline) = task.deadline {
+                ensure!(deadline > now, Error::<T>::DeadlineInPast);
+            }
+
+            // 子任务只能在父任务未结束时重新打开
+            if let Some(parent_id) = task.parent {
+                let parent = Tasks::<T>::get(parent_id).ok_or(Error::<T>::TaskNotFound)?;
+                ensure!(parent.status.is_open(), Error::<T>::ParentNotOpen);
+            }
+
+            // 撤销结算和账户统计
+            Self::reclaim_reward(&task)?;
+            let previous_status = task.status.clone();
+            let on_time = Self::finished_on_time(&task, closed_at);
+            AccountStats::<T>::mutate(Self::responsible(&task), |stats| {
+                if previous_status == TaskStatus::Completed {
+                    stats.completed = stats.completed.saturating_sub(1);
+                    if on_time {
+                        stats.completed_on_time = stats.completed_on_time.saturating_sub(1);
+                    }
+                } else {
+                    stats.cancelled = stats.cancelled.saturating_sub(1);
+                }
+            });
+
+            // 通知结算前的执行者，之后再移除执行者
+            let summary = Self::summary(&task);
+
+            // 保证金已释放或罚没，移除执行者和协作执行者，重新分配时需要冻结新的保证金
+            let previous_assignee = task.assignee.take();
+            if let Some(assignee) = &previous_assignee {
+                UserAssignedTasks::<T>::mutate(assignee, |tasks| tasks.retain(|&id| id != task_id));
+            }
+            Self::remove_co_assignees(&mut task);
+            Self::withdraw_submitted_milestone(task_id);
+
+            // 更新状态并重新登记截止时间
+            let new_status = TaskStatus::Pending;
+            Self::do_transition(&mut task, new_status.clone())?;
+            task.closed_at = None;
+            if let Some(deadline) = task.deadline {
+                Self::unschedule_expiry(task_id, deadline);
+                Self::schedule_expiry(task_id, deadline)?;
+            }
+
+            // 保存任务，公开任务重新变为可认领
+            Tasks::<T>::insert(&task_id, &task);
+            Self::update_claimable(&task);
+
+            if let Some(summary) = summary {
+                T::LifecycleHandler::on_task_reopened(&summary, &previous_status, on_time);
+            }
+
+            // 触发事件
+            Self::deposit_event(Event::TaskReopened {
+                task_id,
+                previous_status,
+                new_status,
+            });
+            if let Some(previous_assignee) = previous_assignee {
+                Self::deposit_event(Event::TaskUnassigned {
+                    task_id,
+                    previous_assignee,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// 设置任务是否公开
+        ///
+        /// 公开且未分配的未结束任务会进入可认领任务索引，任何账户都可以通过 `claim_task` 认领。
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_task_open())]
+        pub fn set_task_open(origin: OriginFor<T>, task_id: u32, open: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以设置任务是否公开
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            // 已结束的任务无法再被认领
+            ensure!(task.status.is_open(), Error::<T>::TaskNotOpen);
+
+            task.open = open;
+            task.updated_at = T::TimeProvider::now();
+
+            // 保存任务并更新可认领任务索引
+            Tasks::<T>::insert(&task_id, &task);
+            Self::update_claimable(&task);
+
+            // 触发事件
+            Self::deposit_event(Event::TaskOpenChanged { task_id, open });
+
+            Ok(())
+        }
+
+        /// 认领公开任务，调用者成为任务的执行者
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::claim_task())]
+        pub fn claim_task(origin: OriginFor<T>, task_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 只有公开、未分配且处于待处理或进行中的任务可以认领
+            ensure!(task.is_claimable(), Error::<T>::TaskNotClaimable);
+
+            // 创建者不能认领自己的任务
+            ensure!(task.creator != who, Error::<T>::CannotAssignToSelf);
+
+            // 创建者已邀请其他执行者时不能认领
+            ensure!(
+                !Self::has_pending_offer(task_id, T::TimeProvider::now()),
+                Error::<T>::AssignmentPending
+            );
+
+            // 分配任务
+            Self::do_assign(&mut task, &who)?;
+
+            // 触发事件
+            Self::deposit_event(Event::TaskClaimed {
+                task_id,
+                assignee: who,
+            });
+
+            Ok(())
+        }
+
+        /// 申请执行任务，可以附上期望的报酬和附言
+        ///
+        /// 申请保留到创建者接受其中一个，或任务被分配、结束或删除为止。
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::apply_for_task(T::MaxApplicants::get(), note.len() as u32))]
+        pub fn apply_for_task(
+            origin: OriginFor<T>,
+            task_id: u32,
+            reward: Option<T::Balance>,
+            note: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let note_len = note.len() as u32;
+            let note: BoundedVec<u8, T::MaxApplicationNoteLength> =
+                note.try_into().map_err(|_| Error::<T>::NoteTooLong)?;
+
+            // 获取任务
+            let task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 只能申请未分配、处于待处理或进行中的任务
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::TaskNotOpen
+            );
+            ensure!(task.assignee.is_none(), Error::<T>::TaskAlreadyAssigned);
+
+            // 创建者不能申请自己的任务
+            ensure!(task.creator != who, Error::<T>::CannotAssignToSelf);
+
+            let mut applications = Applications::<T>::get(task_id);
+            let len = applications.len() as u32;
+            ensure!(
+                !applications
+                    .iter()
+                    .any(|application| application.applicant == who),
+                Error::<T>::AlreadyApplied
+            );
+            applications
+                .try_push(Application {
+                    applicant: who.clone(),
+                    reward,
+                    note,
+                    applied_at: T::TimeProvider::now(),
+                })
+                .map_err(|_| Error::<T>::TooManyApplications)?;
+            Applications::<T>::insert(task_id, applications);
+
+            // 触发事件
+            Self::deposit_event(Event::ApplicationSubmitted {
+                task_id,
+                applicant: who,
+                reward,
+            });
+
+            Ok(Some(T::WeightInfo::apply_for_task(len, note_len)).into())
+        }
+
+        /// 接受 `applicant` 的申请并把任务分配给申请者
+        ///
+        /// 申请提出了报酬时，托管的奖励按该报酬增减。其余申请随之清除。
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::accept_application(T::MaxApplicants::get()))]
+        pub fn accept_application(
+            origin: OriginFor<T>,
+            task_id: u32,
+            applicant: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // 获取任务
+            let mut task = Tasks::<T>::get(&task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+            // 权限检查：个人任务的创建者或组织任务的管理者可以接受申请
+            ensure!(
+                Self::can_manage(&task, &who, OrgRole::Manager),
+                Error::<T>::NotAuthorized
+            );
+
+            // 组织管理者不能接受自己的申请
+            ensure!(applicant != who, Error::<T>::CannotAssignToSelf);
+
+            // 只能分配未结束且不在验证中的任务
+            ensure!(
+                matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress),
+                Error::<T>::TaskNotOpen
+            );
+            ensure!(task.assignee.is_none(), Error::<T>::TaskAlreadyAssigned);
+
+            // 查找申请
+            let applications = Applications::<T>::get(task_id);
+            let len = applications.len() as u32;
+            let application = applications
+                .into_iter()
+                .find(|application| application.applicant == applicant)
+                .ok_or(Error::<T>::ApplicationNotFound)?;
+
+            // 按申请者提出的报酬调整托管奖励
+            if let Some(reward) = application.reward {
+                ensure!(
+                    Self::is_reward_held(&task.status),
+                    Error::<T>::RewardAlreadySettled
+                );
+                Self::adjust_reward_hold(&task, reward)?;
+                task.reward = reward;
+            }
+
+            // 分配任务，同时清除全部申请
+            Self::do_assign(&mut task, &applicant)?;
+
+            // 触发事件
+            Self::deposit_event(Event::ApplicationAccepted {
+                task_id,
+                applicant,
+                reward: task.reward,
+            });
+
+            Ok(Some(T::WeightInfo::accept_application(len)).into())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// 创建任务的核心逻辑，供 `create_task`、`create_subtask`、`create_org_task` 和创世配置共用，
+        /// 返回新任务ID
+        ///
+        /// 不会更新父任务的子任务索引，由调用者负责。
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn do_create_task(
+            who: T::AccountId,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            priority: Priority,
+            difficulty: u8,
+            reward: T::Balance,
+            deadline: Option<T::Moment>,
+            parent: Option<u32>,
+            org: Option<u32>,
+        ) -> Result<u32, DispatchError> {
+            // 验证输入参数
+            ensure!(
+                title.len() <= T::MaxTitleLength::get() as usize,
+                Error::<T>::TitleTooLong
+            );
+            ensure!(
+                description.len() <= T::MaxDescriptionLength::get() as usize,
+                Error::<T>::DescriptionTooLong
+            );
+            ensure!(
+                difficulty >= 1 && difficulty <= 10,
+                Error::<T>::InvalidDifficulty
+            );
+
+            // 检查用户任务数量限制
+            let user_tasks = UserCreatedTasks::<T>::get(&who);
+            ensure!(
+                user_tasks.len() < T::MaxTasksPerUser::get() as usize,
+                Error::<T>::TooManyTasks
+            );
+
+            // 获取当前区块时间，截止时间必须晚于当前时间
+            let now = T::TimeProvider::now();
+            if let Some(deadline) = deadline {
+                ensure!(deadline > now, Error::<T>::DeadlineInPast);
+            }
+
+            // 生成任务ID
+            let task_id = NextTaskId::<T>::get();
+            NextTaskId::<T>::put(task_id.saturating_add(1));
+
+            // 创建任务
+            let task = Task {
+                id: task_id,
+                creator: who.clone(),
+                assignee: None,
+                title: title
+                    .clone()
+                    .try_into()
+                    .map_err(|_| Error::<T>::TitleTooLong)?,
+                description: description
+                    .try_into()
+                    .map_err(|_| Error::<T>::DescriptionTooLong)?,
+                status: TaskStatus::Pending,
+                priority,
+                difficulty,
+                reward,
+                created_at: now,
+                updated_at: now,
+                deadline,
+                parent,
+                verification: None,
+                closed_at: None,
+                submitted_at: None,
+                open: false,
+                bond: Zero::zero(),
+                co_assignees: BoundedVec::new(),
+                org,
+            };
+
+            // 冻结奖励作为托管
+            if !reward.is_zero() {
+                T::Currency::hold(&HoldReason::TaskReward.into(), &who, reward)?;
+                Self::deposit_event(Event::RewardHeld {
+                    task_id,
+                    who: who.clone(),
+                    amount: reward,
+                });
+            }
+
+            // 登记截止时间索引
+            if let Some(deadline) = deadline {
+                Self::schedule_expiry(task_id, deadline)?;
+            }
+
+            // 存储任务
+            Tasks::<T>::insert(&task_id, &task);
+
+            // 更新用户创建的任务列表
+            UserCreatedTasks::<T>::try_mutate(&who, |tasks| {
+                tasks
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::TooManyTasks)
+            })?;
+
+            // 更新统计
+            TaskCountByStatus::<T>::mutate(TaskStatus::Pending, |count| {
+                *count = count.saturating_add(1);
+            });
+            Self::track_attributes(&task.priority, task.difficulty, true);
+            AccountStats::<T>::mutate(&who, |stats| {
+                stats.created = stats.created.saturating_add(1);
+            });
+
+            // 触发事件
+            Self::deposit_event(Event::TaskCreated {
+                task_id,
+                creator: who,
+                title,
+            });
+
+            Ok(task_id)
+        }
+
+        /// 账户是否可以管理任务：组织任务只看组织中的角色是否不低于 `min_role`，
+        /// 创建者离开组织或被降级后同样失去权限；个人任务只有创建者可以管理
+        fn can_manage(task: &Task<T>, who: &T::AccountId, min_role: OrgRole) -> bool {
+            match task.org {
+                Some(org_id) => Self::has_org_role(org_id, who, min_role),
+                None => task.creator == *who,
+            }
+        }
+
+        /// 账户在组织中的角色是否不低于 `min_role`
+        fn has_org_role(org_id: u32, who: &T::AccountId, min_role: OrgRole) -> bool {
+            T::Organizations::role(org_id, who).map_or(false, |role| role >= min_role)
+        }
+
+        /// 任务是否按时完成：提交过验证时以提交时间为准，否则以 `finished_at` 为准
+        fn finished_on_time(task: &Task<T>, finished_at: T::Moment) -> bool {
+            let finished_at = task.submitted_at.unwrap_or(finished_at);
+            task.deadline
+                .map_or(true, |deadline| finished_at <= deadline)
+        }
+
+        /// 执行状态转换：更新统计、时间戳并结算托管奖励，由调用者负责保存任务
+        pub(crate) fn do_transition(task: &mut Task<T>, new_status: TaskStatus) -> DispatchResult {
+            let old_status = task.status.clone();
+            let now = T::TimeProvider::now();
+
+            match new_status {
+                TaskStatus::Completed => {
+                    Self::pay_reward(task)?;
+                    let on_time = Self::finished_on_time(task, now);
+                    if on_time {
+                        Self::release_bond(task)?;
+                    } else {
+                        Self::slash_bond(task)?;
+                    }
+                    AccountStats::<T>::mutate(Self::responsible(task), |stats| {
+                        stats.completed = stats.completed.saturating_add(1);
+                        if on_time {
+                            stats.completed_on_time = stats.completed_on_time.saturating_add(1);
+                        }
+                    });
+                    if let Some(summary) = Self::summary(task) {
+                        T::LifecycleHandler::on_task_completed(&summary, on_time);
+                    }
+                }
+                TaskStatus::Cancelled => {
+                    Self::refund_reward(task)?;
+                    Self::release_bond(task)?;
+                    AccountStats::<T>::mutate(Self::responsible(task), |stats| {
+                        stats.cancelled = stats.cancelled.saturating_add(1);
+                    });
+                }
+                TaskStatus::Expired => {
+                    Self::refund_reward(task)?;
+                    Self::slash_bond(task)?;
+                }
+                _ => {}
+            }
+
+            TaskCountByStatus::<T>::mutate(&old_status, |count| {
+                *count = count.saturating_sub(1);
+            });
+            TaskCountByStatus::<T>::mutate(&new_status, |count| {
+                *count = count.saturating_add(1);
+            });
+
+            task.status = new_status.clone();
+            task.updated_at = now;
+            match task.status {
+                TaskStatus::PendingVerification => task.submitted_at = Some(now),
+                TaskStatus::Pending | TaskStatus::InProgress => task.submitted_at = None,
+                _ => {}
+            }
+
+            let closed = old_status.is_open() && !new_status.is_open();
+            if closed {
+                task.closed_at = Some(now);
+            }
+            let returned = old_status == TaskStatus::PendingVerification && new_status.is_open();
+
+            Self::deposit_event(Event::TaskStatusChanged {
+                task_id: task.id,
+                old_status,
+                new_status,
+            });
+
+            // 提交验证后开启验证轮次，在下一个区块抽取验证小组
+            if task.status == TaskStatus::PendingVerification {
+                Self::start_verification(task)?;
+            }
+
+            // 验证期间已过截止时间的任务退回后重新登记，由过期处理照常过期
+            if returned {
+                if let Some(deadline) = task.deadline.filter(|deadline| *deadline < now) {
+                    Self::reschedule_overdue(task.id, deadline)?;
+                }
+            }
+
+            // 任务结束后不再阻塞其他任务，也不再等待前置任务、接受申请或邀请
+            if closed {
+                Self::clear_dependencies(task.id);
+                Applications::<T>::remove(task.id);
+                AssignmentOffers::<T>::remove(task.id);
+                CoAssigneeOffers::<T>::remove(task.id);
+            }
+
+            Self::update_claimable(task);
+
+            Ok(())
+        }
+
+        /// 把任务分配给 `assignee`，检查其任务数量上限并更新索引和统计，由调用者负责触发事件
+        fn do_assign(task: &mut Task<T>, assignee: &T::AccountId) -> DispatchResult {
+            // 检查执行者的任务数量限制
+            UserAssignedTasks::<T>::try_mutate(assignee, |tasks| {
+                tasks
+                    .try_push(task.id)
+                    .map_err(|_| Error::<T>::TooManyTasks)
+            })?;
+
+            task.assignee = Some(assignee.clone());
+            task.updated_at = T::TimeProvider::now();
+
+            // 保存任务，已分配的任务不再可认领，也不再接受申请或邀请
+            Tasks::<T>::insert(task.id, &*task);
+            Self::update_claimable(task);
+            Applications::<T>::remove(task.id);
+            AssignmentOffers::<T>::remove(task.id);
+
+            // 更新执行者统计
+            AccountStats::<T>::mutate(assignee, |stats| {
+                stats.assigned = stats.assigned.saturating_add(1);
+            });
+
+            Ok(())
+        }
+
+        /// 任务在 `now` 时是否有尚未过期的分配邀请
+        fn has_pending_offer(task_id: u32, now: T::Moment) -> bool {
+            AssignmentOffers::<T>::get(task_id).map_or(false, |offer| now <= offer.expires_at)
+        }
+
+        /// 按任务当前的公开、分配和状态同步可认领任务索引
+        fn update_claimable(task: &Task<T>) {
+            if task.is_claimable() {
+                ClaimableTasks::<T>::insert(task.id, ());
+            } else {
+                ClaimableTasks::<T>::remove(task.id);
+            }
+        }
+
+        /// 任务参与的依赖数量（前置任务与依赖它的任务之和）
+        fn dependency_edges(task_id: u32) -> u32 {
+            let blockers = TaskDependencies::<T>::decode_len(task_id).unwrap_or(0);
+            let dependents = TaskDependents::<T>::decode_len(task_id).unwrap_or(0);
+            blockers.saturating_add(dependents) as u32
+        }
+
+        /// 移除任务的全部依赖关系
+        ///
+        /// 依赖它的任务如果因此失去最后一个前置任务，发出 `TaskUnblocked` 事件。
+        fn clear_dependencies(task_id: u32) {
+            for blocker_id in TaskDependencies::<T>::take(task_id) {
+                TaskDependents::<T>::mutate(blocker_id, |dependents| {
+                    dependents.retain(|&id| id != task_id)
+                });
+            }
+
+            for dependent_id in TaskDependents::<T>::take(task_id) {
+                let mut blockers = TaskDependencies::<T>::get(dependent_id);
+                blockers.retain(|&id| id != task_id);
+                if blockers.is_empty() {
+                    TaskDependencies::<T>::remove(dependent_id);
+                    Self::deposit_event(Event::TaskUnblocked {
+                        task_id: dependent_id,
+                    });
+                } else {
+                    TaskDependencies::<T>::insert(dependent_id, blockers);
+                }
+            }
+        }
+
+        /// 确认添加 `task_id` 依赖 `blocker_id` 不会形成循环，返回访问过的任务数量
+        ///
+        /// 从前置任务出发深度优先搜索它等待的任务：前置任务和子任务（父任务要等待子任务结束）。
+        /// 访问数量受 `MaxDependencySearch` 约束。
+        fn ensure_no_cycle(task_id: u32, blocker_id: u32) -> Result<u32, DispatchError> {
+            let max_visits = T::MaxDependencySearch::get();
+            let mut visited: Vec<u32> = Vec::new();
+            let mut stack = sp_std::vec![blocker_id];
+
+            while let Some(current) = stack.pop() {
+                if current == task_id {
+                    return Err(Error::<T>::CyclicDependency.into());
+                }
+                if visited.contains(&current) {
+                    continue;
+                }
+                ensure!(
+                    (visited.len() as u32) < max_visits,
+                    Error::<T>::DependencyGraphTooLarge
+                );
+                visited.push(current);
+                stack.extend(TaskDependencies::<T>::get(current));
+                stack.extend(TaskChildren::<T>::get(current));
+            }
+
+            Ok(visited.len() as u32)
+        }
+
+        /// 任务在层级中的深度，顶层任务为 0
+        fn task_depth(task: &Task<T>) -> u32 {
+            let mut depth = 0u32;
+            let mut parent = task.parent;
+            while let Some(parent_id) = parent {
+                depth = depth.saturating_add(1);
+                // 深度受 MaxTaskDepth 约束，超过上限后无需继续向上查找
+                if depth > T::MaxTaskDepth::get() {
+                    break;
+                }
+                parent = Tasks::<T>::get(parent_id).and_then(|task| task.parent);
+            }
+            depth
+        }
+
+        /// `ancestor_id` 是否是任务的父任务或更上层的祖先任务
+        fn is_ancestor(ancestor_id: u32, task: &Task<T>) -> bool {
+            let mut parent = task.parent;
+            let mut depth = 0u32;
+            while let Some(parent_id) = parent {
+                if parent_id == ancestor_id {
+                    return true;
+                }
+                depth = depth.saturating_add(1);
+                if depth > T::MaxTaskDepth::get() {
+                    break;
+                }
+                parent = Tasks::<T>::get(parent_id).and_then(|task| task.parent);
+            }
+            false
+        }
+
+        /// 确保任务的全部子任务都已结束，返回子任务数量
+        fn ensure_children_closed(task_id: u32) -> Result<u32, DispatchError> {
+            let children = TaskChildren::<T>::get(task_id);
+            let open = children.iter().any(|child_id| {
+                Tasks::<T>::get(child_id).map_or(false, |child| child.status.is_open())
+            });
+            ensure!(!open, Error::<T>::ChildrenIncomplete);
+            Ok(children.len() as u32)
+        }
+
+        /// 统计中对任务结果负责的账户：执行者，没有执行者时为创建者
+        fn responsible(task: &Task<T>) -> &T::AccountId {
+            task.assignee.as_ref().unwrap_or(&task.creator)
+        }
+
+        /// 生命周期回调使用的任务摘要，未分配执行者的任务没有摘要
+        fn summary(task: &Task<T>) -> Option<TaskSummary<T::AccountId>> {
+            task.assignee.as_ref().map(|assignee| TaskSummary {
+                task_id: task.id,
+                assignee: assignee.clone(),
+                priority: task.priority.clone(),
+                difficulty: task.difficulty,
+                rewarded: !task.reward.is_zero(),
+            })
+        }
+
+        /// 在优先级和难度统计中加入（`added`）或移除任务
+        fn track_attributes(priority: &Priority, difficulty: u8, added: bool) {
+            let update = |count: &mut u32| {
+                *count = if added {
+                    count.saturating_add(1)
+                } else {
+                    count.saturating_sub(1)
+                };
+            };
+            TaskCountByPriority::<T>::mutate(priority, update);
+            TaskCountByDifficulty::<T>::mutate(DifficultyBand::of(difficulty), update);
+        }
+
+        /// 该状态下奖励是否仍处于托管中
+        pub(crate) fn is_reward_held(status: &TaskStatus) -> bool {
+            matches!(
+                status,
+                TaskStatus::Pending | TaskStatus::InProgress | TaskStatus::PendingVerification
+            )
+        }
+
+        /// 按新的奖励金额增减托管
+        fn adjust_reward_hold(task: &Task<T>, new_reward: T::Balance) -> DispatchResult {
+            ensure!(
+                new_reward >= Self::unpaid_milestones(task.id),
+                Error::<T>::MilestonesExceedReward
+            );
+            let reason: T::RuntimeHoldReason = HoldReason::TaskReward.into();
+            if new_reward > task.reward {
+                let amount = new_reward.saturating_sub(task.reward);
+                T::Currency::hold(&reason, &task.creator, amount)?;
+                Self::deposit_event(Event::RewardHeld {
+                    task_id: task.id,
+                    who: task.creator.clone(),
+                    amount,
+                });
+            } else if new_reward < task.reward {
+                let amount = task.reward.saturating_sub(new_reward);
+                T::Currency::release(&reason, &task.creator, amount, Precision::Exact)?;
+                Self::deposit_event(Event::RewardRefunded {
+                    task_id: task.id,
+                    to: task.creator.clone(),
+                    amount,
+                });
+            }
+            Ok(())
+        }
+
+        /// 尚未批准的里程碑金额总和
+        fn unpaid_milestones(task_id: u32) -> T::Balance {
+            Milestones::<T>::get(task_id)
+                .iter()
+                .filter(|milestone| milestone.status != MilestoneStatus::Approved)
+                .fold(Zero::zero(), |total: T::Balance, milestone| {
+                    total.saturating_add(milestone.amount)
+                })
+        }
+
+        /// 执行者变更时，把已提交但尚未批准的里程碑退回待提交
+        fn withdraw_submitted_milestone(task_id: u32) {
+            if !Milestones::<T>::contains_key(task_id) {
+                return;
+            }
+            Milestones::<T>::mutate(task_id, |milestones| {
+                for milestone in milestones
+                    .iter_mut()
+                    .filter(|milestone| milestone.status == MilestoneStatus::Submitted)
+                {
+                    milestone.status = MilestoneStatus::Pending;
+                }
+            });
+        }
+
+        /// 下一个需要提交或批准的里程碑的位置，全部批准后返回 `None`
+        fn current_milestone(milestones: &[Milestone<T>]) -> Option<usize> {
+            milestones
+                .iter()
+                .position(|milestone| milestone.status != MilestoneStatus::Approved)
+        }
+
+        /// 从辞去任务的执行者账户向创建者支付违约金，返回实际支付的金额
+        fn charge_resignation_penalty(
+            assignee: &T::AccountId,
+            creator: &T::AccountId,
+        ) -> Result<T::Balance, DispatchError> {
+            let available =
+                T::Currency::reducible_balance(assignee, Preservation::Preserve, Fortitude::Polite);
+            let penalty = T::ResignationPenalty::get().min(available);
+            if !penalty.is_zero() {
+                T::Currency::transfer(assignee, creator, penalty, Preservation::Preserve)?;
+            }
+            Ok(penalty)
+        }
+
+        /// 移除任务的全部协作执行者和协作邀请并更新协作执行者的任务列表，返回移除的协作执行者数量
+        fn remove_co_assignees(task: &mut Task<T>) -> u32 {
+            CoAssigneeOffers::<T>::remove(task.id);
+            let co_assignees = sp_std::mem::take(&mut task.co_assignees);
+            for (account, _) in co_assignees.iter() {
+                UserAssignedTasks::<T>::mutate(account, |tasks| tasks.retain(|&id| id != task.id));
+            }
+            co_assignees.len() as u32
+        }
+
+        /// 按份额拆分 `total`：协作执行者按各自份额分得，执行者获得其余部分
+        ///
+        /// 低于存在性押金的份额无法转入尚不存在的账户，这部分归入执行者的份额；
+        /// 已存在的账户照常获得份额。
+        fn reward_shares(
+            task: &Task<T>,
+            assignee: &T::AccountId,
+            total: T::Balance,
+        ) -> Vec<(T::AccountId, T::Balance)> {
+            let minimum = T::Currency::minimum_balance();
+            let mut remaining = total;
+            let mut shares = sp_std::vec![];
+            for (account, share) in task.co_assignees.iter() {
+                let amount = share.mul_floor(total);
+                if amount < minimum && T::Currency::total_balance(account).is_zero() {
+                    continue;
+                }
+                remaining = remaining.saturating_sub(amount);
+                shares.push((account.clone(), amount));
+            }
+            shares.insert(0, (assignee.clone(), remaining));
+            shares.retain(|(_, amount)| !amount.is_zero());
+            shares
+        }
+
+        /// 将托管奖励按份额支付给执行者和协作执行者；没有执行者时退还给创建者
+        fn pay_reward(task: &Task<T>) -> DispatchResult {
+            let Some(assignee) = &task.assignee else {
+                return Self::refund_reward(task);
+            };
+            let mut paid = BoundedVec::new();
+            for (to, amount) in Self::reward_shares(task, assignee, task.reward) {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::TaskReward.into(),
+                    &task.creator,
+                    &to,
+                    amount,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+                if to != *assignee {
+                    // 协作执行者不超过 `MaxCoAssignees` 个
+                    let _ = paid.try_push((to.clone(), amount));
+                }
+                Self::deposit_event(Event::RewardPaid {
+                    task_id: task.id,
+                    to,
+                    amount,
+                });
+            }
+            PaidShares::<T>::insert(task.id, paid);
+            Ok(())
+        }
+
+        /// 将托管奖励退还给创建者
+        fn refund_reward(task: &Task<T>) -> DispatchResult {
+            if task.reward.is_zero() {
+                return Ok(());
+            }
+            T::Currency::release(
+                &HoldReason::TaskReward.into(),
+                &task.creator,
+                task.reward,
+                Precision::Exact,
+            )?;
+            Self::deposit_event(Event::RewardRefunded {
+                task_id: task.id,
+                to: task.creator.clone(),
+                amount: task.reward,
+            });
+            Ok(())
+        }
+
+        /// 释放执行者冻结的保证金
+        fn release_bond(task: &mut Task<T>) -> DispatchResult {
+            let Some(assignee) = &task.assignee else {
+                return Ok(());
+            };
+            if task.bond.is_zero() {
+                return Ok(());
+            }
+            let amount = T::Currency::release(
+                &HoldReason::AssigneeBond.into(),
+                assignee,
+                task.bond,
+                Precision::BestEffort,
+            )?;
+            Self::deposit_event(Event::BondReleased {
+                task_id: task.id,
+                assignee: assignee.clone(),
+                amount,
+            });
+            task.bond = Zero::zero();
+            Ok(())
+        }
+
+        /// 罚没执行者冻结的保证金，转入 `BondBeneficiary`，未配置时转给创建者
+        fn slash_bond(task: &mut Task<T>) -> DispatchResult {
+            let Some(assignee) = &task.assignee else {
+                return Ok(());
+            };
+            if task.bond.is_zero() {
+                return Ok(());
+            }
+            let to = T::BondBeneficiary::get().unwrap_or_else(|| task.creator.clone());
+            let amount = T::Currency::transfer_on_hold(
+                &HoldReason::AssigneeBond.into(),
+                assignee,
+                &to,
+                task.bond,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Force,
+            )?;
+            Self::deposit_event(Event::BondSlashed {
+                task_id: task.id,
+                assignee: assignee.clone(),
+                to,
+                amount,
+            });
+            task.bond = Zero::zero();
+            Ok(())
+        }
+
+        /// 撤销任务结束时的奖励结算，重新托管奖励
+        ///
+        /// 已支付给执行者的奖励从执行者收回，已退还的奖励从创建者重新冻结。
+        fn reclaim_reward(task: &Task<T>) -> DispatchResult {
+            if task.reward.is_zero() {
+                return Ok(());
+            }
+            let reason: T::RuntimeHoldReason = HoldReason::TaskReward.into();
+            match (&task.status, &task.assignee) {
+                (TaskStatus::Completed, Some(assignee)) => {
+                    // 按完成时实际支付的拆分收回，执行者退回其余部分
+                    let paid = PaidShares::<T>::take(task.id);
+                    let co_total = paid.iter().fold(T::Balance::zero(), |total, (_, amount)| {
+                        total.saturating_add(*amount)
+                    });
+                    let mut shares =
+                        sp_std::vec![(assignee.clone(), task.reward.saturating_sub(co_total))];
+                    shares.extend(paid);
+                    shares.retain(|(_, amount)| !amount.is_zero());
+                    for (from, amount) in shares {
+                        T::Currency::transfer_and_hold(
+                            &reason,
+                            &from,
+                            &task.creator,
+                            amount,
+                            Precision::Exact,
+                            Preservation::Preserve,
+                            Fortitude::Polite,
+                        )?;
+                        Self::deposit_event(Event::RewardReclaimed {
+                            task_id: task.id,
+                            from,
+                            amount,
+                        });
+                    }
+                }
+                _ => {
+                    T::Currency::hold(&reason, &task.creator, task.reward)?;
+                    Self::deposit_event(Event::RewardHeld {
+                        task_id: task.id,
+                        who: task.creator.clone(),
+                        amount: task.reward,
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        /// 截止时间所在的分桶编号
+        fn expiry_bucket(deadline: T::Moment) -> T::Moment {
+            deadline / T::ExpiryBucketSize::get()
+        }
+
+        /// 将任务登记到截止时间索引
+        fn schedule_expiry(task_id: u32, deadline: T::Moment) -> DispatchResult {
+            Self::push_expiry(task_id, Self::expiry_bucket(deadline))
+        }
+
+        /// 重新登记已过截止时间的任务
+        ///
+        /// 待验证的任务在过期处理时被跳过，并从已处理的分桶中移除。验证被驳回或撤回后，
+        /// 任务仍在原分桶中时无需处理，否则登记到尚未处理的最早分桶，尽快过期。
+        fn reschedule_overdue(task_id: u32, deadline: T::Moment) -> DispatchResult {
+            let bucket = Self::expiry_bucket(deadline);
+            if ExpiryQueue::<T>::get(bucket).contains(&task_id) {
+                return Ok(());
+            }
+            let bucket = NextExpiryBucket::<T>::get().map_or(bucket, |next| bucket.max(next));
+            Self::push_expiry(task_id, bucket)
+        }
+
+        /// 将任务追加到截止时间分桶 `bucket`
+        fn push_expiry(task_id: u32, bucket: T::Moment) -> DispatchResult {
+            ExpiryQueue::<T>::try_mutate(bucket, |queue| {
+                queue
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::ExpiryQueueFull)
+            })?;
+            NextExpiryBucket::<T>::mutate(|next| {
+                if next.map_or(true, |next| bucket < next) {
+                    *next = Some(bucket);
+                }
+            });
+            Ok(())
+        }
+
+        /// 从截止时间索引中移除任务
+        fn unschedule_expiry(task_id: u32, deadline: T::Moment) {
+            ExpiryQueue::<T>::mutate(Self::expiry_bucket(deadline), |queue| {
+                queue.retain(|&id| id != task_id);
+            });
+        }
+
+        /// 依次处理截至 `now` 已完全过去的截止时间分桶，最多执行 `max_steps` 步，返回实际步数
+        ///
+        /// 读取一个分桶或过期一个任务各消耗一步，处理不完的分桶留到下一个区块。
+        pub(crate) fn process_expiries(now: T::Moment, max_steps: u32) -> u32 {
+            let Some(mut cursor) = NextExpiryBucket::<T>::get() else {
+                return 0;
+            };
+            let current = Self::expiry_bucket(now);
+
+            let mut steps = 0u32;
+            while cursor < current && steps < max_steps {
+                steps += 1;
+                let mut queue = ExpiryQueue::<T>::take(cursor);
+
+                while !queue.is_empty() && steps < max_steps {
+                    steps += 1;
+                    let task_id = queue.remove(0);
+                    Self::expire_task(task_id, now);
+                }
+
+                if !queue.is_empty() {
+                    ExpiryQueue::<T>::insert(cursor, queue);
+                    break;
+                }
+                cursor = cursor.saturating_add(One::one());
+            }
+
+            NextExpiryBucket::<T>::put(cursor);
+            steps
+        }
+
+        /// 将已过截止时间且仍未开始或进行中的任务标记为过期并退还托管奖励
+        fn expire_task(task_id: u32, now: T::Moment) {
+            let Some(mut task) = Tasks::<T>::get(task_id) else {
+                return;
+            };
+            // 索引是惰性清理的：状态已变化或截止时间已修改的任务直接跳过
+            let expirable = matches!(task.status, TaskStatus::Pending | TaskStatus::InProgress);
+            let due = task.deadline.map_or(false, |deadline| deadline < now);
+            if !expirable || !due {
+                return;
+            }
+
+            // 钩子中的存储写入不会自动回滚，结算失败时撤销已完成的部分转账
+            let expired = with_storage_layer(|| -> DispatchResult {
+                Self::do_transition(&mut task, TaskStatus::Expired)
+            });
+            if expired.is_ok() {
+                Tasks::<T>::insert(task_id, &task);
+                Self::deposit_event(Event::TaskExpired { task_id });
+            }
+        }
+
+        /// 为任务开启验证轮次，在承诺和揭示阶段都结束后统计结果
+        ///
+        /// 验证小组在下一个区块开始时抽取：抽取使用的随机数在提交验证时还无法得知，
+        /// 执行者不能通过选择提交时机影响抽取结果。抽取之前轮次的验证小组为空。
+        pub(crate) fn start_verification(task: &Task<T>) -> DispatchResult {
+            let task_id = task.id;
+            let draw_at = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+            let commit_ends = draw_at.saturating_add(T::CommitPeriod::get().max(One::one()));
+            let ends_at = commit_ends.saturating_add(T::RevealPeriod::get().max(One::one()));
+            PanelDrawQueue::<T>::try_mutate(draw_at, |queue| {
+                queue
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::VerificationQueueFull)
+            })?;
+            VerificationQueue::<T>::try_mutate(ends_at, |queue| {
+                queue
+                    .try_push(task_id)
+                    .map_err(|_| Error::<T>::VerificationQueueFull)
+            })?;
+
+            VerificationRounds::<T>::insert(
+                task_id,
+                VerificationRound {
+                    commit_ends,
+                    ends_at,
+                    panel: BoundedVec::new(),
+                    commitments: BoundedVec::new(),
+                    votes: BoundedVec::new(),
+                },
+            );
+            Ok(())
+        }
+
+        /// 为在 `now` 抽取的全部验证轮次抽取验证小组，返回处理的索引条目数
+        pub(crate) fn process_panel_draws(now: BlockNumberFor<T>) -> u32 {
+            let queue = PanelDrawQueue::<T>::take(now);
+            for &task_id in queue.iter() {
+                Self::draw_verification_panel(task_id);
+            }
+            queue.len() as u32
+        }
+
+        /// 为验证轮次抽取验证小组
+        ///
+        /// 没有可抽取的陪审员时关闭轮次，任务停留在待验证状态，由创建者确认完成。
+        fn draw_verification_panel(task_id: u32) {
+            // 索引是惰性清理的：任务已删除的轮次直接跳过
+            let Some(mut round) = VerificationRounds::<T>::get(task_id) else {
+                return;
+            };
+            let Some(task) = Tasks::<T>::get(task_id) else {
+                return;
+            };
+            if !round.panel.is_empty() {
+                return;
+            }
+
+            let mut jurors = Jurors::<T>::get();
+            let panel = Self::draw_panel(&task, &jurors);
+            if panel.is_empty() {
+                VerificationRounds::<T>::remove(task_id);
+                Self::deposit_event(Event::NoJurorsAvailable { task_id });
+                return;
+            }
+
+            for juror in jurors
+                .iter_mut()
+                .filter(|juror| panel.contains(&juror.account))
+            {
+                juror.panels = juror.panels.saturating_add(1);
+            }
+            Jurors::<T>::put(jurors);
+
+            round.panel = panel.clone();
+            VerificationRounds::<T>::insert(task_id, &round);
+            Self::deposit_event(Event::VerificationStarted {
+                task_id,
+                commit_ends: round.commit_ends,
+                ends_at: round.ends_at,
+                panel: panel.into_inner(),
+            });
+        }
+
+        /// 按质押加权、不放回地随机抽取最多 `MaxVerifiers` 名陪审员，排除任务的参与者
+        pub(crate) fn draw_panel(
+            task: &Task<T>,
+            jurors: &[Juror<T>],
+        ) -> BoundedVec<T::AccountId, T::MaxVerifiers> {
+            let mut candidates: Vec<(T::AccountId, u128)> = jurors
+                .iter()
+                .filter(|juror| !Self::is_participant(task, &juror.account))
+                .map(|juror| (juror.account.clone(), juror.stake.saturated_into::<u128>()))
+                .collect();
+
+            let mut panel = BoundedVec::new();
+            let mut draw = 0u32;
+            while !candidates.is_empty() && (panel.len() as u32) < T::MaxVerifiers::get() {
+                let total = candidates
+                    .iter()
+                    .fold(0u128, |total, (_, stake)| total.saturating_add(*stake));
+                if total.is_zero() {
+                    break;
+                }
+
+                // 在 [0, total) 中取一个随机点，落在哪名候选人的质押区间内就抽中谁
+                let (seed, _) = T::Randomness::random(&(b"tasks/panel", task.id, draw).encode());
+                let mut point = u128::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+                    .unwrap_or_default()
+                    % total;
+                let index = candidates
+                    .iter()
+                    .position(|(_, stake)| {
+                        if point < *stake {
+                            return true;
+                        }
+                        point -= *stake;
+                        false
+                    })
+                    .unwrap_or(candidates.len() - 1);
+
+                let (account, _) = candidates.swap_remove(index);
+                if panel.try_push(account).is_err() {
+                    break;
+                }
+                draw = draw.saturating_add(1);
+            }
+            panel
+        }
+
+        /// 账户是否为任务的创建者、执行者或协作执行者
+        fn is_participant(task: &Task<T>, who: &T::AccountId) -> bool {
+            task.creator == *who
+                || task.assignee.as_ref() == Some(who)
+                || task.co_assignees.iter().any(|(account, _)| account == who)
+        }
+
+        /// 验证轮次结束后，减少验证小组成员所在的小组计数
+        fn release_panel(panel: &[T::AccountId]) {
+            Jurors::<T>::mutate(|jurors| {
+                for juror in jurors
+                    .iter_mut()
+                    .filter(|juror| panel.contains(&juror.account))
+                {
+                    juror.panels = juror.panels.saturating_sub(1);
+                }
+            });
+        }
+
+        /// 统计在 `now` 结束的全部验证轮次，返回处理的索引条目数
+        pub(crate) fn process_verifications(now: BlockNumberFor<T>) -> u32 {
+            let queue = VerificationQueue::<T>::take(now);
+            for &task_id in queue.iter() {
+                Self::conclude_verification(task_id, now);
+            }
+            queue.len() as u32
+        }
+
+        /// 罚没提交了承诺却没有揭示投票的陪审员的部分质押，罚没的资金被销毁
+        fn slash_unrevealed(task_id: u32, round: &VerificationRound<T>) {
+            let unrevealed: Vec<&T::AccountId> = round
+                .commitments
+                .iter()
+                .map(|(juror, _)| juror)
+                .filter(|juror| !round.votes.iter().any(|(voter, _)| voter == *juror))
+                .collect();
+            if unrevealed.is_empty() {
+                return;
+            }
+
+            Jurors::<T>::mutate(|jurors| {
+                for juror in jurors
+                    .iter_mut()
+                    .filter(|juror| unrevealed.contains(&&juror.account))
+                {
+                    let penalty = T::UnrevealedVotePenalty::get().mul_floor(juror.stake);
+                    let slashed = T::Currency::burn_held(
+                        &HoldReason::JurorStake.into(),
+                        &juror.account,
+                        penalty,
+                        Precision::BestEffort,
+                        Fortitude::Force,
+                    )
+                    .unwrap_or_default();
+                    if slashed.is_zero() {
+                        continue;
+                    }
+                    juror.stake = juror.stake.saturating_sub(slashed);
+                    Self::deposit_event(Event::JurorSlashed {
+                        task_id,
+                        juror: juror.account.clone(),
+                        amount: slashed,
+                    });
+                }
+            });
+        }
+
+        /// 按投票结果完成任务或将其退回进行中，并把结果记录在任务上
+        fn conclude_verification(task_id: u32, now: BlockNumberFor<T>) {
+            // 索引是惰性清理的：任务已删除的轮次直接跳过
+            let Some(round) = VerificationRounds::<T>::get(task_id) else {
+                return;
+            };
+            if round.ends_at != now {
+                return;
+            }
+            VerificationRounds::<T>::remove(task_id);
+            Self::slash_unrevealed(task_id, &round);
+            Self::release_panel(&round.panel);
+            let Some(mut task) = Tasks::<T>::get(task_id) else {
+                return;
+            };
+
+            let outcome = Self::tally(&round.votes);
+            let new_status = match outcome.verdict {
+                VerificationVerdict::Approved => TaskStatus::Completed,
+                _ => TaskStatus::InProgress,
+            };
+            // 钩子中的存储写入不会自动回滚，支付失败时撤销已完成的部分转账，
+            // 任务停留在待验证状态，奖励仍然完整托管
+            let concluded = with_storage_layer(|| -> DispatchResult {
+                Self::do_transition(&mut task, new_status)
+            });
+            if concluded.is_ok() {
+                if let Some(summary) = Self::summary(&task) {
+                    T::LifecycleHandler::on_verification_concluded(&summary, &outcome.verdict);
+                }
+                task.verification = Some(outcome.clone());
+                Tasks::<T>::insert(task_id, &task);
+                Self::deposit_event(Event::VerificationConcluded { task_id, outcome });
+            }
+        }
+
+        /// 陪审员的投票承诺：`hash(陪审员, 任务ID, 意见, 盐)`
+        ///
+        /// 承诺包含陪审员账户，照抄他人的承诺无法揭示。
+        pub fn vote_commitment(
+            juror: &T::AccountId,
+            task_id: u32,
+            approve: bool,
+            salt: &[u8; 32],
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(juror, task_id, approve, salt))
+        }
+
+        /// 统计已揭示的投票：投票数不足 `MinVerificationVotes` 时无结论，
+        /// 否则赞成票至少占 `VerificationThreshold` 时通过
+        fn tally(votes: &[(T::AccountId, bool)]) -> VerificationOutcome {
+            let total = votes.len() as u32;
+            let approvals = votes.iter().filter(|(_, approve)| *approve).count() as u32;
+            let verdict = if total == 0 || total < T::MinVerificationVotes::get() {
+                VerificationVerdict::Inconclusive
+            } else if approvals >= T::VerificationThreshold::get().mul_ceil(total) {
+                VerificationVerdict::Approved
+            } else {
+                VerificationVerdict::Rejected
+            };
+            VerificationOutcome {
+                verdict,
+                approvals,
+                rejections: total.saturating_sub(approvals),
+            }
+        }
+
+        /// 验证任务状态转换的合法性
+        pub(crate) fn validate_status_transition(
+            old_status: &TaskStatus,
+            new_status: &TaskStatus,
+        ) -> Result<(), Error<T>> {
+            use TaskStatus::*;
+
+            let valid = match (old_status, new_status) {
+                // Expired 只能由链上自动设置
+                (_, Expired) => false,
+                // 从 Pending 可以转换到任何状态
+                (Pending, _) => true,
+                // 从 InProgress 可以转换到 Completed, Cancelled, PendingVerification
+                (InProgress, Completed) => true,
+                (InProgress, Cancelled) => true,
+                (InProgress, PendingVerification) => true,
+                // 从 PendingVerification 可以转换到 Completed 或 InProgress
+                (PendingVerification, Completed) => true,
                 (PendingVerification, InProgress) => true,
-                // Completed 和 Cancelled 是终态，不能再转换
+                // Completed、Cancelled 和 Expired 是终态，不能再转换；
+                // 已完成和已取消的任务只能通过 `reopen_task` 重新打开
                 (Completed, _) => false,
                 (Cancelled, _) => false,
+                (Expired, _) => false,
                 // 其他转换都不允许
                 _ => false,
             };
@@ -591,15 +3968,128 @@ pub mod pallet {
             }
         }
 
-        /// 获取任务统计信息
-        pub fn get_task_statistics() -> (u32, u32, u32, u32, u32) {
-            (
-                TaskCountByStatus::<T>::get(TaskStatus::Pending),
-                TaskCountByStatus::<T>::get(TaskStatus::InProgress),
-                TaskCountByStatus::<T>::get(TaskStatus::Completed),
-                TaskCountByStatus::<T>::get(TaskStatus::Cancelled),
-                TaskCountByStatus::<T>::get(TaskStatus::PendingVerification),
-            )
+        /// 查询任务当前是否逾期，任务不存在时返回 `None`
+        pub fn is_task_overdue(task_id: u32) -> Option<bool> {
+            let now = T::TimeProvider::now();
+            Tasks::<T>::get(task_id).map(|task| task.is_overdue(now))
+        }
+
+        /// 分页查询用户创建的任务，按创建顺序返回
+        pub fn tasks_by_creator(who: &T::AccountId, offset: u32, limit: u32) -> Vec<Task<T>> {
+            Self::load_page(UserCreatedTasks::<T>::get(who).into_iter(), offset, limit)
+        }
+
+        /// 分页查询分配给用户的任务，按分配顺序返回
+        pub fn tasks_by_assignee(who: &T::AccountId, offset: u32, limit: u32) -> Vec<Task<T>> {
+            Self::load_page(UserAssignedTasks::<T>::get(who).into_iter(), offset, limit)
+        }
+
+        /// 分页查询处于指定状态的任务，按任务ID升序返回
+        ///
+        /// 需要遍历全部任务ID，只应在链下（运行时 API）调用
+        pub fn tasks_by_status(status: TaskStatus, offset: u32, limit: u32) -> Vec<Task<T>> {
+            (0..NextTaskId::<T>::get())
+                .filter_map(Tasks::<T>::get)
+                .filter(|task| task.status == status)
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// 分页查询可认领的任务，按任务ID升序返回
+        ///
+        /// 需要遍历整个索引，只应在链下（运行时 API）调用
+        pub fn claimable_tasks(offset: u32, limit: u32) -> Vec<Task<T>> {
+            let mut task_ids: Vec<u32> = ClaimableTasks::<T>::iter_keys().collect();
+            task_ids.sort_unstable();
+            Self::load_page(task_ids.into_iter(), offset, limit)
+        }
+
+        fn load_page(task_ids: impl Iterator<Item = u32>, offset: u32, limit: u32) -> Vec<Task<T>> {
+            task_ids
+                .skip(offset as usize)
+                .take(limit as usize)
+                .filter_map(Tasks::<T>::get)
+                .collect()
+        }
+
+        /// 查询任务的直接子任务
+        pub fn subtasks(task_id: u32) -> Vec<Task<T>> {
+            TaskChildren::<T>::get(task_id)
+                .into_iter()
+                .filter_map(Tasks::<T>::get)
+                .collect()
+        }
+
+        /// 任务进度：已完成的直接子任务所占比例
+        ///
+        /// 没有子任务时，已完成的任务为 100%，设置了里程碑的任务为已批准里程碑所占比例，
+        /// 否则为 0%。任务不存在时返回 `None`。
+        pub fn task_progress(task_id: u32) -> Option<Percent> {
+            let task = Tasks::<T>::get(task_id)?;
+            let children = TaskChildren::<T>::get(task_id);
+            if children.is_empty() {
+                if task.status == TaskStatus::Completed {
+                    return Some(Percent::one());
+                }
+                let milestones = Milestones::<T>::get(task_id);
+                let approved = milestones
+                    .iter()
+                    .filter(|milestone| milestone.status == MilestoneStatus::Approved)
+                    .count() as u32;
+                return Some(if milestones.is_empty() {
+                    Percent::zero()
+                } else {
+                    Percent::from_rational(approved, milestones.len() as u32)
+                });
+            }
+            let completed = children
+                .iter()
+                .filter(|&&child_id| {
+                    Tasks::<T>::get(child_id)
+                        .map_or(false, |child| child.status == TaskStatus::Completed)
+                })
+                .count() as u32;
+            Some(Percent::from_rational(completed, children.len() as u32))
+        }
+
+        /// 获取全局任务统计
+        pub fn task_statistics() -> TaskStatistics {
+            let by_status: Vec<_> = TaskStatus::ALL
+                .into_iter()
+                .map(|status| {
+                    let count = TaskCountByStatus::<T>::get(&status);
+                    (status, count)
+                })
+                .collect();
+            let by_priority = Priority::ALL
+                .into_iter()
+                .map(|priority| {
+                    let count = TaskCountByPriority::<T>::get(&priority);
+                    (priority, count)
+                })
+                .collect();
+            let by_difficulty = DifficultyBand::ALL
+                .into_iter()
+                .map(|band| {
+                    let count = TaskCountByDifficulty::<T>::get(&band);
+                    (band, count)
+                })
+                .collect();
+
+            TaskStatistics {
+                total: by_status
+                    .iter()
+                    .fold(0u32, |total, (_, count)| total.saturating_add(*count)),
+                by_status,
+                by_priority,
+                by_difficulty,
+            }
+        }
+
+        /// 获取账户的累计任务统计
+        pub fn account_statistics(who: &T::AccountId) -> AccountTaskStats {
+            AccountStats::<T>::get(who)
         }
     }
 }
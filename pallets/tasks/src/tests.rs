@@ -0,0 +1,4908 @@
+use crate::{
+    mock::*, AccountTaskStats, Applications, AssignmentOffer, AssignmentOffers, ClaimableTasks,
+    CoAssigneeOffers, DifficultyBand, Error, Event, ExpiryQueue, HoldReason, Jurors,
+    MilestoneStatus, Milestones, NextExpiryBucket, NextTaskId, OrgRole, PaidShares, PanelDrawQueue,
+    Priority, TaskChildren, TaskCountByStatus, TaskDependencies, TaskDependents, TaskStatus,
+    UserAssignedTasks, UserCreatedTasks, VerificationOutcome, VerificationQueue,
+    VerificationRounds, VerificationVerdict,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        fungible::{Inspect, InspectHold, MutateHold},
+        tokens::Precision,
+    },
+};
+use sp_runtime::{Percent, Permill};
+
+/// 以默认参数创建任务并返回任务ID
+fn create_task(creator: u64, reward: u64, deadline: Option<u64>) -> u32 {
+    let task_id = NextTaskId::<Test>::get();
+    assert_ok!(Tasks::create_task(
+        RuntimeOrigin::signed(creator),
+        b"title".to_vec(),
+        b"description".to_vec(),
+        Priority::Medium,
+        5,
+        reward,
+        deadline,
+    ));
+    task_id
+}
+
+/// 邀请执行者并由其接受，完成任务分配
+fn assign(creator: u64, task_id: u32, assignee: u64) {
+    assert_ok!(Tasks::assign_task(
+        RuntimeOrigin::signed(creator),
+        task_id,
+        assignee,
+        0
+    ));
+    assert_ok!(Tasks::accept_assignment(
+        RuntimeOrigin::signed(assignee),
+        task_id
+    ));
+}
+
+/// 创建任务并分配给执行者
+fn create_assigned_task(creator: u64, assignee: u64, reward: u64) -> u32 {
+    let task_id = create_task(creator, reward, None);
+    assign(creator, task_id, assignee);
+    task_id
+}
+
+/// 在父任务下创建子任务并返回任务ID
+fn create_subtask(creator: u64, parent_id: u32) -> u32 {
+    let task_id = NextTaskId::<Test>::get();
+    assert_ok!(Tasks::create_subtask(
+        RuntimeOrigin::signed(creator),
+        parent_id,
+        b"subtask".to_vec(),
+        vec![],
+        Priority::Medium,
+        3,
+        0,
+        None,
+    ));
+    task_id
+}
+
+fn held(who: u64) -> u64 {
+    Balances::balance_on_hold(&HoldReason::TaskReward.into(), &who)
+}
+
+fn free(who: u64) -> u64 {
+    Balances::balance(&who)
+}
+
+fn count(status: TaskStatus) -> u32 {
+    TaskCountByStatus::<Test>::get(status)
+}
+
+fn status_of(task_id: u32) -> TaskStatus {
+    Tasks::tasks(task_id).expect("task exists").status
+}
+
+#[test]
+fn create_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, Some(START_TIME + 500));
+
+        let task = Tasks::tasks(task_id).expect("task stored");
+        assert_eq!(task.id, 0);
+        assert_eq!(task.creator, ALICE);
+        assert_eq!(task.assignee, None);
+        assert_eq!(task.title.to_vec(), b"title".to_vec());
+        assert_eq!(task.description.to_vec(), b"description".to_vec());
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(task.priority, Priority::Medium);
+        assert_eq!(task.difficulty, 5);
+        assert_eq!(task.reward, 100);
+        assert_eq!(task.created_at, START_TIME);
+        assert_eq!(task.updated_at, START_TIME);
+        assert_eq!(task.deadline, Some(START_TIME + 500));
+
+        assert_eq!(NextTaskId::<Test>::get(), 1);
+        assert_eq!(UserCreatedTasks::<Test>::get(ALICE).to_vec(), vec![task_id]);
+        assert_eq!(count(TaskStatus::Pending), 1);
+        System::assert_has_event(
+            Event::RewardHeld {
+                task_id,
+                who: ALICE,
+                amount: 100,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::TaskCreated {
+                task_id,
+                creator: ALICE,
+                title: b"title".to_vec(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_task_holds_reward() {
+    new_test_ext().execute_with(|| {
+        create_task(ALICE, 100, None);
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 100);
+    });
+}
+
+#[test]
+fn create_task_without_reward_holds_nothing() {
+    new_test_ext().execute_with(|| {
+        create_task(ALICE, 0, None);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn create_task_fails_without_funds_for_reward() {
+    new_test_ext().execute_with(|| {
+        assert!(Tasks::create_task(
+            RuntimeOrigin::signed(ALICE),
+            b"title".to_vec(),
+            vec![],
+            Priority::Low,
+            1,
+            INITIAL_BALANCE * 2,
+            None,
+        )
+        .is_err());
+        assert_eq!(NextTaskId::<Test>::get(), 0);
+        assert_eq!(count(TaskStatus::Pending), 0);
+    });
+}
+
+#[test]
+fn create_task_validates_input() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Tasks::create_task(
+                RuntimeOrigin::signed(ALICE),
+                vec![b'a'; 33],
+                vec![],
+                Priority::Low,
+                1,
+                0,
+                None,
+            ),
+            Error::<Test>::TitleTooLong
+        );
+        assert_noop!(
+            Tasks::create_task(
+                RuntimeOrigin::signed(ALICE),
+                vec![],
+                vec![b'a'; 129],
+                Priority::Low,
+                1,
+                0,
+                None,
+            ),
+            Error::<Test>::DescriptionTooLong
+        );
+        for difficulty in [0, 11] {
+            assert_noop!(
+                Tasks::create_task(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![],
+                    vec![],
+                    Priority::Low,
+                    difficulty,
+                    0,
+                    None,
+                ),
+                Error::<Test>::InvalidDifficulty
+            );
+        }
+        for deadline in [START_TIME - 1, START_TIME] {
+            assert_noop!(
+                Tasks::create_task(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![],
+                    vec![],
+                    Priority::Low,
+                    1,
+                    0,
+                    Some(deadline),
+                ),
+                Error::<Test>::DeadlineInPast
+            );
+        }
+    });
+}
+
+#[test]
+fn create_task_respects_max_tasks_per_user() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..5 {
+            create_task(ALICE, 0, None);
+        }
+        assert_noop!(
+            Tasks::create_task(
+                RuntimeOrigin::signed(ALICE),
+                vec![],
+                vec![],
+                Priority::Low,
+                1,
+                0,
+                None,
+            ),
+            Error::<Test>::TooManyTasks
+        );
+        // 其他用户不受影响
+        create_task(BOB, 0, None);
+    });
+}
+
+#[test]
+fn update_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        Timestamp::set_timestamp(START_TIME + 10);
+
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            Some(b"new title".to_vec()),
+            Some(b"new description".to_vec()),
+            Some(Priority::Urgent),
+            Some(9),
+            None,
+            Some(Some(START_TIME + 100)),
+        ));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.title.to_vec(), b"new title".to_vec());
+        assert_eq!(task.description.to_vec(), b"new description".to_vec());
+        assert_eq!(task.priority, Priority::Urgent);
+        assert_eq!(task.difficulty, 9);
+        assert_eq!(task.reward, 100);
+        assert_eq!(task.deadline, Some(START_TIME + 100));
+        assert_eq!(task.created_at, START_TIME);
+        assert_eq!(task.updated_at, START_TIME + 10);
+        System::assert_last_event(
+            Event::TaskUpdated {
+                task_id,
+                updater: ALICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn update_task_validates_input_and_permissions() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                9,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(BOB),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                Some(vec![b'a'; 33]),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::TitleTooLong
+        );
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                Some(vec![b'a'; 129]),
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::DescriptionTooLong
+        );
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                Some(11),
+                None,
+                None
+            ),
+            Error::<Test>::InvalidDifficulty
+        );
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(Some(START_TIME))
+            ),
+            Error::<Test>::DeadlineInPast
+        );
+    });
+}
+
+#[test]
+fn update_task_adjusts_reward_hold() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            None,
+            None,
+            Some(150),
+            None,
+        ));
+        assert_eq!(held(ALICE), 150);
+        System::assert_has_event(
+            Event::RewardHeld {
+                task_id,
+                who: ALICE,
+                amount: 50,
+            }
+            .into(),
+        );
+
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            None,
+            None,
+            Some(30),
+            None,
+        ));
+        assert_eq!(held(ALICE), 30);
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 30);
+        System::assert_has_event(
+            Event::RewardRefunded {
+                task_id,
+                to: ALICE,
+                amount: 120,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn update_task_rejects_reward_change_after_settlement() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Cancelled
+        ));
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                Some(10),
+                None
+            ),
+            Error::<Test>::RewardAlreadySettled
+        );
+    });
+}
+
+#[test]
+fn change_task_status_works_for_creator_and_assignee() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            TaskStatus::InProgress
+        ));
+        System::assert_last_event(
+            Event::TaskStatusChanged {
+                task_id,
+                old_status: TaskStatus::Pending,
+                new_status: TaskStatus::InProgress,
+            }
+            .into(),
+        );
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::PendingVerification
+        ));
+        assert_eq!(status_of(task_id), TaskStatus::PendingVerification);
+    });
+}
+
+#[test]
+fn change_task_status_checks_task_and_permissions() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(ALICE), 9, TaskStatus::InProgress),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::change_task_status(
+                RuntimeOrigin::signed(CHARLIE),
+                task_id,
+                TaskStatus::InProgress
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Completed
+        ));
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(ALICE), task_id, TaskStatus::Pending),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn status_transition_matrix() {
+    use TaskStatus::*;
+
+    let all = [
+        Pending,
+        InProgress,
+        Completed,
+        Cancelled,
+        PendingVerification,
+        Expired,
+    ];
+    let allowed = [
+        (Pending, Pending),
+        (Pending, InProgress),
+        (Pending, Completed),
+        (Pending, Cancelled),
+        (Pending, PendingVerification),
+        (InProgress, Completed),
+        (InProgress, Cancelled),
+        (InProgress, PendingVerification),
+        (PendingVerification, Completed),
+        (PendingVerification, InProgress),
+    ];
+
+    for old in all.iter() {
+        for new in all.iter() {
+            let expected = allowed.contains(&(old.clone(), new.clone()));
+            let result = Tasks::validate_status_transition(old, new);
+            if expected {
+                assert!(result.is_ok(), "{:?} -> {:?} should be allowed", old, new);
+            } else {
+                assert!(
+                    matches!(result, Err(Error::<Test>::InvalidStatusTransition)),
+                    "{:?} -> {:?} should be rejected",
+                    old,
+                    new
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn expired_status_cannot_be_set_manually() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(ALICE), task_id, TaskStatus::Expired),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn task_count_by_status_tracks_lifecycle() {
+    new_test_ext().execute_with(|| {
+        let first = create_assigned_task(ALICE, BOB, 0);
+        let second = create_task(ALICE, 0, None);
+        assert_eq!(count(TaskStatus::Pending), 2);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            first,
+            TaskStatus::InProgress
+        ));
+        assert_eq!(count(TaskStatus::Pending), 1);
+        assert_eq!(count(TaskStatus::InProgress), 1);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            first,
+            TaskStatus::Completed
+        ));
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            second,
+            TaskStatus::Cancelled
+        ));
+        let stats = Tasks::task_statistics();
+        assert_eq!(stats.total, 2);
+        assert_eq!(
+            stats.by_status,
+            vec![
+                (TaskStatus::Pending, 0),
+                (TaskStatus::InProgress, 0),
+                (TaskStatus::Completed, 1),
+                (TaskStatus::Cancelled, 1),
+                (TaskStatus::PendingVerification, 0),
+                (TaskStatus::Expired, 0),
+            ]
+        );
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), first));
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), second));
+        let stats = Tasks::task_statistics();
+        assert_eq!(stats.total, 0);
+        assert!(stats.by_status.iter().all(|(_, count)| *count == 0));
+    });
+}
+
+#[test]
+fn completing_task_pays_assignee() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+
+        // 执行者不能自行完成任务并领取奖励
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(BOB), task_id, TaskStatus::Completed),
+            Error::<Test>::NotAuthorized
+        );
+        assert_eq!(held(ALICE), 100);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Completed
+        ));
+
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+        System::assert_has_event(
+            Event::RewardPaid {
+                task_id,
+                to: BOB,
+                amount: 100,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn completing_unassigned_task_refunds_creator() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Completed
+        ));
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn cancelling_task_refunds_creator() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            TaskStatus::Cancelled
+        ));
+
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        System::assert_has_event(
+            Event::RewardRefunded {
+                task_id,
+                to: ALICE,
+                amount: 100,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn assign_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            0
+        ));
+
+        // 执行者接受之前任务不会分配
+        let offer = AssignmentOffers::<Test>::get(task_id).unwrap();
+        assert_eq!(offer.assignee, BOB);
+        // AssignmentOfferPeriod = 50
+        assert_eq!(offer.expires_at, START_TIME + 50);
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, None);
+        assert!(UserAssignedTasks::<Test>::get(BOB).is_empty());
+        System::assert_last_event(
+            Event::AssignmentOffered {
+                task_id,
+                assignee: BOB,
+                expires_at: START_TIME + 50,
+                bond: 0,
+            }
+            .into(),
+        );
+
+        Timestamp::set_timestamp(START_TIME + 5);
+        assert_ok!(Tasks::accept_assignment(
+            RuntimeOrigin::signed(BOB),
+            task_id
+        ));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.assignee, Some(BOB));
+        assert_eq!(task.updated_at, START_TIME + 5);
+        assert_eq!(UserAssignedTasks::<Test>::get(BOB).to_vec(), vec![task_id]);
+        assert!(!AssignmentOffers::<Test>::contains_key(task_id));
+        System::assert_last_event(
+            Event::TaskAssigned {
+                task_id,
+                assignee: BOB,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn assign_task_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), 9, BOB, 0),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(BOB), task_id, CHARLIE, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), task_id, ALICE, 0),
+            Error::<Test>::CannotAssignToSelf
+        );
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            0
+        ));
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), task_id, CHARLIE, 0),
+            Error::<Test>::AssignmentPending
+        );
+        assert_ok!(Tasks::accept_assignment(
+            RuntimeOrigin::signed(BOB),
+            task_id
+        ));
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), task_id, CHARLIE, 0),
+            Error::<Test>::TaskAlreadyAssigned
+        );
+    });
+}
+
+#[test]
+fn closed_tasks_cannot_be_assigned() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Cancelled
+        ));
+
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), task_id, BOB, 0),
+            Error::<Test>::TaskNotOpen
+        );
+
+        // 任务结束后残留的邀请也不能被接受
+        AssignmentOffers::<Test>::insert(
+            task_id,
+            AssignmentOffer {
+                assignee: BOB,
+                expires_at: START_TIME + 100,
+                bond: 0,
+            },
+        );
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::TaskNotOpen
+        );
+    });
+}
+
+#[test]
+fn assigned_task_deadline_cannot_move_earlier() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 100));
+        assign(ALICE, task_id, BOB);
+        let update_deadline = |deadline: Option<u64>| {
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(deadline),
+            )
+        };
+
+        assert_noop!(
+            update_deadline(Some(START_TIME + 1)),
+            Error::<Test>::DeadlineMovedEarlier
+        );
+
+        // 可以延后或取消截止时间，但取消后不能再重新设置
+        assert_ok!(update_deadline(Some(START_TIME + 200)));
+        assert_ok!(update_deadline(None));
+        assert_noop!(
+            update_deadline(Some(START_TIME + 300)),
+            Error::<Test>::DeadlineMovedEarlier
+        );
+    });
+}
+
+#[test]
+fn offered_task_deadline_cannot_move_earlier() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 100));
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            30
+        ));
+
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(Some(START_TIME + 1)),
+            ),
+            Error::<Test>::DeadlineMovedEarlier
+        );
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(START_TIME + 200)),
+        ));
+    });
+}
+
+#[test]
+fn offered_task_cannot_gain_deadline() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            30
+        ));
+
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(Some(START_TIME + 100)),
+            ),
+            Error::<Test>::DeadlineMovedEarlier
+        );
+
+        // 邀请被拒绝后可以重新设置截止时间
+        assert_ok!(Tasks::decline_assignment(
+            RuntimeOrigin::signed(BOB),
+            task_id
+        ));
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(START_TIME + 100)),
+        ));
+    });
+}
+
+#[test]
+fn accept_assignment_respects_assignee_limit() {
+    new_test_ext().execute_with(|| {
+        // 未接受的邀请不占用执行者的任务数量
+        for _ in 0..6 {
+            let task_id = create_task(CHARLIE, 0, None);
+            assert_ok!(Tasks::assign_task(
+                RuntimeOrigin::signed(CHARLIE),
+                task_id,
+                BOB,
+                0
+            ));
+        }
+        assert!(UserAssignedTasks::<Test>::get(BOB).is_empty());
+
+        for _ in 0..5 {
+            create_assigned_task(ALICE, BOB, 0);
+        }
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::TooManyTasks
+        );
+    });
+}
+
+#[test]
+fn unassign_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+        let other = create_assigned_task(CHARLIE, BOB, 0);
+
+        assert_ok!(Tasks::unassign_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, None);
+        assert_eq!(UserAssignedTasks::<Test>::get(BOB).to_vec(), vec![other]);
+        System::assert_last_event(
+            Event::TaskUnassigned {
+                task_id,
+                previous_assignee: BOB,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn unassign_task_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::unassign_task(RuntimeOrigin::signed(ALICE), 9),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::unassign_task(RuntimeOrigin::signed(ALICE), task_id),
+            Error::<Test>::TaskNotAssigned
+        );
+        assign(ALICE, task_id, BOB);
+        assert_noop!(
+            Tasks::unassign_task(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn unassign_task_fails_during_verification() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+
+        assert_noop!(
+            Tasks::unassign_task(RuntimeOrigin::signed(ALICE), task_id),
+            Error::<Test>::InvalidStatusTransition
+        );
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, Some(BOB));
+    });
+}
+
+#[test]
+fn unassign_task_fails_on_completed_task() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert_noop!(
+            Tasks::unassign_task(RuntimeOrigin::signed(ALICE), task_id),
+            Error::<Test>::InvalidStatusTransition
+        );
+        assert_eq!(UserAssignedTasks::<Test>::get(BOB).to_vec(), vec![task_id]);
+    });
+}
+
+#[test]
+fn delete_task_cleans_up_everything() {
+    new_test_ext().execute_with(|| {
+        let kept = create_task(ALICE, 0, None);
+        let task_id = create_task(ALICE, 100, Some(START_TIME + 50));
+        assign(ALICE, task_id, BOB);
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert!(Tasks::tasks(task_id).is_none());
+        assert_eq!(UserCreatedTasks::<Test>::get(ALICE).to_vec(), vec![kept]);
+        assert!(UserAssignedTasks::<Test>::get(BOB).is_empty());
+        assert_eq!(count(TaskStatus::Pending), 1);
+        assert!(ExpiryQueue::<Test>::get((START_TIME + 50) / 10).is_empty());
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+        System::assert_last_event(
+            Event::TaskDeleted {
+                task_id,
+                deleted_by: ALICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn delete_task_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert_noop!(
+            Tasks::delete_task(RuntimeOrigin::signed(ALICE), 9),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::delete_task(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn deleting_completed_task_does_not_refund_twice() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Completed
+        ));
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+    });
+}
+
+#[test]
+fn overdue_tasks_are_reported() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 20));
+        let no_deadline = create_task(ALICE, 0, None);
+
+        assert_eq!(Tasks::is_task_overdue(task_id), Some(false));
+        Timestamp::set_timestamp(START_TIME + 21);
+        assert_eq!(Tasks::is_task_overdue(task_id), Some(true));
+        assert_eq!(Tasks::is_task_overdue(no_deadline), Some(false));
+        assert_eq!(Tasks::is_task_overdue(9), None);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Completed
+        ));
+        assert_eq!(Tasks::is_task_overdue(task_id), Some(false));
+    });
+}
+
+#[test]
+fn tasks_expire_after_their_deadline_bucket() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, Some(START_TIME + 25));
+        assert_eq!(
+            NextExpiryBucket::<Test>::get(),
+            Some((START_TIME + 25) / 10)
+        );
+
+        // 截止时间所在分桶尚未结束
+        next_block_at(START_TIME + 29);
+        assert_eq!(status_of(task_id), TaskStatus::Pending);
+
+        next_block_at(START_TIME + 30);
+        assert_eq!(status_of(task_id), TaskStatus::Expired);
+        assert_eq!(count(TaskStatus::Pending), 0);
+        assert_eq!(count(TaskStatus::Expired), 1);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+        System::assert_has_event(Event::TaskExpired { task_id }.into());
+        System::assert_has_event(
+            Event::RewardRefunded {
+                task_id,
+                to: ALICE,
+                amount: 100,
+            }
+            .into(),
+        );
+        assert!(ExpiryQueue::<Test>::get((START_TIME + 25) / 10).is_empty());
+    });
+}
+
+#[test]
+fn expiry_skips_closed_and_rescheduled_tasks() {
+    new_test_ext().execute_with(|| {
+        let completed = create_task(ALICE, 0, Some(START_TIME + 15));
+        let verifying = create_task(ALICE, 0, Some(START_TIME + 15));
+        let moved = create_task(ALICE, 0, Some(START_TIME + 15));
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            completed,
+            TaskStatus::Completed
+        ));
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            verifying,
+            TaskStatus::PendingVerification
+        ));
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            moved,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(START_TIME + 100)),
+        ));
+
+        next_block_at(START_TIME + 50);
+        assert_eq!(status_of(completed), TaskStatus::Completed);
+        assert_eq!(status_of(verifying), TaskStatus::PendingVerification);
+        assert_eq!(status_of(moved), TaskStatus::Pending);
+
+        // 每个区块最多推进三个空分桶，需要几个区块才能追上新的截止时间
+        for offset in 0..4 {
+            next_block_at(START_TIME + 110 + offset);
+        }
+        assert_eq!(status_of(moved), TaskStatus::Expired);
+    });
+}
+
+#[test]
+fn task_rejected_after_deadline_then_expires() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_task(ALICE, 100, Some(START_TIME + 15));
+        assign(ALICE, task_id, BOB);
+        submit_for_verification(BOB, task_id);
+        vote_all(task_id, &[(CHARLIE, false), (DAVE, false)]);
+
+        // 截止时间所在的分桶处理时任务正在验证，被跳过
+        run_to_block(10);
+        assert_eq!(status_of(task_id), TaskStatus::InProgress);
+        assert!(ExpiryQueue::<Test>::get(101).is_empty());
+
+        // 验证被驳回后重新登记，在下一个区块过期
+        run_to_block(11);
+        assert_eq!(status_of(task_id), TaskStatus::Expired);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn expiry_work_is_bounded_per_block() {
+    new_test_ext().execute_with(|| {
+        let deadline = START_TIME + 15;
+        let tasks: Vec<u32> = [ALICE, BOB, CHARLIE, DAVE]
+            .into_iter()
+            .map(|who| create_task(who, 0, Some(deadline)))
+            .collect();
+        let expired = || {
+            tasks
+                .iter()
+                .filter(|&&id| status_of(id) == TaskStatus::Expired)
+                .count()
+        };
+
+        // MaxExpiriesPerBlock = 3：读取分桶一步，过期两个任务两步
+        next_block_at(START_TIME + 50);
+        assert_eq!(expired(), 2);
+        assert_eq!(ExpiryQueue::<Test>::get(deadline / 10).len(), 2);
+
+        next_block_at(START_TIME + 51);
+        assert_eq!(expired(), 4);
+        assert!(ExpiryQueue::<Test>::get(deadline / 10).is_empty());
+    });
+}
+
+#[test]
+fn expiry_bucket_capacity_is_enforced() {
+    new_test_ext().execute_with(|| {
+        let deadline = START_TIME + 15;
+        for who in [ALICE, BOB, CHARLIE, DAVE] {
+            create_task(who, 0, Some(deadline));
+        }
+        assert_noop!(
+            Tasks::create_task(
+                RuntimeOrigin::signed(ALICE),
+                vec![],
+                vec![],
+                Priority::Low,
+                1,
+                0,
+                Some(deadline + 1),
+            ),
+            Error::<Test>::ExpiryQueueFull
+        );
+        // 其他分桶仍可使用
+        create_task(ALICE, 0, Some(deadline + 10));
+    });
+}
+
+#[test]
+fn task_queries_are_paginated() {
+    new_test_ext().execute_with(|| {
+        let ids: Vec<u32> = (0..4)
+            .map(|_| create_assigned_task(ALICE, BOB, 0))
+            .collect();
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            ids[1],
+            TaskStatus::Cancelled
+        ));
+        let page_ids = |tasks: Vec<crate::Task<Test>>| -> Vec<u32> {
+            tasks.into_iter().map(|task| task.id).collect()
+        };
+
+        assert_eq!(page_ids(Tasks::tasks_by_creator(&ALICE, 0, 10)), ids);
+        assert_eq!(
+            page_ids(Tasks::tasks_by_creator(&ALICE, 1, 2)),
+            vec![ids[1], ids[2]]
+        );
+        assert!(Tasks::tasks_by_creator(&ALICE, 4, 10).is_empty());
+        assert!(Tasks::tasks_by_creator(&BOB, 0, 10).is_empty());
+        assert_eq!(
+            page_ids(Tasks::tasks_by_assignee(&BOB, 3, 10)),
+            vec![ids[3]]
+        );
+
+        assert_eq!(
+            page_ids(Tasks::tasks_by_status(TaskStatus::Pending, 0, 10)),
+            vec![ids[0], ids[2], ids[3]]
+        );
+        assert_eq!(
+            page_ids(Tasks::tasks_by_status(TaskStatus::Pending, 1, 1)),
+            vec![ids[2]]
+        );
+        assert_eq!(
+            page_ids(Tasks::tasks_by_status(TaskStatus::Cancelled, 0, 10)),
+            vec![ids[1]]
+        );
+
+        // 已删除的任务不会出现在查询结果中
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), ids[0]));
+        assert_eq!(
+            page_ids(Tasks::tasks_by_status(TaskStatus::Pending, 0, 10)),
+            vec![ids[2], ids[3]]
+        );
+    });
+}
+
+#[test]
+fn statistics_track_priority_and_difficulty() {
+    new_test_ext().execute_with(|| {
+        // create_task 默认为 Medium 优先级、难度 5
+        let task_id = create_task(ALICE, 0, None);
+        create_task(BOB, 0, None);
+        assert_ok!(Tasks::create_task(
+            RuntimeOrigin::signed(ALICE),
+            vec![],
+            vec![],
+            Priority::Urgent,
+            9,
+            0,
+            None,
+        ));
+
+        let stats = Tasks::task_statistics();
+        assert_eq!(stats.total, 3);
+        assert_eq!(
+            stats.by_priority,
+            vec![
+                (Priority::Low, 0),
+                (Priority::Medium, 2),
+                (Priority::High, 0),
+                (Priority::Urgent, 1),
+            ]
+        );
+        assert_eq!(
+            stats.by_difficulty,
+            vec![
+                (DifficultyBand::Easy, 0),
+                (DifficultyBand::Medium, 2),
+                (DifficultyBand::Hard, 1),
+            ]
+        );
+
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            Some(Priority::Low),
+            Some(2),
+            None,
+            None,
+        ));
+        let stats = Tasks::task_statistics();
+        assert_eq!(stats.by_priority[0], (Priority::Low, 1));
+        assert_eq!(stats.by_priority[1], (Priority::Medium, 1));
+        assert_eq!(stats.by_difficulty[0], (DifficultyBand::Easy, 1));
+        assert_eq!(stats.by_difficulty[1], (DifficultyBand::Medium, 1));
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), task_id));
+        let stats = Tasks::task_statistics();
+        assert_eq!(stats.total, 2);
+        assert_eq!(stats.by_priority[0], (Priority::Low, 0));
+        assert_eq!(stats.by_difficulty[0], (DifficultyBand::Easy, 0));
+    });
+}
+
+#[test]
+fn difficulty_bands() {
+    assert_eq!(DifficultyBand::of(1), DifficultyBand::Easy);
+    assert_eq!(DifficultyBand::of(3), DifficultyBand::Easy);
+    assert_eq!(DifficultyBand::of(4), DifficultyBand::Medium);
+    assert_eq!(DifficultyBand::of(7), DifficultyBand::Medium);
+    assert_eq!(DifficultyBand::of(8), DifficultyBand::Hard);
+    assert_eq!(DifficultyBand::of(10), DifficultyBand::Hard);
+}
+
+#[test]
+fn account_statistics_are_tracked() {
+    new_test_ext().execute_with(|| {
+        let on_time = create_task(ALICE, 0, Some(START_TIME + 100));
+        assign(ALICE, on_time, BOB);
+        let late = create_task(ALICE, 0, Some(START_TIME + 100));
+        assign(ALICE, late, BOB);
+        let cancelled = create_assigned_task(ALICE, BOB, 0);
+        let own = create_task(ALICE, 0, None);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            on_time,
+            TaskStatus::Completed
+        ));
+        Timestamp::set_timestamp(START_TIME + 101);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            late,
+            TaskStatus::Completed
+        ));
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            cancelled,
+            TaskStatus::Cancelled
+        ));
+        // 没有执行者的任务计入创建者
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            own,
+            TaskStatus::Completed
+        ));
+
+        assert_eq!(
+            Tasks::account_statistics(&BOB),
+            AccountTaskStats {
+                created: 0,
+                assigned: 3,
+                completed: 2,
+                completed_on_time: 1,
+                cancelled: 1,
+            }
+        );
+        assert_eq!(
+            Tasks::account_statistics(&ALICE),
+            AccountTaskStats {
+                created: 4,
+                assigned: 0,
+                completed: 1,
+                completed_on_time: 1,
+                cancelled: 0,
+            }
+        );
+        assert_eq!(
+            Tasks::account_statistics(&BOB).on_time_rate(),
+            Permill::from_percent(50)
+        );
+        assert_eq!(
+            Tasks::account_statistics(&CHARLIE).on_time_rate(),
+            Permill::zero()
+        );
+
+        // 删除任务不会回退累计统计
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), on_time));
+        assert_eq!(Tasks::account_statistics(&BOB).completed, 2);
+    });
+}
+
+#[test]
+fn create_subtask_works() {
+    new_test_ext().execute_with(|| {
+        let parent_id = create_assigned_task(ALICE, BOB, 0);
+
+        let first = create_subtask(ALICE, parent_id);
+        System::assert_last_event(
+            Event::SubtaskCreated {
+                parent_id,
+                task_id: first,
+            }
+            .into(),
+        );
+        // 父任务的执行者也可以拆分子任务
+        let second = create_subtask(BOB, parent_id);
+
+        let task = Tasks::tasks(second).unwrap();
+        assert_eq!(task.parent, Some(parent_id));
+        assert_eq!(task.creator, BOB);
+        assert_eq!(Tasks::tasks(parent_id).unwrap().parent, None);
+        assert_eq!(
+            TaskChildren::<Test>::get(parent_id).to_vec(),
+            vec![first, second]
+        );
+        assert_eq!(UserCreatedTasks::<Test>::get(BOB).to_vec(), vec![second]);
+        assert_eq!(
+            Tasks::subtasks(parent_id)
+                .into_iter()
+                .map(|task| task.id)
+                .collect::<Vec<_>>(),
+            vec![first, second]
+        );
+    });
+}
+
+#[test]
+fn create_subtask_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let parent_id = create_task(ALICE, 0, None);
+        let subtask = |who: u64, parent_id: u32| {
+            Tasks::create_subtask(
+                RuntimeOrigin::signed(who),
+                parent_id,
+                vec![],
+                vec![],
+                Priority::Low,
+                1,
+                0,
+                None,
+            )
+        };
+
+        assert_noop!(subtask(ALICE, 9), Error::<Test>::TaskNotFound);
+        assert_noop!(subtask(BOB, parent_id), Error::<Test>::NotAuthorized);
+
+        let closed = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            closed,
+            TaskStatus::Cancelled
+        ));
+        assert_noop!(subtask(ALICE, closed), Error::<Test>::ParentNotOpen);
+    });
+}
+
+#[test]
+fn subtask_depth_is_bounded() {
+    new_test_ext().execute_with(|| {
+        // MaxTaskDepth = 2
+        let root = create_task(ALICE, 0, None);
+        let child = create_subtask(ALICE, root);
+        let grandchild = create_subtask(ALICE, child);
+        assert_noop!(
+            Tasks::create_subtask(
+                RuntimeOrigin::signed(ALICE),
+                grandchild,
+                vec![],
+                vec![],
+                Priority::Low,
+                1,
+                0,
+                None,
+            ),
+            Error::<Test>::MaxDepthExceeded
+        );
+    });
+}
+
+#[test]
+fn subtask_fan_out_is_bounded() {
+    new_test_ext().execute_with(|| {
+        // MaxSubtasks = 3
+        let root = create_assigned_task(ALICE, BOB, 0);
+        for _ in 0..3 {
+            create_subtask(BOB, root);
+        }
+        assert_noop!(
+            Tasks::create_subtask(
+                RuntimeOrigin::signed(ALICE),
+                root,
+                vec![],
+                vec![],
+                Priority::Low,
+                1,
+                0,
+                None,
+            ),
+            Error::<Test>::TooManySubtasks
+        );
+    });
+}
+
+#[test]
+fn parent_cannot_complete_with_open_children() {
+    new_test_ext().execute_with(|| {
+        let parent_id = create_task(ALICE, 0, None);
+        let done = create_subtask(ALICE, parent_id);
+        let dropped = create_subtask(ALICE, parent_id);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            done,
+            TaskStatus::Completed
+        ));
+        assert_noop!(
+            Tasks::change_task_status(
+                RuntimeOrigin::signed(ALICE),
+                parent_id,
+                TaskStatus::Completed
+            ),
+            Error::<Test>::ChildrenIncomplete
+        );
+        // 其他状态转换不受子任务影响
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            parent_id,
+            TaskStatus::InProgress
+        ));
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            dropped,
+            TaskStatus::Cancelled
+        ));
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            parent_id,
+            TaskStatus::Completed
+        ));
+    });
+}
+
+#[test]
+fn task_progress_rolls_up_children() {
+    new_test_ext().execute_with(|| {
+        let parent_id = create_task(ALICE, 0, None);
+        assert_eq!(Tasks::task_progress(parent_id), Some(Percent::zero()));
+        assert_eq!(Tasks::task_progress(9), None);
+
+        let children: Vec<u32> = (0..3).map(|_| create_subtask(ALICE, parent_id)).collect();
+        assert_eq!(Tasks::task_progress(parent_id), Some(Percent::zero()));
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            children[0],
+            TaskStatus::Completed
+        ));
+        assert_eq!(
+            Tasks::task_progress(parent_id),
+            Some(Percent::from_percent(33))
+        );
+        assert_eq!(Tasks::task_progress(children[0]), Some(Percent::one()));
+
+        for &child in &children[1..] {
+            assert_ok!(Tasks::change_task_status(
+                RuntimeOrigin::signed(ALICE),
+                child,
+                TaskStatus::Completed
+            ));
+        }
+        assert_eq!(Tasks::task_progress(parent_id), Some(Percent::one()));
+    });
+}
+
+#[test]
+fn deleting_tasks_respects_hierarchy() {
+    new_test_ext().execute_with(|| {
+        let parent_id = create_task(ALICE, 0, None);
+        let first = create_subtask(ALICE, parent_id);
+        let second = create_subtask(ALICE, parent_id);
+
+        assert_noop!(
+            Tasks::delete_task(RuntimeOrigin::signed(ALICE), parent_id),
+            Error::<Test>::TaskHasSubtasks
+        );
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), first));
+        assert_eq!(TaskChildren::<Test>::get(parent_id).to_vec(), vec![second]);
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), second));
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), parent_id));
+    });
+}
+
+fn add_dependency(who: u64, task_id: u32, blocker_id: u32) {
+    assert_ok!(Tasks::add_dependency(
+        RuntimeOrigin::signed(who),
+        task_id,
+        blocker_id
+    ));
+}
+
+#[test]
+fn add_dependency_works() {
+    new_test_ext().execute_with(|| {
+        let blocker = create_task(ALICE, 0, None);
+        let task_id = create_task(BOB, 0, None);
+
+        add_dependency(BOB, task_id, blocker);
+
+        assert_eq!(
+            TaskDependencies::<Test>::get(task_id).to_vec(),
+            vec![blocker]
+        );
+        assert_eq!(TaskDependents::<Test>::get(blocker).to_vec(), vec![task_id]);
+        System::assert_last_event(
+            Event::DependencyAdded {
+                task_id,
+                blocker_id: blocker,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn add_dependency_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        let blocker = create_task(ALICE, 0, None);
+        let add = |who: u64, task_id: u32, blocker_id: u32| {
+            Tasks::add_dependency(RuntimeOrigin::signed(who), task_id, blocker_id)
+        };
+
+        assert_noop!(add(ALICE, 9, blocker), Error::<Test>::TaskNotFound);
+        assert_noop!(add(ALICE, task_id, 9), Error::<Test>::TaskNotFound);
+        assert_noop!(add(BOB, task_id, blocker), Error::<Test>::NotAuthorized);
+        assert_noop!(add(ALICE, task_id, task_id), Error::<Test>::SelfDependency);
+
+        add_dependency(ALICE, task_id, blocker);
+        assert_noop!(
+            add(ALICE, task_id, blocker),
+            Error::<Test>::DependencyAlreadyExists
+        );
+
+        let closed = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            closed,
+            TaskStatus::Cancelled
+        ));
+        assert_noop!(add(ALICE, task_id, closed), Error::<Test>::BlockerNotOpen);
+
+        let started = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            started,
+            TaskStatus::InProgress
+        ));
+        assert_noop!(
+            add(ALICE, started, blocker),
+            Error::<Test>::TaskAlreadyStarted
+        );
+    });
+}
+
+#[test]
+fn dependency_cycles_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let a = create_task(ALICE, 0, None);
+        let b = create_task(ALICE, 0, None);
+        let c = create_task(ALICE, 0, None);
+        add_dependency(ALICE, a, b);
+        add_dependency(ALICE, b, c);
+
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(ALICE), b, a),
+            Error::<Test>::CyclicDependency
+        );
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(ALICE), c, a),
+            Error::<Test>::CyclicDependency
+        );
+        // 菱形依赖不是循环
+        add_dependency(ALICE, a, c);
+    });
+}
+
+#[test]
+fn dependencies_within_hierarchy_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let parent = create_task(ALICE, 0, None);
+        let child = create_subtask(ALICE, parent);
+        let grandchild = create_subtask(ALICE, child);
+        let other = create_task(ALICE, 0, None);
+
+        for (task_id, blocker_id) in [
+            (child, parent),
+            (parent, child),
+            (grandchild, parent),
+            (parent, grandchild),
+        ] {
+            assert_noop!(
+                Tasks::add_dependency(RuntimeOrigin::signed(ALICE), task_id, blocker_id),
+                Error::<Test>::RelatedTaskDependency
+            );
+        }
+
+        // 父任务等待子任务结束，经由其他任务间接依赖父任务也会形成循环
+        add_dependency(ALICE, child, other);
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(ALICE), other, parent),
+            Error::<Test>::CyclicDependency
+        );
+    });
+}
+
+#[test]
+fn dependency_search_is_bounded() {
+    new_test_ext().execute_with(|| {
+        // MaxDependencySearch = 4：前置任务所在依赖链有 5 个任务
+        let chain: Vec<u32> = [ALICE, BOB, CHARLIE, DAVE, ALICE]
+            .into_iter()
+            .map(|who| create_task(who, 0, None))
+            .collect();
+        for (i, who) in [ALICE, BOB, CHARLIE, DAVE].into_iter().enumerate() {
+            add_dependency(who, chain[i], chain[i + 1]);
+        }
+        let task_id = create_task(BOB, 0, None);
+
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(BOB), task_id, chain[0]),
+            Error::<Test>::DependencyGraphTooLarge
+        );
+        add_dependency(BOB, task_id, chain[1]);
+    });
+}
+
+#[test]
+fn dependency_count_is_bounded() {
+    new_test_ext().execute_with(|| {
+        // MaxDependencies = 3
+        let task_id = create_task(ALICE, 0, None);
+        for _ in 0..3 {
+            let blocker = create_task(BOB, 0, None);
+            add_dependency(ALICE, task_id, blocker);
+        }
+        let blocker = create_task(BOB, 0, None);
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(ALICE), task_id, blocker),
+            Error::<Test>::TooManyDependencies
+        );
+
+        // 被依赖的数量同样受限
+        for who in [CHARLIE, DAVE, ALICE] {
+            let dependent = create_task(who, 0, None);
+            add_dependency(who, dependent, blocker);
+        }
+        let dependent = create_task(CHARLIE, 0, None);
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(CHARLIE), dependent, blocker),
+            Error::<Test>::TooManyDependencies
+        );
+    });
+}
+
+#[test]
+fn blocked_task_cannot_start() {
+    new_test_ext().execute_with(|| {
+        let blocker = create_task(ALICE, 0, None);
+        let task_id = create_task(ALICE, 0, None);
+        add_dependency(ALICE, task_id, blocker);
+
+        for status in [
+            TaskStatus::InProgress,
+            TaskStatus::PendingVerification,
+            TaskStatus::Completed,
+        ] {
+            assert_noop!(
+                Tasks::change_task_status(RuntimeOrigin::signed(ALICE), task_id, status),
+                Error::<Test>::BlockedByDependencies
+            );
+        }
+
+        // 被阻塞的任务仍然可以取消，取消后依赖随之移除
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Cancelled
+        ));
+        assert!(TaskDependencies::<Test>::get(task_id).is_empty());
+        assert!(TaskDependents::<Test>::get(blocker).is_empty());
+    });
+}
+
+#[test]
+fn task_is_unblocked_when_last_blocker_closes() {
+    new_test_ext().execute_with(|| {
+        let first = create_task(ALICE, 0, None);
+        let second = create_task(ALICE, 0, None);
+        let task_id = create_task(BOB, 0, None);
+        add_dependency(BOB, task_id, first);
+        add_dependency(BOB, task_id, second);
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            first,
+            TaskStatus::Completed
+        ));
+        assert!(!System::events()
+            .iter()
+            .any(|record| record.event == Event::TaskUnblocked { task_id }.into()));
+        assert_eq!(
+            TaskDependencies::<Test>::get(task_id).to_vec(),
+            vec![second]
+        );
+        assert!(TaskDependents::<Test>::get(first).is_empty());
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            second,
+            TaskStatus::Completed
+        ));
+        System::assert_last_event(Event::TaskUnblocked { task_id }.into());
+        assert!(TaskDependencies::<Test>::get(task_id).is_empty());
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            TaskStatus::InProgress
+        ));
+    });
+}
+
+#[test]
+fn remove_dependency_works() {
+    new_test_ext().execute_with(|| {
+        let blocker = create_task(ALICE, 0, None);
+        let task_id = create_task(BOB, 0, None);
+        add_dependency(BOB, task_id, blocker);
+
+        assert_noop!(
+            Tasks::remove_dependency(RuntimeOrigin::signed(ALICE), task_id, blocker),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::remove_dependency(RuntimeOrigin::signed(BOB), task_id, 9),
+            Error::<Test>::DependencyNotFound
+        );
+
+        assert_ok!(Tasks::remove_dependency(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            blocker
+        ));
+        System::assert_has_event(
+            Event::DependencyRemoved {
+                task_id,
+                blocker_id: blocker,
+            }
+            .into(),
+        );
+        System::assert_last_event(Event::TaskUnblocked { task_id }.into());
+        assert!(TaskDependencies::<Test>::get(task_id).is_empty());
+        assert!(TaskDependents::<Test>::get(blocker).is_empty());
+    });
+}
+
+#[test]
+fn deleting_tasks_clears_dependencies() {
+    new_test_ext().execute_with(|| {
+        let blocker = create_task(ALICE, 0, None);
+        let task_id = create_task(BOB, 0, None);
+        let other_blocker = create_task(CHARLIE, 0, None);
+        add_dependency(BOB, task_id, blocker);
+        add_dependency(BOB, task_id, other_blocker);
+
+        // 删除依赖方：前置任务的反向索引随之清理
+        let dependent = create_task(DAVE, 0, None);
+        add_dependency(DAVE, dependent, other_blocker);
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(DAVE), dependent));
+        assert_eq!(
+            TaskDependents::<Test>::get(other_blocker).to_vec(),
+            vec![task_id]
+        );
+
+        // 删除前置任务：依赖它的任务失去最后一个前置任务后解除阻塞
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), blocker));
+        assert_eq!(
+            TaskDependencies::<Test>::get(task_id).to_vec(),
+            vec![other_blocker]
+        );
+        assert_ok!(Tasks::delete_task(
+            RuntimeOrigin::signed(CHARLIE),
+            other_blocker
+        ));
+        System::assert_has_event(Event::TaskUnblocked { task_id }.into());
+        assert!(TaskDependencies::<Test>::get(task_id).is_empty());
+    });
+}
+
+#[test]
+fn expiring_blocker_unblocks_dependents() {
+    new_test_ext().execute_with(|| {
+        let blocker = create_task(ALICE, 0, Some(START_TIME + 5));
+        let task_id = create_task(BOB, 0, None);
+        add_dependency(BOB, task_id, blocker);
+
+        next_block_at(START_TIME + 20);
+        assert_eq!(status_of(blocker), TaskStatus::Expired);
+        System::assert_has_event(Event::TaskUnblocked { task_id }.into());
+    });
+}
+
+/// 由执行者提交验证，并推进到下一个区块抽取验证小组
+fn submit_for_verification(assignee: u64, task_id: u32) {
+    assert_ok!(Tasks::change_task_status(
+        RuntimeOrigin::signed(assignee),
+        task_id,
+        TaskStatus::PendingVerification
+    ));
+    run_to_block(System::block_number() + 1);
+}
+
+/// 每个陪审员使用的固定盐
+fn salt(juror: u64) -> [u8; 32] {
+    [juror as u8; 32]
+}
+
+fn commit(juror: u64, task_id: u32, approve: bool) {
+    let commitment = Tasks::vote_commitment(&juror, task_id, approve, &salt(juror));
+    assert_ok!(Tasks::commit_vote(
+        RuntimeOrigin::signed(juror),
+        task_id,
+        commitment
+    ));
+}
+
+fn reveal(juror: u64, task_id: u32, approve: bool) {
+    assert_ok!(Tasks::reveal_vote(
+        RuntimeOrigin::signed(juror),
+        task_id,
+        approve,
+        salt(juror)
+    ));
+}
+
+/// 提交全部投票承诺，进入揭示阶段后全部揭示
+fn vote_all(task_id: u32, votes: &[(u64, bool)]) {
+    for &(juror, approve) in votes {
+        commit(juror, task_id, approve);
+    }
+    let commit_ends = VerificationRounds::<Test>::get(task_id)
+        .expect("round exists")
+        .commit_ends;
+    run_to_block(commit_ends);
+    for &(juror, approve) in votes {
+        reveal(juror, task_id, approve);
+    }
+}
+
+/// 以相同质押登记陪审员
+fn register_jurors(jurors: &[u64]) {
+    for &who in jurors {
+        assert_ok!(Tasks::register_juror(RuntimeOrigin::signed(who), 10));
+    }
+}
+
+/// 逐块推进到区块 `n`，每个区块时间增加 6 秒
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        next_block_at(Timestamp::get() + 6_000);
+    }
+}
+
+fn verification_of(task_id: u32) -> Option<VerificationOutcome> {
+    Tasks::tasks(task_id).expect("task exists").verification
+}
+
+fn panel_of(task_id: u32) -> Vec<u64> {
+    let mut panel = VerificationRounds::<Test>::get(task_id)
+        .expect("round exists")
+        .panel
+        .to_vec();
+    panel.sort();
+    panel
+}
+
+fn juror_panels(who: u64) -> u32 {
+    Jurors::<Test>::get()
+        .iter()
+        .find(|juror| juror.account == who)
+        .map_or(0, |juror| juror.panels)
+}
+
+fn juror_stake(who: u64) -> u64 {
+    Balances::balance_on_hold(&HoldReason::JurorStake.into(), &who)
+}
+
+#[test]
+fn register_juror_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Tasks::register_juror(RuntimeOrigin::signed(CHARLIE), 50));
+
+        assert_eq!(juror_stake(CHARLIE), 50);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE - 50);
+        let jurors = Jurors::<Test>::get();
+        assert_eq!(jurors.len(), 1);
+        assert_eq!(jurors[0].account, CHARLIE);
+        assert_eq!(jurors[0].stake, 50);
+        System::assert_last_event(
+            Event::JurorRegistered {
+                who: CHARLIE,
+                stake: 50,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn register_juror_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        // MinJurorStake = 10
+        assert_noop!(
+            Tasks::register_juror(RuntimeOrigin::signed(ALICE), 9),
+            Error::<Test>::StakeTooLow
+        );
+        assert_noop!(
+            Tasks::register_juror(RuntimeOrigin::signed(ALICE), INITIAL_BALANCE * 2),
+            sp_runtime::TokenError::FundsUnavailable
+        );
+
+        register_jurors(&[ALICE]);
+        assert_noop!(
+            Tasks::register_juror(RuntimeOrigin::signed(ALICE), 10),
+            Error::<Test>::AlreadyJuror
+        );
+
+        // MaxJurors = 5
+        register_jurors(&[BOB, CHARLIE, DAVE, EVE]);
+        assert_noop!(
+            Tasks::register_juror(RuntimeOrigin::signed(6), 10),
+            Error::<Test>::TooManyJurors
+        );
+    });
+}
+
+#[test]
+fn unregister_juror_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Tasks::unregister_juror(RuntimeOrigin::signed(CHARLIE)),
+            Error::<Test>::NotJuror
+        );
+
+        register_jurors(&[CHARLIE, DAVE]);
+        assert_ok!(Tasks::unregister_juror(RuntimeOrigin::signed(CHARLIE)));
+
+        assert_eq!(juror_stake(CHARLIE), 0);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE);
+        assert_eq!(Jurors::<Test>::get().len(), 1);
+        System::assert_last_event(
+            Event::JurorUnregistered {
+                who: CHARLIE,
+                stake: 10,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn juror_cannot_leave_during_verification() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE]);
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+        submit_for_verification(BOB, task_id);
+        assert_eq!(juror_panels(CHARLIE), 1);
+
+        assert_noop!(
+            Tasks::unregister_juror(RuntimeOrigin::signed(CHARLIE)),
+            Error::<Test>::JurorOnPanel
+        );
+
+        run_to_block(10);
+        assert_eq!(juror_panels(CHARLIE), 0);
+        assert_ok!(Tasks::unregister_juror(RuntimeOrigin::signed(CHARLIE)));
+    });
+}
+
+#[test]
+fn entering_verification_draws_panel_in_next_block() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[ALICE, BOB, CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            TaskStatus::PendingVerification
+        ));
+
+        // 提交的区块内还没有验证小组，任何人都不能投票，创建者也不能直接确认完成
+        // CommitPeriod = 5，RevealPeriod = 3，从抽取的区块 2 开始计算
+        let round = VerificationRounds::<Test>::get(task_id).expect("round started");
+        assert_eq!(round.commit_ends, 7);
+        assert_eq!(round.ends_at, 10);
+        assert!(round.panel.is_empty());
+        assert_eq!(PanelDrawQueue::<Test>::get(2).to_vec(), vec![task_id]);
+        assert_eq!(VerificationQueue::<Test>::get(10).to_vec(), vec![task_id]);
+        let commitment = Tasks::vote_commitment(&CHARLIE, task_id, true, &salt(CHARLIE));
+        assert_noop!(
+            Tasks::commit_vote(RuntimeOrigin::signed(CHARLIE), task_id, commitment),
+            Error::<Test>::NotOnPanel
+        );
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(ALICE), task_id, TaskStatus::Completed),
+            Error::<Test>::VerificationInProgress
+        );
+
+        // 下一个区块开始时抽取：创建者和执行者不会被抽中，MaxVerifiers = 3
+        run_to_block(2);
+        let round = VerificationRounds::<Test>::get(task_id).expect("round started");
+        assert!(round.commitments.is_empty());
+        assert!(round.votes.is_empty());
+        assert_eq!(panel_of(task_id), vec![CHARLIE, DAVE, EVE]);
+        assert!(PanelDrawQueue::<Test>::get(2).is_empty());
+        System::assert_last_event(
+            Event::VerificationStarted {
+                task_id,
+                commit_ends: 7,
+                ends_at: 10,
+                panel: round.panel.to_vec(),
+            }
+            .into(),
+        );
+        for who in [CHARLIE, DAVE, EVE] {
+            assert_eq!(juror_panels(who), 1);
+        }
+        assert_eq!(juror_panels(ALICE), 0);
+    });
+}
+
+#[test]
+fn co_assignees_cannot_verify_task() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(EVE, 20)]);
+
+        submit_for_verification(BOB, task_id);
+
+        assert_eq!(panel_of(task_id), vec![CHARLIE, DAVE]);
+        let commitment = Tasks::vote_commitment(&EVE, task_id, true, &salt(EVE));
+        assert_noop!(
+            Tasks::commit_vote(RuntimeOrigin::signed(EVE), task_id, commitment),
+            Error::<Test>::CannotVerifyOwnTask
+        );
+    });
+}
+
+#[test]
+fn panel_draw_is_weighted_by_stake() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Tasks::register_juror(RuntimeOrigin::signed(BOB), 10));
+        assert_ok!(Tasks::register_juror(RuntimeOrigin::signed(CHARLIE), 10));
+        assert_ok!(Tasks::register_juror(RuntimeOrigin::signed(DAVE), 10));
+        assert_ok!(Tasks::register_juror(RuntimeOrigin::signed(EVE), 970));
+        let task_id = create_task(ALICE, 0, None);
+        let mut task = Tasks::tasks(task_id).expect("task exists");
+        let jurors = Jurors::<Test>::get();
+
+        // 四名候选人抽三名：质押占绝大多数的 EVE 几乎总会被抽中
+        let mut drawn = 0;
+        for id in 0..200 {
+            task.id = id;
+            let panel = Tasks::draw_panel(&task, &jurors);
+            assert_eq!(panel.len(), 3);
+            if panel.contains(&EVE) {
+                drawn += 1;
+            }
+        }
+        assert!(drawn >= 195, "EVE drawn only {} times", drawn);
+    });
+}
+
+#[test]
+fn verification_without_jurors_needs_creator() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+
+        assert!(VerificationRounds::<Test>::get(task_id).is_none());
+        System::assert_last_event(Event::NoJurorsAvailable { task_id }.into());
+
+        // 执行者不能自行确认完成
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(BOB), task_id, TaskStatus::Completed),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            TaskStatus::Completed
+        ));
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+    });
+}
+
+#[test]
+fn status_cannot_change_during_verification() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+
+        for (who, status) in [
+            (ALICE, TaskStatus::Completed),
+            (BOB, TaskStatus::Completed),
+            (ALICE, TaskStatus::InProgress),
+        ] {
+            assert_noop!(
+                Tasks::change_task_status(RuntimeOrigin::signed(who), task_id, status),
+                Error::<Test>::VerificationInProgress
+            );
+        }
+        assert_noop!(
+            Tasks::create_subtask(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                b"subtask".to_vec(),
+                vec![],
+                Priority::Medium,
+                3,
+                0,
+                None,
+            ),
+            Error::<Test>::VerificationInProgress
+        );
+    });
+}
+
+#[test]
+fn verification_requires_closed_subtasks() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+        create_subtask(ALICE, task_id);
+
+        assert_noop!(
+            Tasks::change_task_status(
+                RuntimeOrigin::signed(BOB),
+                task_id,
+                TaskStatus::PendingVerification
+            ),
+            Error::<Test>::ChildrenIncomplete
+        );
+    });
+}
+
+#[test]
+fn commit_vote_works() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+
+        commit(CHARLIE, task_id, true);
+
+        let round = VerificationRounds::<Test>::get(task_id).expect("round exists");
+        let commitment = Tasks::vote_commitment(&CHARLIE, task_id, true, &salt(CHARLIE));
+        assert_eq!(round.commitments.to_vec(), vec![(CHARLIE, commitment)]);
+        // 承诺阶段不公开意见
+        assert!(round.votes.is_empty());
+        System::assert_last_event(
+            Event::VoteCommitted {
+                task_id,
+                juror: CHARLIE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn commit_vote_fails_on_invalid_commitments() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        let commitment = Tasks::vote_commitment(&CHARLIE, task_id, true, &salt(CHARLIE));
+        let commit_vote =
+            |who: u64| Tasks::commit_vote(RuntimeOrigin::signed(who), task_id, commitment);
+
+        assert_noop!(
+            Tasks::commit_vote(RuntimeOrigin::signed(CHARLIE), 9, commitment),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(commit_vote(CHARLIE), Error::<Test>::NotUnderVerification);
+
+        submit_for_verification(BOB, task_id);
+        for who in [ALICE, BOB] {
+            assert_noop!(commit_vote(who), Error::<Test>::CannotVerifyOwnTask);
+        }
+        assert_noop!(commit_vote(6), Error::<Test>::NotOnPanel);
+
+        commit(CHARLIE, task_id, true);
+        assert_noop!(commit_vote(CHARLIE), Error::<Test>::AlreadyCommitted);
+
+        // 承诺阶段在区块 7 结束
+        run_to_block(7);
+        assert_noop!(commit_vote(DAVE), Error::<Test>::CommitPhaseClosed);
+
+        // 轮次结束后不能再投票
+        run_to_block(10);
+        assert_noop!(commit_vote(DAVE), Error::<Test>::NotUnderVerification);
+    });
+}
+
+#[test]
+fn reveal_vote_works() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+        commit(CHARLIE, task_id, false);
+
+        run_to_block(7);
+        reveal(CHARLIE, task_id, false);
+
+        let round = VerificationRounds::<Test>::get(task_id).expect("round exists");
+        assert_eq!(round.votes.to_vec(), vec![(CHARLIE, false)]);
+        System::assert_last_event(
+            Event::VoteRevealed {
+                task_id,
+                juror: CHARLIE,
+                approve: false,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reveal_vote_fails_on_invalid_reveals() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        let reveal_vote = |who: u64, approve: bool, salt: [u8; 32]| {
+            Tasks::reveal_vote(RuntimeOrigin::signed(who), task_id, approve, salt)
+        };
+
+        assert_noop!(
+            reveal_vote(CHARLIE, true, salt(CHARLIE)),
+            Error::<Test>::NotUnderVerification
+        );
+
+        submit_for_verification(BOB, task_id);
+        commit(CHARLIE, task_id, true);
+        assert_noop!(
+            reveal_vote(CHARLIE, true, salt(CHARLIE)),
+            Error::<Test>::RevealPhaseNotStarted
+        );
+
+        run_to_block(7);
+        assert_noop!(
+            reveal_vote(DAVE, true, salt(DAVE)),
+            Error::<Test>::NoCommitment
+        );
+        assert_noop!(
+            reveal_vote(CHARLIE, false, salt(CHARLIE)),
+            Error::<Test>::InvalidReveal
+        );
+        assert_noop!(
+            reveal_vote(CHARLIE, true, salt(DAVE)),
+            Error::<Test>::InvalidReveal
+        );
+
+        reveal(CHARLIE, task_id, true);
+        assert_noop!(
+            reveal_vote(CHARLIE, true, salt(CHARLIE)),
+            Error::<Test>::AlreadyRevealed
+        );
+    });
+}
+
+#[test]
+fn copied_commitment_cannot_be_revealed() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+
+        // DAVE 照抄 CHARLIE 的承诺，即使得知意见和盐也无法揭示
+        let commitment = Tasks::vote_commitment(&CHARLIE, task_id, true, &salt(CHARLIE));
+        commit(CHARLIE, task_id, true);
+        assert_ok!(Tasks::commit_vote(
+            RuntimeOrigin::signed(DAVE),
+            task_id,
+            commitment
+        ));
+
+        run_to_block(7);
+        reveal(CHARLIE, task_id, true);
+        assert_noop!(
+            Tasks::reveal_vote(RuntimeOrigin::signed(DAVE), task_id, true, salt(CHARLIE)),
+            Error::<Test>::InvalidReveal
+        );
+    });
+}
+
+#[test]
+fn unrevealed_votes_are_slashed_and_not_counted() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+        commit(CHARLIE, task_id, true);
+        commit(DAVE, task_id, true);
+        commit(EVE, task_id, false);
+
+        run_to_block(7);
+        reveal(CHARLIE, task_id, true);
+        reveal(DAVE, task_id, true);
+
+        // EVE 没有揭示：UnrevealedVotePenalty = 50%，罚没 5 并销毁
+        let issuance = Balances::total_issuance();
+        run_to_block(10);
+        assert_eq!(juror_stake(EVE), 5);
+        assert_eq!(free(EVE), INITIAL_BALANCE - 10);
+        assert_eq!(Balances::total_issuance(), issuance - 5);
+        let eve = Jurors::<Test>::get()
+            .into_iter()
+            .find(|juror| juror.account == EVE)
+            .expect("EVE is still a juror");
+        assert_eq!(eve.stake, 5);
+        System::assert_has_event(
+            Event::JurorSlashed {
+                task_id,
+                juror: EVE,
+                amount: 5,
+            }
+            .into(),
+        );
+        assert_eq!(juror_stake(CHARLIE), 10);
+
+        // 只统计已揭示的两张赞成票
+        assert_eq!(status_of(task_id), TaskStatus::Completed);
+        assert_eq!(
+            verification_of(task_id),
+            Some(VerificationOutcome {
+                verdict: VerificationVerdict::Approved,
+                approvals: 2,
+                rejections: 0,
+            })
+        );
+
+        // 罚没后的质押可以全部取回
+        assert_ok!(Tasks::unregister_juror(RuntimeOrigin::signed(EVE)));
+        assert_eq!(free(EVE), INITIAL_BALANCE - 5);
+    });
+}
+
+#[test]
+fn approved_verification_completes_task() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+        vote_all(task_id, &[(CHARLIE, true), (DAVE, true), (EVE, false)]);
+
+        run_to_block(9);
+        assert_eq!(status_of(task_id), TaskStatus::PendingVerification);
+
+        // 3 票中 2 票赞成，达到 60% 阈值
+        run_to_block(10);
+        let outcome = VerificationOutcome {
+            verdict: VerificationVerdict::Approved,
+            approvals: 2,
+            rejections: 1,
+        };
+        assert_eq!(status_of(task_id), TaskStatus::Completed);
+        assert_eq!(verification_of(task_id), Some(outcome.clone()));
+        assert!(VerificationRounds::<Test>::get(task_id).is_none());
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(
+            Tasks::account_statistics(&BOB),
+            AccountTaskStats {
+                assigned: 1,
+                completed: 1,
+                completed_on_time: 1,
+                ..Default::default()
+            }
+        );
+        System::assert_last_event(Event::VerificationConcluded { task_id, outcome }.into());
+    });
+}
+
+#[test]
+fn deadline_passing_during_verification_counts_as_on_time() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_task(ALICE, 100, Some(START_TIME + 20_000));
+        assign_with_bond(ALICE, task_id, BOB, 30);
+        submit_for_verification(BOB, task_id);
+        assert_eq!(
+            Tasks::tasks(task_id).expect("task exists").submitted_at,
+            Some(START_TIME)
+        );
+        vote_all(task_id, &[(CHARLIE, true), (DAVE, true)]);
+
+        // 截止时间在投票期间已过，但提交验证时尚未逾期，保证金照常释放
+        run_to_block(10);
+        assert!(Timestamp::get() > START_TIME + 20_000);
+        assert_eq!(status_of(task_id), TaskStatus::Completed);
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+        assert_eq!(Tasks::account_statistics(&BOB).completed_on_time, 1);
+        System::assert_has_event(
+            Event::BondReleased {
+                task_id,
+                assignee: BOB,
+                amount: 30,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn failed_payout_after_verification_is_rolled_back() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(EVE, 50)]);
+        submit_for_verification(BOB, task_id);
+        vote_all(task_id, &[(CHARLIE, true), (DAVE, true)]);
+
+        // 托管奖励被外部减少：执行者的份额可以支付，共同执行者的份额无法支付
+        assert_ok!(Balances::release(
+            &HoldReason::TaskReward.into(),
+            &ALICE,
+            10,
+            Precision::Exact
+        ));
+        run_to_block(10);
+
+        // 已支付给执行者的份额随之撤销，任务停留在待验证状态
+        assert_eq!(status_of(task_id), TaskStatus::PendingVerification);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(free(EVE), INITIAL_BALANCE);
+        assert_eq!(held(ALICE), 90);
+        assert!(VerificationRounds::<Test>::get(task_id).is_none());
+    });
+}
+
+#[test]
+fn rejected_verification_returns_task_to_in_progress() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+        vote_all(task_id, &[(CHARLIE, true), (DAVE, false)]);
+
+        // 2 票中 1 票赞成，低于 60% 阈值
+        run_to_block(10);
+        assert_eq!(status_of(task_id), TaskStatus::InProgress);
+        assert_eq!(
+            verification_of(task_id),
+            Some(VerificationOutcome {
+                verdict: VerificationVerdict::Rejected,
+                approvals: 1,
+                rejections: 1,
+            })
+        );
+        assert_eq!(held(ALICE), 100);
+
+        // 执行者可以再次提交验证
+        submit_for_verification(BOB, task_id);
+        assert_eq!(
+            VerificationRounds::<Test>::get(task_id).map(|round| round.ends_at),
+            Some(19)
+        );
+    });
+}
+
+#[test]
+fn verification_without_quorum_is_inconclusive() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+        // MinVerificationVotes = 2
+        vote_all(task_id, &[(CHARLIE, true)]);
+
+        run_to_block(10);
+        assert_eq!(status_of(task_id), TaskStatus::InProgress);
+        assert_eq!(
+            verification_of(task_id),
+            Some(VerificationOutcome {
+                verdict: VerificationVerdict::Inconclusive,
+                approvals: 1,
+                rejections: 0,
+            })
+        );
+    });
+}
+
+#[test]
+fn deleted_task_skips_verification() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[CHARLIE, DAVE, EVE]);
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        submit_for_verification(BOB, task_id);
+        commit(CHARLIE, task_id, true);
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert!(VerificationRounds::<Test>::get(task_id).is_none());
+        assert_eq!(juror_panels(CHARLIE), 0);
+
+        run_to_block(10);
+        assert!(VerificationQueue::<Test>::get(10).is_empty());
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Tasks(Event::VerificationConcluded { .. })
+        )));
+    });
+}
+
+#[test]
+fn verifications_per_block_are_bounded() {
+    new_test_ext().execute_with(|| {
+        register_jurors(&[EVE]);
+        // MaxVerificationsPerBlock = 2
+        let tasks: Vec<u32> = [ALICE, BOB, CHARLIE]
+            .into_iter()
+            .map(|who| create_assigned_task(who, DAVE, 0))
+            .collect();
+        set_status(DAVE, tasks[0], TaskStatus::PendingVerification);
+        set_status(DAVE, tasks[1], TaskStatus::PendingVerification);
+        assert_noop!(
+            Tasks::change_task_status(
+                RuntimeOrigin::signed(DAVE),
+                tasks[2],
+                TaskStatus::PendingVerification
+            ),
+            Error::<Test>::VerificationQueueFull
+        );
+
+        // 下一个区块提交的轮次在下一个区块结束
+        run_to_block(2);
+        set_status(DAVE, tasks[2], TaskStatus::PendingVerification);
+        assert_eq!(VerificationQueue::<Test>::get(11).to_vec(), vec![tasks[2]]);
+    });
+}
+
+fn set_status(who: u64, task_id: u32, status: TaskStatus) {
+    assert_ok!(Tasks::change_task_status(
+        RuntimeOrigin::signed(who),
+        task_id,
+        status
+    ));
+}
+
+#[test]
+fn reopen_completed_task_reclaims_reward() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(
+            Tasks::tasks(task_id).expect("task exists").closed_at,
+            Some(START_TIME)
+        );
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        // 任务回到待处理并移除执行者，奖励从执行者收回并重新托管
+        let task = Tasks::tasks(task_id).expect("task exists");
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(task.assignee, None);
+        assert_eq!(task.closed_at, None);
+        assert!(UserAssignedTasks::<Test>::get(BOB).is_empty());
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(count(TaskStatus::Completed), 0);
+        assert_eq!(count(TaskStatus::Pending), 1);
+        let stats = Tasks::account_statistics(&BOB);
+        assert_eq!(stats.completed, 0);
+        assert_eq!(stats.completed_on_time, 0);
+        System::assert_has_event(
+            Event::RewardReclaimed {
+                task_id,
+                from: BOB,
+                amount: 100,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::TaskReopened {
+                task_id,
+                previous_status: TaskStatus::Completed,
+                new_status: TaskStatus::Pending,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::TaskUnassigned {
+                task_id,
+                previous_assignee: BOB,
+            }
+            .into(),
+        );
+
+        // 重新分配并完成后再次支付
+        assign(ALICE, task_id, BOB);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+        assert_eq!(Tasks::account_statistics(&BOB).completed, 1);
+    });
+}
+
+#[test]
+fn reopen_cancelled_task_holds_reward_again() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 50, None);
+        set_status(ALICE, task_id, TaskStatus::Cancelled);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        // 未分配的任务回到待处理
+        assert_eq!(status_of(task_id), TaskStatus::Pending);
+        assert_eq!(held(ALICE), 50);
+        assert_eq!(count(TaskStatus::Cancelled), 0);
+        assert_eq!(count(TaskStatus::Pending), 1);
+        assert_eq!(Tasks::account_statistics(&ALICE).cancelled, 0);
+        System::assert_has_event(
+            Event::RewardHeld {
+                task_id,
+                who: ALICE,
+                amount: 50,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reopen_task_checks_status_and_permissions() {
+    new_test_ext().execute_with(|| {
+        let open = create_task(ALICE, 0, None);
+        let expiring = create_task(ALICE, 0, Some(START_TIME + 5));
+        let completed = create_assigned_task(ALICE, BOB, 0);
+        set_status(ALICE, completed, TaskStatus::Completed);
+
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(ALICE), 99),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(BOB), completed),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(ALICE), open),
+            Error::<Test>::NotReopenable
+        );
+
+        // 过期的任务不能重新打开
+        next_block_at(START_TIME + 20);
+        assert_eq!(status_of(expiring), TaskStatus::Expired);
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(ALICE), expiring),
+            Error::<Test>::NotReopenable
+        );
+    });
+}
+
+#[test]
+fn reopen_task_respects_window() {
+    new_test_ext().execute_with(|| {
+        let first = create_assigned_task(ALICE, BOB, 0);
+        let second = create_assigned_task(ALICE, BOB, 0);
+        set_status(ALICE, first, TaskStatus::Completed);
+        set_status(ALICE, second, TaskStatus::Completed);
+
+        // ReopenWindow = 100
+        Timestamp::set_timestamp(START_TIME + 100);
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), first));
+
+        Timestamp::set_timestamp(START_TIME + 101);
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(ALICE), second),
+            Error::<Test>::ReopenWindowExpired
+        );
+    });
+}
+
+#[test]
+fn reopen_task_requires_future_deadline() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 50));
+        assign(ALICE, task_id, BOB);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        Timestamp::set_timestamp(START_TIME + 60);
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id),
+            Error::<Test>::DeadlineInPast
+        );
+
+        // 先延长截止时间再重新打开，任务会在新的截止时间过期
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(START_TIME + 80)),
+        ));
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert_eq!(status_of(task_id), TaskStatus::Pending);
+
+        next_block_at(START_TIME + 100);
+        assert_eq!(status_of(task_id), TaskStatus::Expired);
+    });
+}
+
+#[test]
+fn reopen_subtask_requires_open_parent() {
+    new_test_ext().execute_with(|| {
+        let parent_id = create_task(ALICE, 0, None);
+        let child_id = create_subtask(ALICE, parent_id);
+        set_status(ALICE, child_id, TaskStatus::Completed);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), child_id));
+        set_status(ALICE, child_id, TaskStatus::Completed);
+        set_status(ALICE, parent_id, TaskStatus::Completed);
+
+        assert_noop!(
+            Tasks::reopen_task(RuntimeOrigin::signed(ALICE), child_id),
+            Error::<Test>::ParentNotOpen
+        );
+    });
+}
+
+/// 创建任务并设置为公开
+fn create_open_task(creator: u64) -> u32 {
+    let task_id = create_task(creator, 0, None);
+    assert_ok!(Tasks::set_task_open(
+        RuntimeOrigin::signed(creator),
+        task_id,
+        true
+    ));
+    task_id
+}
+
+fn claimable() -> Vec<u32> {
+    Tasks::claimable_tasks(0, u32::MAX)
+        .into_iter()
+        .map(|task| task.id)
+        .collect()
+}
+
+#[test]
+fn set_task_open_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert!(!Tasks::tasks(task_id).unwrap().open);
+        assert!(claimable().is_empty());
+
+        Timestamp::set_timestamp(START_TIME + 5);
+        assert_ok!(Tasks::set_task_open(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            true
+        ));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert!(task.open);
+        assert_eq!(task.updated_at, START_TIME + 5);
+        assert_eq!(claimable(), vec![task_id]);
+        System::assert_last_event(
+            Event::TaskOpenChanged {
+                task_id,
+                open: true,
+            }
+            .into(),
+        );
+
+        assert_ok!(Tasks::set_task_open(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            false
+        ));
+        assert!(!Tasks::tasks(task_id).unwrap().open);
+        assert!(!ClaimableTasks::<Test>::contains_key(task_id));
+    });
+}
+
+#[test]
+fn set_task_open_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::set_task_open(RuntimeOrigin::signed(ALICE), 9, true),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::set_task_open(RuntimeOrigin::signed(BOB), task_id, true),
+            Error::<Test>::NotAuthorized
+        );
+
+        set_status(ALICE, task_id, TaskStatus::Cancelled);
+        assert_noop!(
+            Tasks::set_task_open(RuntimeOrigin::signed(ALICE), task_id, true),
+            Error::<Test>::TaskNotOpen
+        );
+    });
+}
+
+#[test]
+fn assigned_task_can_be_opened_but_not_claimed() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+        assert_ok!(Tasks::set_task_open(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            true
+        ));
+        assert!(claimable().is_empty());
+
+        // 取消分配后任务变为可认领
+        assert_ok!(Tasks::unassign_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert_eq!(claimable(), vec![task_id]);
+    });
+}
+
+#[test]
+fn claim_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_open_task(ALICE);
+
+        Timestamp::set_timestamp(START_TIME + 5);
+        assert_ok!(Tasks::claim_task(RuntimeOrigin::signed(BOB), task_id));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.assignee, Some(BOB));
+        assert_eq!(task.updated_at, START_TIME + 5);
+        assert!(task.open);
+        assert_eq!(UserAssignedTasks::<Test>::get(BOB).to_vec(), vec![task_id]);
+        assert_eq!(Tasks::account_statistics(&BOB).assigned, 1);
+        assert!(claimable().is_empty());
+        System::assert_last_event(
+            Event::TaskClaimed {
+                task_id,
+                assignee: BOB,
+            }
+            .into(),
+        );
+
+        // 认领后由创建者确认完成，奖励和统计与手动分配一致
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(Tasks::account_statistics(&BOB).completed, 1);
+    });
+}
+
+#[test]
+fn claim_task_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let closed = create_task(ALICE, 0, None);
+        let open = create_open_task(ALICE);
+
+        assert_noop!(
+            Tasks::claim_task(RuntimeOrigin::signed(BOB), 9),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::claim_task(RuntimeOrigin::signed(BOB), closed),
+            Error::<Test>::TaskNotClaimable
+        );
+        assert_noop!(
+            Tasks::claim_task(RuntimeOrigin::signed(ALICE), open),
+            Error::<Test>::CannotAssignToSelf
+        );
+
+        assert_ok!(Tasks::claim_task(RuntimeOrigin::signed(BOB), open));
+        assert_noop!(
+            Tasks::claim_task(RuntimeOrigin::signed(CHARLIE), open),
+            Error::<Test>::TaskNotClaimable
+        );
+    });
+}
+
+#[test]
+fn claim_task_respects_assignee_limit() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..5 {
+            create_assigned_task(ALICE, BOB, 0);
+        }
+        let task_id = create_open_task(CHARLIE);
+
+        assert_noop!(
+            Tasks::claim_task(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::TooManyTasks
+        );
+        assert_eq!(claimable(), vec![task_id]);
+    });
+}
+
+#[test]
+fn closed_and_deleted_tasks_leave_claimable_index() {
+    new_test_ext().execute_with(|| {
+        let completed = create_open_task(ALICE);
+        let deleted = create_open_task(ALICE);
+        let in_progress = create_open_task(ALICE);
+
+        set_status(ALICE, in_progress, TaskStatus::InProgress);
+        assert_eq!(claimable(), vec![completed, deleted, in_progress]);
+
+        set_status(ALICE, completed, TaskStatus::Completed);
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), deleted));
+        assert_eq!(claimable(), vec![in_progress]);
+        assert!(!ClaimableTasks::<Test>::contains_key(completed));
+        assert!(!ClaimableTasks::<Test>::contains_key(deleted));
+
+        // 重新打开的公开任务再次可认领
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), completed));
+        assert_eq!(claimable(), vec![completed, in_progress]);
+    });
+}
+
+#[test]
+fn claimable_tasks_are_paginated() {
+    new_test_ext().execute_with(|| {
+        let tasks: Vec<u32> = (0..4).map(|_| create_open_task(ALICE)).collect();
+        create_task(ALICE, 0, None);
+
+        let page = |offset, limit| -> Vec<u32> {
+            Tasks::claimable_tasks(offset, limit)
+                .into_iter()
+                .map(|task| task.id)
+                .collect()
+        };
+        assert_eq!(page(0, 2), tasks[..2].to_vec());
+        assert_eq!(page(2, 10), tasks[2..].to_vec());
+        assert!(page(4, 10).is_empty());
+    });
+}
+
+fn apply(applicant: u64, task_id: u32, reward: Option<u64>) {
+    assert_ok!(Tasks::apply_for_task(
+        RuntimeOrigin::signed(applicant),
+        task_id,
+        reward,
+        b"note".to_vec()
+    ));
+}
+
+fn applicants(task_id: u32) -> Vec<u64> {
+    Applications::<Test>::get(task_id)
+        .into_iter()
+        .map(|application| application.applicant)
+        .collect()
+}
+
+#[test]
+fn apply_for_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+
+        Timestamp::set_timestamp(START_TIME + 5);
+        apply(BOB, task_id, Some(150));
+        System::assert_last_event(
+            Event::ApplicationSubmitted {
+                task_id,
+                applicant: BOB,
+                reward: Some(150),
+            }
+            .into(),
+        );
+        apply(CHARLIE, task_id, None);
+
+        let applications = Applications::<Test>::get(task_id);
+        assert_eq!(applicants(task_id), vec![BOB, CHARLIE]);
+        assert_eq!(applications[0].reward, Some(150));
+        assert_eq!(applications[0].note.to_vec(), b"note".to_vec());
+        assert_eq!(applications[0].applied_at, START_TIME + 5);
+        assert_eq!(applications[1].reward, None);
+
+        // 申请不影响任务本身
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.assignee, None);
+        assert_eq!(task.reward, 100);
+    });
+}
+
+#[test]
+fn apply_for_task_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(BOB), 9, None, vec![]),
+            Error::<Test>::TaskNotFound
+        );
+        // MaxApplicationNoteLength = 16
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(BOB), task_id, None, vec![0; 17]),
+            Error::<Test>::NoteTooLong
+        );
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(ALICE), task_id, None, vec![]),
+            Error::<Test>::CannotAssignToSelf
+        );
+
+        apply(BOB, task_id, None);
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(BOB), task_id, Some(5), vec![]),
+            Error::<Test>::AlreadyApplied
+        );
+
+        // MaxApplicants = 3
+        apply(CHARLIE, task_id, None);
+        apply(DAVE, task_id, None);
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(EVE), task_id, None, vec![]),
+            Error::<Test>::TooManyApplications
+        );
+
+        let assigned = create_assigned_task(ALICE, BOB, 0);
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(CHARLIE), assigned, None, vec![]),
+            Error::<Test>::TaskAlreadyAssigned
+        );
+
+        let cancelled = create_task(ALICE, 0, None);
+        set_status(ALICE, cancelled, TaskStatus::Cancelled);
+        assert_noop!(
+            Tasks::apply_for_task(RuntimeOrigin::signed(CHARLIE), cancelled, None, vec![]),
+            Error::<Test>::TaskNotOpen
+        );
+    });
+}
+
+#[test]
+fn accept_application_assigns_and_clears_applications() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        apply(BOB, task_id, None);
+        apply(CHARLIE, task_id, None);
+
+        assert_ok!(Tasks::accept_application(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            CHARLIE
+        ));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.assignee, Some(CHARLIE));
+        assert_eq!(task.reward, 100);
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(
+            UserAssignedTasks::<Test>::get(CHARLIE).to_vec(),
+            vec![task_id]
+        );
+        assert_eq!(Tasks::account_statistics(&CHARLIE).assigned, 1);
+        assert!(!Applications::<Test>::contains_key(task_id));
+        System::assert_last_event(
+            Event::ApplicationAccepted {
+                task_id,
+                applicant: CHARLIE,
+                reward: 100,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn accept_application_applies_counter_offer() {
+    new_test_ext().execute_with(|| {
+        let raised = create_task(ALICE, 100, None);
+        apply(BOB, raised, Some(150));
+        assert_ok!(Tasks::accept_application(
+            RuntimeOrigin::signed(ALICE),
+            raised,
+            BOB
+        ));
+        assert_eq!(Tasks::tasks(raised).unwrap().reward, 150);
+        assert_eq!(held(ALICE), 150);
+
+        let lowered = create_task(ALICE, 100, None);
+        apply(CHARLIE, lowered, Some(60));
+        assert_ok!(Tasks::accept_application(
+            RuntimeOrigin::signed(ALICE),
+            lowered,
+            CHARLIE
+        ));
+        assert_eq!(Tasks::tasks(lowered).unwrap().reward, 60);
+        assert_eq!(held(ALICE), 210);
+
+        // 完成后按接受的报酬支付
+        set_status(ALICE, raised, TaskStatus::Completed);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 150);
+    });
+}
+
+#[test]
+fn accept_application_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        apply(BOB, task_id, Some(INITIAL_BALANCE * 2));
+        apply(CHARLIE, task_id, None);
+
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(ALICE), 9, BOB),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(BOB), task_id, BOB),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(ALICE), task_id, DAVE),
+            Error::<Test>::ApplicationNotFound
+        );
+        // 创建者无法托管申请者提出的报酬
+        assert!(Tasks::accept_application(RuntimeOrigin::signed(ALICE), task_id, BOB).is_err());
+        assert_eq!(applicants(task_id), vec![BOB, CHARLIE]);
+
+        // 执行者的任务数量达到上限时申请保留
+        for _ in 0..5 {
+            create_assigned_task(DAVE, CHARLIE, 0);
+        }
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(ALICE), task_id, CHARLIE),
+            Error::<Test>::TooManyTasks
+        );
+        assert_eq!(applicants(task_id), vec![BOB, CHARLIE]);
+    });
+}
+
+#[test]
+fn accept_application_requires_open_task() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        apply(BOB, task_id, None);
+
+        // 验证中的任务不能再分配执行者，申请保留
+        set_status(ALICE, task_id, TaskStatus::PendingVerification);
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(ALICE), task_id, BOB),
+            Error::<Test>::TaskNotOpen
+        );
+        assert_eq!(applicants(task_id), vec![BOB]);
+    });
+}
+
+#[test]
+fn applications_are_cleared_when_task_is_taken_or_closed() {
+    new_test_ext().execute_with(|| {
+        let assigned = create_task(ALICE, 0, None);
+        let claimed = create_open_task(ALICE);
+        let cancelled = create_task(ALICE, 0, None);
+        let deleted = create_task(ALICE, 0, None);
+        for task_id in [assigned, claimed, cancelled, deleted] {
+            apply(BOB, task_id, None);
+        }
+
+        assign(ALICE, assigned, CHARLIE);
+        assert_ok!(Tasks::claim_task(RuntimeOrigin::signed(CHARLIE), claimed));
+        set_status(ALICE, cancelled, TaskStatus::Cancelled);
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), deleted));
+
+        for task_id in [assigned, claimed, cancelled, deleted] {
+            assert!(!Applications::<Test>::contains_key(task_id));
+        }
+
+        // 取消分配后可以重新申请
+        assert_ok!(Tasks::unassign_task(RuntimeOrigin::signed(ALICE), assigned));
+        apply(BOB, assigned, None);
+        assert_eq!(applicants(assigned), vec![BOB]);
+    });
+}
+
+fn offer(creator: u64, task_id: u32, assignee: u64) {
+    assert_ok!(Tasks::assign_task(
+        RuntimeOrigin::signed(creator),
+        task_id,
+        assignee,
+        0
+    ));
+}
+
+#[test]
+fn accept_assignment_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), 9),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NoAssignmentOffer
+        );
+
+        offer(ALICE, task_id, BOB);
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(CHARLIE), task_id),
+            Error::<Test>::NoAssignmentOffer
+        );
+
+        // AssignmentOfferPeriod = 50
+        Timestamp::set_timestamp(START_TIME + 51);
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::AssignmentOfferExpired
+        );
+    });
+}
+
+#[test]
+fn decline_assignment_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        offer(ALICE, task_id, BOB);
+
+        assert_noop!(
+            Tasks::decline_assignment(RuntimeOrigin::signed(CHARLIE), task_id),
+            Error::<Test>::NoAssignmentOffer
+        );
+        assert_ok!(Tasks::decline_assignment(
+            RuntimeOrigin::signed(BOB),
+            task_id
+        ));
+
+        assert!(!AssignmentOffers::<Test>::contains_key(task_id));
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, None);
+        System::assert_last_event(
+            Event::AssignmentDeclined {
+                task_id,
+                assignee: BOB,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NoAssignmentOffer
+        );
+
+        // 拒绝后可以邀请其他执行者
+        assign(ALICE, task_id, CHARLIE);
+    });
+}
+
+#[test]
+fn expired_offer_can_be_replaced() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        offer(ALICE, task_id, BOB);
+
+        Timestamp::set_timestamp(START_TIME + 50);
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), task_id, CHARLIE, 0),
+            Error::<Test>::AssignmentPending
+        );
+
+        Timestamp::set_timestamp(START_TIME + 51);
+        offer(ALICE, task_id, CHARLIE);
+        assert_eq!(
+            AssignmentOffers::<Test>::get(task_id).map(|offer| offer.expires_at),
+            Some(START_TIME + 101)
+        );
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NoAssignmentOffer
+        );
+        assert_ok!(Tasks::accept_assignment(
+            RuntimeOrigin::signed(CHARLIE),
+            task_id
+        ));
+    });
+}
+
+#[test]
+fn pending_offer_blocks_claims() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_open_task(ALICE);
+        offer(ALICE, task_id, BOB);
+
+        assert_noop!(
+            Tasks::claim_task(RuntimeOrigin::signed(CHARLIE), task_id),
+            Error::<Test>::AssignmentPending
+        );
+
+        Timestamp::set_timestamp(START_TIME + 51);
+        assert_ok!(Tasks::claim_task(RuntimeOrigin::signed(CHARLIE), task_id));
+        assert!(!AssignmentOffers::<Test>::contains_key(task_id));
+    });
+}
+
+#[test]
+fn offers_are_removed_when_task_is_taken_or_closed() {
+    new_test_ext().execute_with(|| {
+        let accepted = create_task(ALICE, 0, None);
+        let cancelled = create_task(ALICE, 0, None);
+        let deleted = create_task(ALICE, 0, None);
+        for task_id in [accepted, cancelled, deleted] {
+            offer(ALICE, task_id, BOB);
+        }
+
+        apply(CHARLIE, accepted, None);
+        assert_ok!(Tasks::accept_application(
+            RuntimeOrigin::signed(ALICE),
+            accepted,
+            CHARLIE
+        ));
+        set_status(ALICE, cancelled, TaskStatus::Cancelled);
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), deleted));
+
+        for task_id in [accepted, cancelled, deleted] {
+            assert!(!AssignmentOffers::<Test>::contains_key(task_id));
+        }
+        assert_noop!(
+            Tasks::accept_assignment(RuntimeOrigin::signed(BOB), cancelled),
+            Error::<Test>::NoAssignmentOffer
+        );
+    });
+}
+
+#[test]
+fn resign_from_task_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        let other = create_assigned_task(CHARLIE, BOB, 0);
+        set_status(BOB, task_id, TaskStatus::InProgress);
+
+        assert_ok!(Tasks::resign_from_task(RuntimeOrigin::signed(BOB), task_id));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.assignee, None);
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(count(TaskStatus::InProgress), 0);
+        assert_eq!(UserAssignedTasks::<Test>::get(BOB).to_vec(), vec![other]);
+        // 没有截止时间的任务不收取违约金，奖励仍由创建者托管
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(held(ALICE), 100);
+        System::assert_last_event(
+            Event::TaskResigned {
+                task_id,
+                assignee: BOB,
+                penalty: 0,
+            }
+            .into(),
+        );
+
+        // 辞去后可以重新分配
+        assign(ALICE, task_id, CHARLIE);
+    });
+}
+
+#[test]
+fn resign_from_task_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::resign_from_task(RuntimeOrigin::signed(BOB), 9),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::resign_from_task(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NotAuthorized
+        );
+        assign(ALICE, task_id, BOB);
+        assert_noop!(
+            Tasks::resign_from_task(RuntimeOrigin::signed(ALICE), task_id),
+            Error::<Test>::NotAuthorized
+        );
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_noop!(
+            Tasks::resign_from_task(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn resigning_close_to_deadline_pays_penalty() {
+    new_test_ext().execute_with(|| {
+        let early = create_task(ALICE, 0, Some(START_TIME + 200));
+        let late = create_task(ALICE, 0, Some(START_TIME + 200));
+        assign(ALICE, early, BOB);
+        assign(ALICE, late, BOB);
+
+        // ResignationPenaltyWindow = 50
+        Timestamp::set_timestamp(START_TIME + 149);
+        assert_ok!(Tasks::resign_from_task(RuntimeOrigin::signed(BOB), early));
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+
+        Timestamp::set_timestamp(START_TIME + 150);
+        assert_ok!(Tasks::resign_from_task(RuntimeOrigin::signed(BOB), late));
+
+        // ResignationPenalty = 20，支付给创建者
+        assert_eq!(free(BOB), INITIAL_BALANCE - 20);
+        assert_eq!(free(ALICE), INITIAL_BALANCE + 20);
+        System::assert_last_event(
+            Event::TaskResigned {
+                task_id: late,
+                assignee: BOB,
+                penalty: 20,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn resignation_penalty_is_limited_to_available_balance() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 20));
+        assign(ALICE, task_id, BOB);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), BOB, 11));
+
+        assert_ok!(Tasks::resign_from_task(RuntimeOrigin::signed(BOB), task_id));
+
+        // 保留存在性押金，只支付可用的部分
+        assert_eq!(free(BOB), 1);
+        assert_eq!(free(ALICE), INITIAL_BALANCE + 10);
+        System::assert_last_event(
+            Event::TaskResigned {
+                task_id,
+                assignee: BOB,
+                penalty: 10,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn resigned_open_task_becomes_claimable_again() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_open_task(ALICE);
+        assert_ok!(Tasks::claim_task(RuntimeOrigin::signed(BOB), task_id));
+        assert!(claimable().is_empty());
+
+        assert_ok!(Tasks::resign_from_task(RuntimeOrigin::signed(BOB), task_id));
+
+        assert_eq!(claimable(), vec![task_id]);
+        assert_ok!(Tasks::claim_task(RuntimeOrigin::signed(CHARLIE), task_id));
+    });
+}
+
+fn bonded(who: u64) -> u64 {
+    Balances::balance_on_hold(&HoldReason::AssigneeBond.into(), &who)
+}
+
+/// 邀请执行者并要求保证金，由执行者接受
+fn assign_with_bond(creator: u64, task_id: u32, assignee: u64, bond: u64) {
+    assert_ok!(Tasks::assign_task(
+        RuntimeOrigin::signed(creator),
+        task_id,
+        assignee,
+        bond
+    ));
+    assert_ok!(Tasks::accept_assignment(
+        RuntimeOrigin::signed(assignee),
+        task_id
+    ));
+}
+
+#[test]
+fn accept_assignment_holds_bond() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            30
+        ));
+        assert_eq!(AssignmentOffers::<Test>::get(task_id).unwrap().bond, 30);
+        assert_eq!(bonded(BOB), 0);
+
+        assert_ok!(Tasks::accept_assignment(
+            RuntimeOrigin::signed(BOB),
+            task_id
+        ));
+
+        assert_eq!(Tasks::tasks(task_id).unwrap().bond, 30);
+        assert_eq!(bonded(BOB), 30);
+        assert_eq!(free(BOB), INITIAL_BALANCE - 30);
+    });
+}
+
+#[test]
+fn accept_assignment_fails_without_funds_for_bond() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        assert_ok!(Tasks::assign_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            BOB,
+            INITIAL_BALANCE * 2
+        ));
+
+        assert!(Tasks::accept_assignment(RuntimeOrigin::signed(BOB), task_id).is_err());
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, None);
+        assert!(AssignmentOffers::<Test>::contains_key(task_id));
+        assert_eq!(bonded(BOB), 0);
+    });
+}
+
+#[test]
+fn bond_is_released_on_time_completion() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 200));
+        assign_with_bond(ALICE, task_id, BOB, 30);
+
+        Timestamp::set_timestamp(START_TIME + 200);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert_eq!(Tasks::tasks(task_id).unwrap().bond, 0);
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        System::assert_has_event(
+            Event::BondReleased {
+                task_id,
+                assignee: BOB,
+                amount: 30,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn bond_is_slashed_on_late_completion() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 200));
+        assign_with_bond(ALICE, task_id, BOB, 30);
+
+        Timestamp::set_timestamp(START_TIME + 201);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE - 30);
+        assert_eq!(free(ALICE), INITIAL_BALANCE + 30);
+        System::assert_has_event(
+            Event::BondSlashed {
+                task_id,
+                assignee: BOB,
+                to: ALICE,
+                amount: 30,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn bond_is_slashed_when_task_expires() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, Some(START_TIME + 25));
+        assign_with_bond(ALICE, task_id, BOB, 30);
+
+        next_block_at(START_TIME + 30);
+
+        assert_eq!(status_of(task_id), TaskStatus::Expired);
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE + 30);
+    });
+}
+
+#[test]
+fn bond_is_slashed_when_assignee_abandons_task() {
+    new_test_ext().execute_with(|| {
+        let cancelled = create_task(ALICE, 0, None);
+        let resigned = create_task(ALICE, 0, None);
+        assign_with_bond(ALICE, cancelled, BOB, 30);
+        assign_with_bond(ALICE, resigned, BOB, 20);
+        assert_eq!(bonded(BOB), 50);
+
+        set_status(BOB, cancelled, TaskStatus::Cancelled);
+        assert_ok!(Tasks::resign_from_task(
+            RuntimeOrigin::signed(BOB),
+            resigned
+        ));
+
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE - 50);
+        assert_eq!(free(ALICE), INITIAL_BALANCE + 50);
+        assert_eq!(Tasks::tasks(resigned).unwrap().bond, 0);
+    });
+}
+
+#[test]
+fn bond_is_released_when_creator_withdraws_task() {
+    new_test_ext().execute_with(|| {
+        let cancelled = create_task(ALICE, 0, None);
+        let unassigned = create_task(ALICE, 0, None);
+        let deleted = create_task(ALICE, 0, None);
+        for task_id in [cancelled, unassigned, deleted] {
+            assign_with_bond(ALICE, task_id, BOB, 10);
+        }
+
+        set_status(ALICE, cancelled, TaskStatus::Cancelled);
+        assert_ok!(Tasks::unassign_task(
+            RuntimeOrigin::signed(ALICE),
+            unassigned
+        ));
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), deleted));
+
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+        assert_eq!(Tasks::tasks(unassigned).unwrap().bond, 0);
+    });
+}
+
+#[test]
+fn slashed_bond_goes_to_configured_beneficiary() {
+    new_test_ext().execute_with(|| {
+        BondBeneficiary::set(Some(EVE));
+        let task_id = create_task(ALICE, 0, None);
+        assign_with_bond(ALICE, task_id, BOB, 30);
+
+        set_status(BOB, task_id, TaskStatus::Cancelled);
+
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+        assert_eq!(free(EVE), INITIAL_BALANCE + 30);
+        System::assert_has_event(
+            Event::BondSlashed {
+                task_id,
+                assignee: BOB,
+                to: EVE,
+                amount: 30,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reopened_task_requires_fresh_bond() {
+    new_test_ext().execute_with(|| {
+        let completed = create_task(ALICE, 0, Some(START_TIME + 200));
+        let cancelled = create_task(ALICE, 0, None);
+        assign_with_bond(ALICE, completed, BOB, 30);
+        assign_with_bond(ALICE, cancelled, CHARLIE, 20);
+        set_status(ALICE, completed, TaskStatus::Completed);
+        set_status(CHARLIE, cancelled, TaskStatus::Cancelled);
+        assert_eq!(bonded(BOB), 0);
+        assert_eq!(bonded(CHARLIE), 0);
+
+        // 已释放或罚没的保证金不会恢复，执行者被移除
+        for task_id in [completed, cancelled] {
+            assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+            let task = Tasks::tasks(task_id).unwrap();
+            assert_eq!(task.status, TaskStatus::Pending);
+            assert_eq!(task.assignee, None);
+            assert_eq!(task.bond, 0);
+        }
+        assert!(UserAssignedTasks::<Test>::get(BOB).is_empty());
+        assert!(UserAssignedTasks::<Test>::get(CHARLIE).is_empty());
+
+        // 重新分配时冻结新的保证金
+        assign_with_bond(ALICE, completed, BOB, 40);
+        assert_eq!(Tasks::tasks(completed).unwrap().bond, 40);
+        assert_eq!(bonded(BOB), 40);
+    });
+}
+
+/// 设置协作执行者，新邀请的账户立即接受
+fn set_crew(creator: u64, task_id: u32, co_assignees: Vec<(u64, u8)>) {
+    assert_ok!(Tasks::set_co_assignees(
+        RuntimeOrigin::signed(creator),
+        task_id,
+        co_assignees
+            .into_iter()
+            .map(|(who, share)| (who, Percent::from_percent(share)))
+            .collect()
+    ));
+    for (who, _) in CoAssigneeOffers::<Test>::get(task_id) {
+        assert_ok!(Tasks::accept_co_assignment(
+            RuntimeOrigin::signed(who),
+            task_id
+        ));
+    }
+}
+
+fn crew(task_id: u32) -> Vec<(u64, Percent)> {
+    Tasks::tasks(task_id).unwrap().co_assignees.to_vec()
+}
+
+#[test]
+fn set_co_assignees_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+
+        set_crew(ALICE, task_id, vec![(CHARLIE, 30), (DAVE, 20)]);
+
+        assert_eq!(
+            crew(task_id),
+            vec![
+                (CHARLIE, Percent::from_percent(30)),
+                (DAVE, Percent::from_percent(20))
+            ]
+        );
+        assert_eq!(
+            UserAssignedTasks::<Test>::get(CHARLIE).to_vec(),
+            vec![task_id]
+        );
+        assert_eq!(UserAssignedTasks::<Test>::get(DAVE).to_vec(), vec![task_id]);
+        System::assert_has_event(
+            Event::CoAssigneesSet {
+                task_id,
+                co_assignees: Tasks::tasks(task_id).unwrap().co_assignees,
+            }
+            .into(),
+        );
+
+        // 替换协作执行者时同步任务列表，保留的协作执行者无需重新接受
+        set_crew(ALICE, task_id, vec![(DAVE, 50)]);
+        assert_eq!(crew(task_id), vec![(DAVE, Percent::from_percent(50))]);
+        assert!(UserAssignedTasks::<Test>::get(CHARLIE).is_empty());
+        assert_eq!(UserAssignedTasks::<Test>::get(DAVE).to_vec(), vec![task_id]);
+
+        set_crew(ALICE, task_id, vec![]);
+        assert!(crew(task_id).is_empty());
+        assert!(UserAssignedTasks::<Test>::get(DAVE).is_empty());
+    });
+}
+
+#[test]
+fn set_co_assignees_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 0, None);
+        let set = |who: u64, co_assignees: Vec<(u64, u8)>| {
+            Tasks::set_co_assignees(
+                RuntimeOrigin::signed(who),
+                task_id,
+                co_assignees
+                    .into_iter()
+                    .map(|(who, share)| (who, Percent::from_percent(share)))
+                    .collect(),
+            )
+        };
+
+        assert_noop!(
+            Tasks::set_co_assignees(RuntimeOrigin::signed(ALICE), 9, vec![]),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            set(ALICE, vec![(CHARLIE, 10)]),
+            Error::<Test>::TaskNotAssigned
+        );
+        assign(ALICE, task_id, BOB);
+        assert_noop!(set(BOB, vec![(CHARLIE, 10)]), Error::<Test>::NotAuthorized);
+        assert_noop!(
+            set(ALICE, vec![(ALICE, 10)]),
+            Error::<Test>::CannotAssignToSelf
+        );
+        assert_noop!(
+            set(ALICE, vec![(BOB, 10)]),
+            Error::<Test>::DuplicateCoAssignee
+        );
+        assert_noop!(
+            set(ALICE, vec![(CHARLIE, 10), (CHARLIE, 20)]),
+            Error::<Test>::DuplicateCoAssignee
+        );
+        assert_noop!(set(ALICE, vec![(CHARLIE, 0)]), Error::<Test>::InvalidShares);
+        assert_noop!(
+            set(ALICE, vec![(CHARLIE, 60), (DAVE, 41)]),
+            Error::<Test>::InvalidShares
+        );
+        // MaxCoAssignees = 3
+        assert_noop!(
+            set(ALICE, vec![(CHARLIE, 10), (DAVE, 10), (EVE, 10), (6, 10)]),
+            Error::<Test>::TooManyCoAssignees
+        );
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_noop!(
+            set(ALICE, vec![(CHARLIE, 10)]),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn set_co_assignees_respects_task_limit() {
+    new_test_ext().execute_with(|| {
+        // MaxTasksPerUser = 5
+        for _ in 0..5 {
+            create_assigned_task(DAVE, CHARLIE, 0);
+        }
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+        assert_ok!(Tasks::set_co_assignees(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            vec![(CHARLIE, Percent::from_percent(10))]
+        ));
+
+        assert_noop!(
+            Tasks::accept_co_assignment(RuntimeOrigin::signed(CHARLIE), task_id),
+            Error::<Test>::TooManyTasks
+        );
+    });
+}
+
+#[test]
+fn co_assignees_must_accept_invitation() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        assert_ok!(Tasks::set_co_assignees(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            vec![
+                (CHARLIE, Percent::from_percent(30)),
+                (DAVE, Percent::from_percent(20))
+            ]
+        ));
+
+        // 邀请被接受之前不加入任务
+        assert!(crew(task_id).is_empty());
+        assert!(UserAssignedTasks::<Test>::get(CHARLIE).is_empty());
+        assert_eq!(CoAssigneeOffers::<Test>::get(task_id).len(), 2);
+        assert_noop!(
+            Tasks::accept_co_assignment(RuntimeOrigin::signed(EVE), task_id),
+            Error::<Test>::NoCoAssignmentOffer
+        );
+
+        assert_ok!(Tasks::accept_co_assignment(
+            RuntimeOrigin::signed(CHARLIE),
+            task_id
+        ));
+        System::assert_last_event(
+            Event::CoAssignmentAccepted {
+                task_id,
+                co_assignee: CHARLIE,
+            }
+            .into(),
+        );
+        assert_eq!(crew(task_id), vec![(CHARLIE, Percent::from_percent(30))]);
+        assert_eq!(
+            UserAssignedTasks::<Test>::get(CHARLIE).to_vec(),
+            vec![task_id]
+        );
+
+        assert_ok!(Tasks::decline_co_assignment(
+            RuntimeOrigin::signed(DAVE),
+            task_id
+        ));
+        System::assert_last_event(
+            Event::CoAssignmentDeclined {
+                task_id,
+                co_assignee: DAVE,
+            }
+            .into(),
+        );
+        assert!(!CoAssigneeOffers::<Test>::contains_key(task_id));
+        assert_noop!(
+            Tasks::decline_co_assignment(RuntimeOrigin::signed(DAVE), task_id),
+            Error::<Test>::NoCoAssignmentOffer
+        );
+
+        // 未接受的份额归执行者
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 70);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE + 30);
+        assert_eq!(free(DAVE), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn co_assignment_offers_end_with_task() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 0);
+        assert_ok!(Tasks::set_co_assignees(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            vec![(CHARLIE, Percent::from_percent(10))]
+        ));
+
+        set_status(ALICE, task_id, TaskStatus::Cancelled);
+
+        assert!(!CoAssigneeOffers::<Test>::contains_key(task_id));
+        assert_noop!(
+            Tasks::accept_co_assignment(RuntimeOrigin::signed(CHARLIE), task_id),
+            Error::<Test>::NoCoAssignmentOffer
+        );
+    });
+}
+
+#[test]
+fn dust_shares_of_missing_accounts_go_to_assignee() {
+    new_test_ext().execute_with(|| {
+        // 账户 6 没有余额，不存在
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 30), (6, 2)]);
+        ExistentialDeposit::set(5);
+
+        // 账户 6 的份额低于存在性押金，归入执行者的份额
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 70);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE + 30);
+        assert_eq!(free(6), 0);
+
+        // 重新打开时按支付时的拆分收回，之后开户的账户不会被收回
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(DAVE),
+            6,
+            10
+        ));
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE);
+        assert_eq!(free(6), 10);
+        assert!(!PaidShares::<Test>::contains_key(task_id));
+    });
+}
+
+#[test]
+fn dust_shares_of_funded_accounts_are_paid() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 30), (DAVE, 2)]);
+        ExistentialDeposit::set(5);
+
+        // DAVE 的份额低于存在性押金，但账户已存在，照常支付
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 68);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE + 30);
+        assert_eq!(free(DAVE), INITIAL_BALANCE + 2);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE);
+        assert_eq!(free(DAVE), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn completing_task_splits_reward_by_share() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 30), (DAVE, 25)]);
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 45);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE + 30);
+        assert_eq!(free(DAVE), INITIAL_BALANCE + 25);
+        for (to, amount) in [(BOB, 45), (CHARLIE, 30), (DAVE, 25)] {
+            System::assert_has_event(
+                Event::RewardPaid {
+                    task_id,
+                    to,
+                    amount,
+                }
+                .into(),
+            );
+        }
+    });
+}
+
+#[test]
+fn reward_split_rounds_in_favour_of_assignee() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 10);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 33), (DAVE, 33)]);
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE + 3);
+        assert_eq!(free(DAVE), INITIAL_BALANCE + 3);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 4);
+    });
+}
+
+#[test]
+fn reopening_task_reclaims_every_share() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 40)]);
+        set_status(ALICE, task_id, TaskStatus::Completed);
+
+        assert_ok!(Tasks::reopen_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE);
+        assert!(Tasks::tasks(task_id).unwrap().co_assignees.is_empty());
+        assert!(UserAssignedTasks::<Test>::get(CHARLIE).is_empty());
+        System::assert_has_event(
+            Event::RewardReclaimed {
+                task_id,
+                from: CHARLIE,
+                amount: 40,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn co_assignee_can_resign_without_affecting_assignment() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 30), (DAVE, 20)]);
+        set_status(BOB, task_id, TaskStatus::InProgress);
+
+        assert_ok!(Tasks::resign_from_task(
+            RuntimeOrigin::signed(CHARLIE),
+            task_id
+        ));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.assignee, Some(BOB));
+        assert_eq!(task.status, TaskStatus::InProgress);
+        assert_eq!(crew(task_id), vec![(DAVE, Percent::from_percent(20))]);
+        assert!(UserAssignedTasks::<Test>::get(CHARLIE).is_empty());
+        assert_eq!(UserAssignedTasks::<Test>::get(BOB).to_vec(), vec![task_id]);
+        System::assert_last_event(
+            Event::TaskResigned {
+                task_id,
+                assignee: CHARLIE,
+                penalty: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn co_assignees_are_removed_with_assignee() {
+    new_test_ext().execute_with(|| {
+        let unassigned = create_assigned_task(ALICE, BOB, 0);
+        let resigned = create_assigned_task(ALICE, BOB, 0);
+        let deleted = create_assigned_task(ALICE, BOB, 0);
+        set_crew(ALICE, unassigned, vec![(CHARLIE, 10)]);
+        set_crew(ALICE, resigned, vec![(CHARLIE, 10)]);
+        set_crew(ALICE, deleted, vec![(CHARLIE, 10)]);
+        assert_eq!(UserAssignedTasks::<Test>::get(CHARLIE).len(), 3);
+
+        assert_ok!(Tasks::unassign_task(
+            RuntimeOrigin::signed(ALICE),
+            unassigned
+        ));
+        assert_ok!(Tasks::resign_from_task(
+            RuntimeOrigin::signed(BOB),
+            resigned
+        ));
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), deleted));
+
+        assert!(crew(unassigned).is_empty());
+        assert!(crew(resigned).is_empty());
+        assert!(UserAssignedTasks::<Test>::get(CHARLIE).is_empty());
+    });
+}
+
+fn set_milestones(creator: u64, task_id: u32, amounts: Vec<u64>) {
+    assert_ok!(Tasks::set_milestones(
+        RuntimeOrigin::signed(creator),
+        task_id,
+        amounts
+            .into_iter()
+            .map(|amount| (b"milestone".to_vec(), amount))
+            .collect()
+    ));
+}
+
+fn milestone_status(task_id: u32) -> Vec<MilestoneStatus> {
+    Milestones::<Test>::get(task_id)
+        .into_iter()
+        .map(|milestone| milestone.status)
+        .collect()
+}
+
+#[test]
+fn set_milestones_works() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+
+        set_milestones(ALICE, task_id, vec![30, 50]);
+
+        let milestones = Milestones::<Test>::get(task_id);
+        assert_eq!(milestones.len(), 2);
+        assert_eq!(milestones[0].description.to_vec(), b"milestone".to_vec());
+        assert_eq!(milestones[1].amount, 50);
+        assert_eq!(
+            milestone_status(task_id),
+            vec![MilestoneStatus::Pending, MilestoneStatus::Pending]
+        );
+        System::assert_last_event(Event::MilestonesSet { task_id, count: 2 }.into());
+
+        // 传入空列表移除全部里程碑
+        set_milestones(ALICE, task_id, vec![]);
+        assert!(!Milestones::<Test>::contains_key(task_id));
+    });
+}
+
+#[test]
+fn set_milestones_fails_on_invalid_requests() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        let milestones = |amounts: Vec<u64>| -> Vec<(Vec<u8>, u64)> {
+            amounts.into_iter().map(|amount| (vec![], amount)).collect()
+        };
+
+        assert_noop!(
+            Tasks::set_milestones(RuntimeOrigin::signed(ALICE), 9, vec![]),
+            Error::<Test>::TaskNotFound
+        );
+        assert_noop!(
+            Tasks::set_milestones(RuntimeOrigin::signed(BOB), task_id, milestones(vec![10])),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::set_milestones(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                milestones(vec![60, 41])
+            ),
+            Error::<Test>::MilestonesExceedReward
+        );
+        // MaxMilestones = 3
+        assert_noop!(
+            Tasks::set_milestones(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                milestones(vec![1, 1, 1, 1])
+            ),
+            Error::<Test>::TooManyMilestones
+        );
+        assert_noop!(
+            Tasks::set_milestones(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                vec![(vec![b'a'; 129], 10)]
+            ),
+            Error::<Test>::DescriptionTooLong
+        );
+
+        // 有里程碑提交后不能再修改
+        set_milestones(ALICE, task_id, vec![10, 20]);
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+        assert_noop!(
+            Tasks::set_milestones(RuntimeOrigin::signed(ALICE), task_id, milestones(vec![10])),
+            Error::<Test>::MilestonesLocked
+        );
+
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_noop!(
+            Tasks::set_milestones(RuntimeOrigin::signed(ALICE), task_id, vec![]),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn milestones_are_paid_in_order() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_milestones(ALICE, task_id, vec![30, 50]);
+
+        assert_noop!(
+            Tasks::submit_milestone(RuntimeOrigin::signed(BOB), task_id, 1),
+            Error::<Test>::MilestoneOutOfOrder
+        );
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+        System::assert_last_event(Event::MilestoneSubmitted { task_id, index: 0 }.into());
+        assert_noop!(
+            Tasks::submit_milestone(RuntimeOrigin::signed(BOB), task_id, 0),
+            Error::<Test>::MilestoneAlreadySubmitted
+        );
+
+        assert_ok!(Tasks::approve_milestone(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            0
+        ));
+
+        // 里程碑金额从托管奖励中支付
+        assert_eq!(Tasks::tasks(task_id).unwrap().reward, 70);
+        assert_eq!(held(ALICE), 70);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 30);
+        assert_eq!(
+            milestone_status(task_id),
+            vec![MilestoneStatus::Approved, MilestoneStatus::Pending]
+        );
+        assert_eq!(
+            Tasks::task_progress(task_id),
+            Some(Percent::from_percent(50))
+        );
+        System::assert_last_event(
+            Event::MilestoneApproved {
+                task_id,
+                index: 0,
+                amount: 30,
+            }
+            .into(),
+        );
+
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            1
+        ));
+        assert_ok!(Tasks::approve_milestone(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            1
+        ));
+        assert_eq!(Tasks::task_progress(task_id), Some(Percent::one()));
+        assert_noop!(
+            Tasks::submit_milestone(RuntimeOrigin::signed(BOB), task_id, 1),
+            Error::<Test>::MilestoneOutOfOrder
+        );
+
+        // 未分配给里程碑的剩余奖励在任务完成时支付
+        set_status(ALICE, task_id, TaskStatus::Completed);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(BOB), INITIAL_BALANCE + 100);
+        assert_eq!(free(ALICE), INITIAL_BALANCE - 100);
+    });
+}
+
+#[test]
+fn milestone_calls_check_permissions_and_state() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_milestones(ALICE, task_id, vec![30]);
+
+        assert_noop!(
+            Tasks::submit_milestone(RuntimeOrigin::signed(ALICE), task_id, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::submit_milestone(RuntimeOrigin::signed(BOB), task_id, 3),
+            Error::<Test>::MilestoneNotFound
+        );
+        assert_noop!(
+            Tasks::approve_milestone(RuntimeOrigin::signed(ALICE), task_id, 0),
+            Error::<Test>::MilestoneNotSubmitted
+        );
+        assert_noop!(
+            Tasks::reject_milestone(RuntimeOrigin::signed(ALICE), task_id, 0),
+            Error::<Test>::MilestoneNotSubmitted
+        );
+
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+        assert_noop!(
+            Tasks::approve_milestone(RuntimeOrigin::signed(BOB), task_id, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::reject_milestone(RuntimeOrigin::signed(BOB), task_id, 0),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn rejected_milestone_can_be_resubmitted() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_milestones(ALICE, task_id, vec![30]);
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+
+        assert_ok!(Tasks::reject_milestone(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            0
+        ));
+
+        assert_eq!(milestone_status(task_id), vec![MilestoneStatus::Pending]);
+        assert_eq!(free(BOB), INITIAL_BALANCE);
+        System::assert_last_event(Event::MilestoneRejected { task_id, index: 0 }.into());
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+    });
+}
+
+#[test]
+fn milestone_payout_is_split_by_share() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_crew(ALICE, task_id, vec![(CHARLIE, 40)]);
+        set_milestones(ALICE, task_id, vec![50]);
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+
+        assert_ok!(Tasks::approve_milestone(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            0
+        ));
+
+        assert_eq!(free(BOB), INITIAL_BALANCE + 30);
+        assert_eq!(free(CHARLIE), INITIAL_BALANCE + 20);
+    });
+}
+
+#[test]
+fn reward_cannot_drop_below_unpaid_milestones() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        set_milestones(ALICE, task_id, vec![30, 50]);
+
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(ALICE),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                Some(79),
+                None
+            ),
+            Error::<Test>::MilestonesExceedReward
+        );
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(ALICE),
+            task_id,
+            None,
+            None,
+            None,
+            None,
+            Some(80),
+            None
+        ));
+    });
+}
+
+#[test]
+fn changing_assignee_withdraws_submitted_milestone() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_assigned_task(ALICE, BOB, 100);
+        set_milestones(ALICE, task_id, vec![30]);
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+
+        assert_ok!(Tasks::resign_from_task(RuntimeOrigin::signed(BOB), task_id));
+
+        assert_eq!(milestone_status(task_id), vec![MilestoneStatus::Pending]);
+        assign(ALICE, task_id, CHARLIE);
+        assert_noop!(
+            Tasks::approve_milestone(RuntimeOrigin::signed(ALICE), task_id, 0),
+            Error::<Test>::MilestoneNotSubmitted
+        );
+    });
+}
+
+#[test]
+fn deleting_task_removes_milestones() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task(ALICE, 100, None);
+        set_milestones(ALICE, task_id, vec![30]);
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(ALICE), task_id));
+
+        assert!(!Milestones::<Test>::contains_key(task_id));
+    });
+}
+
+/// 组织ID
+const ORG: u32 = 7;
+
+/// 设置组织角色：ALICE 为成员，BOB 为管理者，CHARLIE 为成员，EVE 为所有者
+fn setup_org() {
+    set_org_role(ORG, ALICE, OrgRole::Member);
+    set_org_role(ORG, BOB, OrgRole::Manager);
+    set_org_role(ORG, CHARLIE, OrgRole::Member);
+    set_org_role(ORG, EVE, OrgRole::Owner);
+}
+
+/// 以组织名义创建任务并返回任务ID
+fn create_org_task(creator: u64, reward: u64) -> u32 {
+    let task_id = NextTaskId::<Test>::get();
+    assert_ok!(Tasks::create_org_task(
+        RuntimeOrigin::signed(creator),
+        ORG,
+        b"title".to_vec(),
+        b"description".to_vec(),
+        Priority::Medium,
+        5,
+        reward,
+        None,
+    ));
+    task_id
+}
+
+#[test]
+fn create_org_task_works() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 100);
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.org, Some(ORG));
+        assert_eq!(task.creator, ALICE);
+        assert_eq!(held(ALICE), 100);
+        assert_eq!(UserCreatedTasks::<Test>::get(ALICE).to_vec(), vec![task_id]);
+        System::assert_last_event(
+            Event::OrgTaskCreated {
+                task_id,
+                org_id: ORG,
+            }
+            .into(),
+        );
+
+        // 子任务继承父任务所属的组织
+        let subtask_id = create_subtask(ALICE, task_id);
+        assert_eq!(Tasks::tasks(subtask_id).unwrap().org, Some(ORG));
+    });
+}
+
+#[test]
+fn create_org_task_requires_member_role() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        set_org_role(ORG, DAVE, OrgRole::Viewer);
+
+        let create = |who: u64, org_id: u32| {
+            Tasks::create_org_task(
+                RuntimeOrigin::signed(who),
+                org_id,
+                b"title".to_vec(),
+                vec![],
+                Priority::Medium,
+                5,
+                0,
+                None,
+            )
+        };
+
+        // 只读成员不能以组织名义创建任务，其他组织的角色也不能
+        assert_noop!(create(DAVE, ORG), Error::<Test>::NotOrgMember);
+        assert_noop!(create(ALICE, ORG + 1), Error::<Test>::NotOrgMember);
+    });
+}
+
+#[test]
+fn org_manager_can_update_and_assign_org_tasks() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 100);
+
+        assert_ok!(Tasks::update_task(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            Some(b"renamed".to_vec()),
+            None,
+            Some(Priority::High),
+            None,
+            None,
+            None
+        ));
+        assert_eq!(
+            Tasks::tasks(task_id).unwrap().title.to_vec(),
+            b"renamed".to_vec()
+        );
+
+        // 奖励托管在创建者账户中，管理者不能修改
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(BOB),
+                task_id,
+                None,
+                None,
+                None,
+                None,
+                Some(50),
+                None
+            ),
+            Error::<Test>::NotAuthorized
+        );
+
+        // 普通成员不能编辑或分配其他人创建的任务
+        assert_noop!(
+            Tasks::update_task(
+                RuntimeOrigin::signed(CHARLIE),
+                task_id,
+                Some(b"x".to_vec()),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(CHARLIE), task_id, DAVE, 0),
+            Error::<Test>::NotAuthorized
+        );
+
+        // 管理者不能把任务分配给自己
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(BOB), task_id, BOB, 0),
+            Error::<Test>::CannotAssignToSelf
+        );
+
+        assign(BOB, task_id, DAVE);
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, Some(DAVE));
+    });
+}
+
+#[test]
+fn org_roles_do_not_apply_to_personal_tasks() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_task(ALICE, 0, None);
+
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(BOB), task_id, DAVE, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(BOB), task_id, TaskStatus::InProgress),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::delete_task(RuntimeOrigin::signed(EVE), task_id),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn org_manager_can_change_status_and_pay_reward() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 100);
+        assign(BOB, task_id, DAVE);
+
+        assert_noop!(
+            Tasks::change_task_status(
+                RuntimeOrigin::signed(CHARLIE),
+                task_id,
+                TaskStatus::InProgress
+            ),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            TaskStatus::InProgress
+        ));
+        assert_ok!(Tasks::change_task_status(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            TaskStatus::Completed
+        ));
+
+        assert_eq!(status_of(task_id), TaskStatus::Completed);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(DAVE), INITIAL_BALANCE + 100);
+    });
+}
+
+#[test]
+fn only_org_owner_can_delete_org_tasks() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 100);
+
+        assert_noop!(
+            Tasks::delete_task(RuntimeOrigin::signed(BOB), task_id),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(Tasks::delete_task(RuntimeOrigin::signed(EVE), task_id));
+
+        assert!(Tasks::tasks(task_id).is_none());
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(free(ALICE), INITIAL_BALANCE);
+        assert!(UserCreatedTasks::<Test>::get(ALICE).is_empty());
+    });
+}
+
+#[test]
+fn removed_org_member_loses_access() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 0);
+
+        OrgMembers::set(vec![]);
+
+        // 组织任务只看组织角色，创建者离开组织后同样失去权限
+        for who in [ALICE, BOB] {
+            assert_noop!(
+                Tasks::assign_task(RuntimeOrigin::signed(who), task_id, DAVE, 0),
+                Error::<Test>::NotAuthorized
+            );
+        }
+    });
+}
+
+#[test]
+fn org_task_creator_needs_manager_role() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 0);
+
+        // ALICE 只是成员，不能管理自己以组织名义创建的任务
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(ALICE), task_id, DAVE, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::change_task_status(RuntimeOrigin::signed(ALICE), task_id, TaskStatus::Cancelled),
+            Error::<Test>::NotAuthorized
+        );
+
+        set_org_role(ORG, ALICE, OrgRole::Manager);
+        assign(ALICE, task_id, DAVE);
+    });
+}
+
+#[test]
+fn demoted_org_task_creator_cannot_approve_milestones() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        set_org_role(ORG, ALICE, OrgRole::Manager);
+        let task_id = create_org_task(ALICE, 100);
+        assign(ALICE, task_id, DAVE);
+        set_milestones(ALICE, task_id, vec![30, 50]);
+        assert_ok!(Tasks::submit_milestone(
+            RuntimeOrigin::signed(DAVE),
+            task_id,
+            0
+        ));
+
+        // ALICE 被降级为成员后不能再处理里程碑，由管理者批准
+        set_org_role(ORG, ALICE, OrgRole::Member);
+        assert_noop!(
+            Tasks::approve_milestone(RuntimeOrigin::signed(ALICE), task_id, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::reject_milestone(RuntimeOrigin::signed(ALICE), task_id, 0),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Tasks::approve_milestone(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            0
+        ));
+
+        assert_eq!(milestone_status(task_id)[0], MilestoneStatus::Approved);
+        assert_eq!(free(DAVE), INITIAL_BALANCE + 30);
+        assert_eq!(held(ALICE), 70);
+    });
+}
+
+#[test]
+fn demoted_org_task_creator_cannot_accept_applications() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 100);
+        apply(DAVE, task_id, None);
+        apply(BOB, task_id, None);
+
+        // ALICE 只是成员，不能接受申请；管理者不能接受自己的申请
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(ALICE), task_id, DAVE),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::accept_application(RuntimeOrigin::signed(BOB), task_id, BOB),
+            Error::<Test>::CannotAssignToSelf
+        );
+
+        assert_ok!(Tasks::accept_application(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            DAVE
+        ));
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, Some(DAVE));
+    });
+}
+
+#[test]
+fn org_manager_can_manage_assignment_and_dependencies() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(ALICE, 0);
+        let blocker = create_org_task(ALICE, 0);
+
+        // 普通成员（包括创建者）不能修改依赖、公开状态或分配
+        assert_noop!(
+            Tasks::add_dependency(RuntimeOrigin::signed(ALICE), task_id, blocker),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Tasks::set_task_open(RuntimeOrigin::signed(ALICE), task_id, true),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(Tasks::add_dependency(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            blocker
+        ));
+        assert_ok!(Tasks::remove_dependency(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            blocker
+        ));
+        assert_ok!(Tasks::set_task_open(
+            RuntimeOrigin::signed(BOB),
+            task_id,
+            true
+        ));
+
+        assign(BOB, task_id, DAVE);
+        assert_noop!(
+            Tasks::unassign_task(RuntimeOrigin::signed(ALICE), task_id),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Tasks::unassign_task(RuntimeOrigin::signed(BOB), task_id));
+        assert_eq!(Tasks::tasks(task_id).unwrap().assignee, None);
+    });
+}
+
+#[test]
+fn org_subtasks_require_member_role() {
+    new_test_ext().execute_with(|| {
+        setup_org();
+        let task_id = create_org_task(CHARLIE, 0);
+        assign(BOB, task_id, DAVE);
+
+        // 执行者不是组织成员，不能以组织名义拆分子任务
+        let create = |who: u64| {
+            Tasks::create_subtask(
+                RuntimeOrigin::signed(who),
+                task_id,
+                b"subtask".to_vec(),
+                vec![],
+                Priority::Medium,
+                3,
+                0,
+                None,
+            )
+        };
+        assert_noop!(create(DAVE), Error::<Test>::NotOrgMember);
+
+        // 创建者被降级为只读成员后同样不能创建
+        set_org_role(ORG, CHARLIE, OrgRole::Viewer);
+        assert_noop!(create(CHARLIE), Error::<Test>::NotOrgMember);
+
+        set_org_role(ORG, DAVE, OrgRole::Member);
+        assert_ok!(create(DAVE));
+    });
+}
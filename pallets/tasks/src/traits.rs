@@ -0,0 +1,131 @@
+//! 供其他 pallet 订阅任务生命周期、提供组织成员角色的接口
+
+use crate::{OrgRole, Priority, TaskStatus, VerificationVerdict};
+use frame_support::weights::Weight;
+
+/// 任务结果涉及的执行者及任务属性
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TaskSummary<AccountId> {
+    /// 任务ID
+    pub task_id: u32,
+    /// 执行者
+    pub assignee: AccountId,
+    /// 优先级
+    pub priority: Priority,
+    /// 难度等级 (1-10)
+    pub difficulty: u8,
+    /// 任务是否带有奖励
+    pub rewarded: bool,
+}
+
+/// 任务生命周期回调
+///
+/// 只有分配了执行者的任务才会触发回调，创建者自己完成的任务不计入。
+/// 回调在任务 pallet 的调用和 `on_initialize` 中同步执行。常数级的开销计入任务 pallet 的基准测试；
+/// 开销随实现方状态增长的回调必须通过对应的 `*_weight` 函数报告最坏情况的权重，
+/// 任务 pallet 会把它计入调用和 `on_initialize` 的权重。
+pub trait TaskLifecycleHandler<AccountId> {
+    /// 任务完成，`on_time` 表示是否在截止时间之前完成（没有截止时间视为按时）
+    fn on_task_completed(summary: &TaskSummary<AccountId>, on_time: bool);
+
+    /// 验证轮次结束
+    fn on_verification_concluded(summary: &TaskSummary<AccountId>, verdict: &VerificationVerdict);
+
+    /// 执行者主动取消了任务
+    fn on_task_cancelled_by_assignee(summary: &TaskSummary<AccountId>);
+
+    /// 创建者重新打开了已完成或已取消的任务，`on_time` 与完成时传入的值一致
+    fn on_task_reopened(
+        summary: &TaskSummary<AccountId>,
+        previous_status: &TaskStatus,
+        on_time: bool,
+    );
+
+    /// 任务被删除，无论是否分配过执行者都会触发，实现方应清理按任务记录的状态
+    fn on_task_deleted(task_id: u32);
+
+    /// `on_task_completed` 超出常数级开销的最坏情况权重
+    fn on_task_completed_weight() -> Weight {
+        Weight::zero()
+    }
+
+    /// `on_task_cancelled_by_assignee` 超出常数级开销的最坏情况权重
+    fn on_task_cancelled_by_assignee_weight() -> Weight {
+        Weight::zero()
+    }
+
+    /// `on_task_reopened` 超出常数级开销的最坏情况权重
+    fn on_task_reopened_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AccountId> TaskLifecycleHandler<AccountId> for Tuple {
+    fn on_task_completed(summary: &TaskSummary<AccountId>, on_time: bool) {
+        for_tuples!( #( Tuple::on_task_completed(summary, on_time); )* );
+    }
+
+    fn on_verification_concluded(summary: &TaskSummary<AccountId>, verdict: &VerificationVerdict) {
+        for_tuples!( #( Tuple::on_verification_concluded(summary, verdict); )* );
+    }
+
+    fn on_task_cancelled_by_assignee(summary: &TaskSummary<AccountId>) {
+        for_tuples!( #( Tuple::on_task_cancelled_by_assignee(summary); )* );
+    }
+
+    fn on_task_reopened(
+        summary: &TaskSummary<AccountId>,
+        previous_status: &TaskStatus,
+        on_time: bool,
+    ) {
+        for_tuples!( #( Tuple::on_task_reopened(summary, previous_status, on_time); )* );
+    }
+
+    fn on_task_deleted(task_id: u32) {
+        for_tuples!( #( Tuple::on_task_deleted(task_id); )* );
+    }
+
+    fn on_task_completed_weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_task_completed_weight()); )* );
+        weight
+    }
+
+    fn on_task_cancelled_by_assignee_weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_task_cancelled_by_assignee_weight()); )* );
+        weight
+    }
+
+    fn on_task_reopened_weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_task_reopened_weight()); )* );
+        weight
+    }
+}
+
+/// 组织成员角色查询
+///
+/// 任务 pallet 只读取角色，组织的创建和成员管理由实现方负责。查询在权限检查中同步执行，
+/// 实现应保持常数级的存储读取。
+pub trait OrganizationProvider<AccountId> {
+    /// 账户在组织中的角色，组织不存在或账户不是成员时为 `None`
+    fn role(org_id: u32, who: &AccountId) -> Option<OrgRole>;
+
+    /// 为基准测试创建组织并赋予账户角色，返回组织ID
+    #[cfg(feature = "runtime-benchmarks")]
+    fn setup_member(who: &AccountId, role: OrgRole) -> u32;
+}
+
+/// 不支持组织：任何账户都不是组织成员
+impl<AccountId> OrganizationProvider<AccountId> for () {
+    fn role(_org_id: u32, _who: &AccountId) -> Option<OrgRole> {
+        None
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn setup_member(_who: &AccountId, _role: OrgRole) -> u32 {
+        0
+    }
+}
@@ -0,0 +1,942 @@
+//! Placeholder weights for pallet_tasks
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI. Database reads/writes follow the storage items listed
+//! above each function, but execution times have not been measured. The benchmarks in
+//! `benchmarking.rs` are complete; run `scripts/benchmark-weights.sh tasks` on reference
+//! hardware to replace this file with generated weights before the runtime is deployed.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_tasks.
+pub trait WeightInfo {
+	fn create_task(t: u32, d: u32, ) -> Weight;
+	fn update_task(t: u32, d: u32, ) -> Weight;
+	fn change_task_status(c: u32, d: u32, ) -> Weight;
+	fn assign_task() -> Weight;
+	fn unassign_task(a: u32, ) -> Weight;
+	fn delete_task(c: u32, a: u32, d: u32, ) -> Weight;
+	fn process_expiries(n: u32, ) -> Weight;
+	fn create_subtask(t: u32, d: u32, ) -> Weight;
+	fn add_dependency(n: u32, ) -> Weight;
+	fn remove_dependency() -> Weight;
+	fn commit_vote(v: u32, ) -> Weight;
+	fn reveal_vote(v: u32, ) -> Weight;
+	fn process_verifications(n: u32, ) -> Weight;
+	fn start_verification() -> Weight;
+	fn process_panel_draws(n: u32, ) -> Weight;
+	fn register_juror(j: u32, ) -> Weight;
+	fn unregister_juror(j: u32, ) -> Weight;
+	fn reopen_task() -> Weight;
+	fn set_task_open() -> Weight;
+	fn claim_task() -> Weight;
+	fn apply_for_task(a: u32, n: u32, ) -> Weight;
+	fn accept_application(a: u32, ) -> Weight;
+	fn accept_assignment() -> Weight;
+	fn decline_assignment() -> Weight;
+	fn resign_from_task(a: u32, ) -> Weight;
+	fn set_co_assignees(n: u32, ) -> Weight;
+	fn accept_co_assignment() -> Weight;
+	fn decline_co_assignment() -> Weight;
+	fn set_milestones(m: u32, ) -> Weight;
+	fn submit_milestone() -> Weight;
+	fn approve_milestone(c: u32, ) -> Weight;
+	fn reject_milestone() -> Weight;
+	fn create_org_task(t: u32, d: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_tasks, scaled by the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Tasks UserCreatedTasks (r:1 w:1)
+	/// Proof: Tasks UserCreatedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks NextTaskId (r:1 w:1)
+	/// Proof: Tasks NextTaskId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:1 w:1)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks Tasks (r:0 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 128]`.
+	/// The range of component `d` is `[0, 1024]`.
+	fn create_task(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(49_500_000, 4515)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:2 w:2)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:0)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 128]`.
+	/// The range of component `d` is `[0, 1024]`.
+	fn update_task(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(53_800_000, 8040)
+			.saturating_add(Weight::from_parts(2_300, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:10 w:10)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:2 w:2)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskChildren (r:1 w:0)
+	/// Proof: Tasks TaskChildren (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tasks AccountStats (r:1 w:1)
+	/// Proof: Tasks AccountStats (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependencies (r:33 w:33)
+	/// Proof: Tasks TaskDependencies (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependents (r:17 w:17)
+	/// Proof: Tasks TaskDependents (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:0 w:1)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:0 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tasks CoAssigneeOffers (r:0 w:1)
+	/// Proof: Tasks CoAssigneeOffers (max_values: None, max_size: Some(286), added: 2761, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 32]`.
+	/// The range of component `d` is `[0, 32]`.
+	fn change_task_status(c: u32, d: u32, ) -> Weight {
+		Weight::from_parts(270_800_000, 27020)
+			.saturating_add(Weight::from_parts(5_200_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(6_100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 3793).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(d.into()))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:1 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn assign_task() -> Weight {
+		Weight::from_parts(22_300_000, 4793)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 100]`.
+	fn unassign_task(a: u32, ) -> Weight {
+		Weight::from_parts(27_600_000, 4783)
+			.saturating_add(Weight::from_parts(41_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:1 w:1)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks UserCreatedTasks (r:1 w:1)
+	/// Proof: Tasks UserCreatedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependencies (r:17 w:17)
+	/// Proof: Tasks TaskDependencies (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependents (r:17 w:17)
+	/// Proof: Tasks TaskDependents (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:0 w:1)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:0 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:0 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 100]`.
+	/// The range of component `a` is `[1, 100]`.
+	/// The range of component `d` is `[0, 32]`.
+	fn delete_task(c: u32, a: u32, d: u32, ) -> Weight {
+		Weight::from_parts(60_400_000, 4783)
+			.saturating_add(Weight::from_parts(43_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(41_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(6_100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(d.into()))
+	}
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks Tasks (r:64 w:64)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:64 w:64)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:2 w:2)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:64)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:0 w:64)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:0 w:64)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn process_expiries(n: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 4515)
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3793).saturating_mul(n.into()))
+	}
+	/// Storage: Tasks Tasks (r:5 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks UserCreatedTasks (r:1 w:1)
+	/// Proof: Tasks UserCreatedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks NextTaskId (r:1 w:1)
+	/// Proof: Tasks NextTaskId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:1 w:1)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskChildren (r:1 w:1)
+	/// Proof: Tasks TaskChildren (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 128]`.
+	/// The range of component `d` is `[0, 1024]`.
+	fn create_subtask(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(73_000_000, 19955)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Tasks Tasks (r:10 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependencies (r:128 w:1)
+	/// Proof: Tasks TaskDependencies (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskChildren (r:128 w:0)
+	/// Proof: Tasks TaskChildren (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependents (r:1 w:1)
+	/// Proof: Tasks TaskDependents (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 128]`.
+	fn add_dependency(n: u32, ) -> Weight {
+		Weight::from_parts(51_000_000, 41288)
+			.saturating_add(Weight::from_parts(6_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 5186).saturating_mul(n.into()))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependencies (r:1 w:1)
+	/// Proof: Tasks TaskDependencies (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependents (r:1 w:1)
+	/// Proof: Tasks TaskDependents (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	fn remove_dependency() -> Weight {
+		Weight::from_parts(25_000_000, 4783)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks VerificationRounds (r:1 w:1)
+	/// Proof: Tasks VerificationRounds (max_values: None, max_size: Some(3329), added: 5804, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 31]`.
+	fn commit_vote(v: u32, ) -> Weight {
+		Weight::from_parts(27_100_000, 6794)
+			.saturating_add(Weight::from_parts(104_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks VerificationRounds (r:1 w:1)
+	/// Proof: Tasks VerificationRounds (max_values: None, max_size: Some(3329), added: 5804, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 31]`.
+	fn reveal_vote(v: u32, ) -> Weight {
+		Weight::from_parts(28_300_000, 6794)
+			.saturating_add(Weight::from_parts(118_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks VerificationQueue (r:1 w:1)
+	/// Proof: Tasks VerificationQueue (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Tasks VerificationRounds (r:64 w:64)
+	/// Proof: Tasks VerificationRounds (max_values: None, max_size: Some(3329), added: 5804, mode: MaxEncodedLen)
+	/// Storage: Tasks Jurors (r:1 w:1)
+	/// Proof: Tasks Jurors (max_values: Some(1), max_size: Some(13826), added: 14321, mode: MaxEncodedLen)
+	/// Storage: Tasks Tasks (r:64 w:64)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:2112 w:2112)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	/// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2688 w:2688)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks AccountStats (r:64 w:64)
+	/// Proof: Tasks AccountStats (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:2 w:2)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependencies (r:64 w:64)
+	/// Proof: Tasks TaskDependencies (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskDependents (r:64 w:64)
+	/// Proof: Tasks TaskDependents (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:64)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:0 w:64)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:0 w:64)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tasks CoAssigneeOffers (r:0 w:64)
+	/// Proof: Tasks CoAssigneeOffers (max_values: None, max_size: Some(286), added: 2761, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn process_verifications(n: u32, ) -> Weight {
+		Weight::from_parts(4_300_000, 15311)
+			.saturating_add(Weight::from_parts(977_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((85_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((89_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 26628).saturating_mul(n.into()))
+	}
+	/// Storage: Tasks PanelDrawQueue (r:1 w:1)
+	/// Proof: Tasks PanelDrawQueue (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Tasks VerificationQueue (r:1 w:1)
+	/// Proof: Tasks VerificationQueue (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Tasks VerificationRounds (r:0 w:1)
+	/// Proof: Tasks VerificationRounds (max_values: None, max_size: Some(3329), added: 5804, mode: MaxEncodedLen)
+	fn start_verification() -> Weight {
+		Weight::from_parts(8_200_000, 3727)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tasks PanelDrawQueue (r:1 w:1)
+	/// Proof: Tasks PanelDrawQueue (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Tasks VerificationRounds (r:64 w:64)
+	/// Proof: Tasks VerificationRounds (max_values: None, max_size: Some(3329), added: 5804, mode: MaxEncodedLen)
+	/// Storage: Tasks Tasks (r:64 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Jurors (r:64 w:64)
+	/// Proof: Tasks Jurors (max_values: Some(1), max_size: Some(13826), added: 14321, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn process_panel_draws(n: u32, ) -> Weight {
+		Weight::from_parts(3_900_000, 18048)
+			.saturating_add(Weight::from_parts(89_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 9893).saturating_mul(n.into()))
+	}
+	/// Storage: Tasks Jurors (r:1 w:1)
+	/// Proof: Tasks Jurors (max_values: Some(1), max_size: Some(13826), added: 14321, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `j` is `[0, 255]`.
+	fn register_juror(j: u32, ) -> Weight {
+		Weight::from_parts(32_500_000, 3593)
+			.saturating_add(Weight::from_parts(62_000, 0).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 54).saturating_mul(j.into()))
+	}
+	/// Storage: Tasks Jurors (r:1 w:1)
+	/// Proof: Tasks Jurors (max_values: Some(1), max_size: Some(13826), added: 14321, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `j` is `[1, 256]`.
+	fn unregister_juror(j: u32, ) -> Weight {
+		Weight::from_parts(31_200_000, 3593)
+			.saturating_add(Weight::from_parts(68_000, 0).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 54).saturating_mul(j.into()))
+	}
+	/// Storage: Tasks Tasks (r:2 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:10 w:10)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks AccountStats (r:1 w:1)
+	/// Proof: Tasks AccountStats (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:2 w:2)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	fn reopen_task() -> Weight {
+		Weight::from_parts(277_200_000, 29370)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	fn set_task_open() -> Weight {
+		Weight::from_parts(22_000_000, 4793)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks AccountStats (r:1 w:1)
+	/// Proof: Tasks AccountStats (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:0 w:1)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:1 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn claim_task() -> Weight {
+		Weight::from_parts(34_200_000, 4793)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:1 w:1)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 63]`.
+	/// The range of component `n` is `[0, 256]`.
+	fn apply_for_task(a: u32, n: u32, ) -> Weight {
+		Weight::from_parts(25_100_000, 4793)
+			.saturating_add(Weight::from_parts(96_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_100, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 315).saturating_mul(a.into()))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:1 w:1)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks AccountStats (r:1 w:1)
+	/// Proof: Tasks AccountStats (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:0 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:0)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 64]`.
+	fn accept_application(a: u32, ) -> Weight {
+		Weight::from_parts(54_300_000, 4793)
+			.saturating_add(Weight::from_parts(104_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 315).saturating_mul(a.into()))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks AssignmentOffers (r:1 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Tasks AccountStats (r:1 w:1)
+	/// Proof: Tasks AccountStats (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Tasks Applications (r:0 w:1)
+	/// Proof: Tasks Applications (max_values: None, max_size: Some(20181), added: 22656, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn accept_assignment() -> Weight {
+		Weight::from_parts(36_400_000, 4793)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Tasks AssignmentOffers (r:1 w:1)
+	/// Proof: Tasks AssignmentOffers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn decline_assignment() -> Weight {
+		Weight::from_parts(12_600_000, 3525)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:1 w:1)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Tasks ClaimableTasks (r:0 w:1)
+	/// Proof: Tasks ClaimableTasks (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 100]`.
+	fn resign_from_task(a: u32, ) -> Weight {
+		Weight::from_parts(59_400_000, 6196)
+			.saturating_add(Weight::from_parts(43_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:16 w:16)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Tasks CoAssigneeOffers (r:0 w:1)
+	/// Proof: Tasks CoAssigneeOffers (max_values: None, max_size: Some(286), added: 2761, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_co_assignees(n: u32, ) -> Weight {
+		Weight::from_parts(21_900_000, 5074)
+			.saturating_add(Weight::from_parts(3_100_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2925).saturating_mul(n.into()))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks CoAssigneeOffers (r:1 w:1)
+	/// Proof: Tasks CoAssigneeOffers (max_values: None, max_size: Some(286), added: 2761, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks UserAssignedTasks (r:1 w:1)
+	/// Proof: Tasks UserAssignedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	fn accept_co_assignment() -> Weight {
+		Weight::from_parts(31_800_000, 4793)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tasks CoAssigneeOffers (r:1 w:1)
+	/// Proof: Tasks CoAssigneeOffers (max_values: None, max_size: Some(286), added: 2761, mode: MaxEncodedLen)
+	fn decline_co_assignment() -> Weight {
+		Weight::from_parts(13_100_000, 3751)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 16]`.
+	fn set_milestones(m: u32, ) -> Weight {
+		Weight::from_parts(18_700_000, 20174)
+			.saturating_add(Weight::from_parts(620_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	fn submit_milestone() -> Weight {
+		Weight::from_parts(25_200_000, 20174)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tasks Tasks (r:1 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 8]`.
+	fn approve_milestone(c: u32, ) -> Weight {
+		Weight::from_parts(62_500_000, 20174)
+			.saturating_add(Weight::from_parts(24_600_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	/// Storage: Tasks Tasks (r:1 w:0)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// Storage: Tasks Milestones (r:1 w:1)
+	/// Proof: Tasks Milestones (max_values: None, max_size: Some(16709), added: 19184, mode: MaxEncodedLen)
+	fn reject_milestone() -> Weight {
+		Weight::from_parts(24_100_000, 20174)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: Tasks UserCreatedTasks (r:1 w:1)
+	/// Proof: Tasks UserCreatedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks NextTaskId (r:1 w:1)
+	/// Proof: Tasks NextTaskId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:1 w:1)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks Tasks (r:0 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 128]`.
+	/// The range of component `d` is `[0, 1024]`.
+	fn create_org_task(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(53_300_000, 4515)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_task(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(49_500_000, 4515)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn update_task(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(53_800_000, 8040)
+			.saturating_add(Weight::from_parts(2_300, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn change_task_status(c: u32, d: u32, ) -> Weight {
+		Weight::from_parts(270_800_000, 27020)
+			.saturating_add(Weight::from_parts(5_200_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(6_100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 3793).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(d.into()))
+	}
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn assign_task() -> Weight {
+		Weight::from_parts(22_300_000, 4793)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unassign_task(a: u32, ) -> Weight {
+		Weight::from_parts(27_600_000, 4783)
+			.saturating_add(Weight::from_parts(41_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn delete_task(c: u32, a: u32, d: u32, ) -> Weight {
+		Weight::from_parts(60_400_000, 4783)
+			.saturating_add(Weight::from_parts(43_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(41_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(6_100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(d.into()))
+	}
+	fn process_expiries(n: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 4515)
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3793).saturating_mul(n.into()))
+	}
+	fn create_subtask(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(73_000_000, 19955)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn add_dependency(n: u32, ) -> Weight {
+		Weight::from_parts(51_000_000, 41288)
+			.saturating_add(Weight::from_parts(6_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 5186).saturating_mul(n.into()))
+	}
+	fn remove_dependency() -> Weight {
+		Weight::from_parts(25_000_000, 4783)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn commit_vote(v: u32, ) -> Weight {
+		Weight::from_parts(27_100_000, 6794)
+			.saturating_add(Weight::from_parts(104_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn reveal_vote(v: u32, ) -> Weight {
+		Weight::from_parts(28_300_000, 6794)
+			.saturating_add(Weight::from_parts(118_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn process_verifications(n: u32, ) -> Weight {
+		Weight::from_parts(4_300_000, 15311)
+			.saturating_add(Weight::from_parts(977_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((85_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((89_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 26628).saturating_mul(n.into()))
+	}
+	fn start_verification() -> Weight {
+		Weight::from_parts(8_200_000, 3727)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn process_panel_draws(n: u32, ) -> Weight {
+		Weight::from_parts(3_900_000, 18048)
+			.saturating_add(Weight::from_parts(89_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 9893).saturating_mul(n.into()))
+	}
+	fn register_juror(j: u32, ) -> Weight {
+		Weight::from_parts(32_500_000, 3593)
+			.saturating_add(Weight::from_parts(62_000, 0).saturating_mul(j.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 54).saturating_mul(j.into()))
+	}
+	fn unregister_juror(j: u32, ) -> Weight {
+		Weight::from_parts(31_200_000, 3593)
+			.saturating_add(Weight::from_parts(68_000, 0).saturating_mul(j.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 54).saturating_mul(j.into()))
+	}
+	fn reopen_task() -> Weight {
+		Weight::from_parts(277_200_000, 29370)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	fn set_task_open() -> Weight {
+		Weight::from_parts(22_000_000, 4793)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn claim_task() -> Weight {
+		Weight::from_parts(34_200_000, 4793)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn apply_for_task(a: u32, n: u32, ) -> Weight {
+		Weight::from_parts(25_100_000, 4793)
+			.saturating_add(Weight::from_parts(96_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_100, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 315).saturating_mul(a.into()))
+	}
+	fn accept_application(a: u32, ) -> Weight {
+		Weight::from_parts(54_300_000, 4793)
+			.saturating_add(Weight::from_parts(104_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 315).saturating_mul(a.into()))
+	}
+	fn accept_assignment() -> Weight {
+		Weight::from_parts(36_400_000, 4793)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn decline_assignment() -> Weight {
+		Weight::from_parts(12_600_000, 3525)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn resign_from_task(a: u32, ) -> Weight {
+		Weight::from_parts(59_400_000, 6196)
+			.saturating_add(Weight::from_parts(43_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn set_co_assignees(n: u32, ) -> Weight {
+		Weight::from_parts(21_900_000, 5074)
+			.saturating_add(Weight::from_parts(3_100_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2925).saturating_mul(n.into()))
+	}
+	fn accept_co_assignment() -> Weight {
+		Weight::from_parts(31_800_000, 4793)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn decline_co_assignment() -> Weight {
+		Weight::from_parts(13_100_000, 3751)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_milestones(m: u32, ) -> Weight {
+		Weight::from_parts(18_700_000, 20174)
+			.saturating_add(Weight::from_parts(620_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn submit_milestone() -> Weight {
+		Weight::from_parts(25_200_000, 20174)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn approve_milestone(c: u32, ) -> Weight {
+		Weight::from_parts(62_500_000, 20174)
+			.saturating_add(Weight::from_parts(24_600_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	fn reject_milestone() -> Weight {
+		Weight::from_parts(24_100_000, 20174)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Organizations Members (r:1 w:0)
+	/// Proof: Organizations Members (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: Tasks UserCreatedTasks (r:1 w:1)
+	/// Proof: Tasks UserCreatedTasks (max_values: None, max_size: Some(450), added: 2925, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tasks NextTaskId (r:1 w:1)
+	/// Proof: Tasks NextTaskId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tasks ExpiryQueue (r:1 w:1)
+	/// Proof: Tasks ExpiryQueue (max_values: None, max_size: Some(1050), added: 3525, mode: MaxEncodedLen)
+	/// Storage: Tasks NextExpiryBucket (r:1 w:1)
+	/// Proof: Tasks NextExpiryBucket (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Tasks TaskCountByStatus (r:1 w:1)
+	/// Proof: Tasks TaskCountByStatus (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Tasks Tasks (r:0 w:1)
+	/// Proof: Tasks Tasks (max_values: None, max_size: Some(1614), added: 4089, mode: MaxEncodedLen)
+	/// The range of component `t` is `[0, 128]`.
+	/// The range of component `d` is `[0, 1024]`.
+	fn create_org_task(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(53_300_000, 4515)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+}
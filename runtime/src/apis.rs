@@ -42,10 +42,51 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Reputation,
+	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, Tasks, TransactionPayment,
+	VERSION,
 };
 
+sp_api::decl_runtime_apis! {
+	/// Read-only queries over `pallet_tasks` storage.
+	///
+	/// List queries are paginated with `offset`/`limit`; `offset` counts matching tasks, not
+	/// task ids.
+	pub trait TasksApi<AccountId, Task> where
+		AccountId: codec::Codec,
+		Task: codec::Codec,
+	{
+		/// Get a single task by id.
+		fn task(task_id: u32) -> Option<Task>;
+		/// Tasks created by `creator`, in creation order.
+		fn tasks_by_creator(creator: AccountId, offset: u32, limit: u32) -> Vec<Task>;
+		/// Tasks assigned to `assignee`, in assignment order.
+		fn tasks_by_assignee(assignee: AccountId, offset: u32, limit: u32) -> Vec<Task>;
+		/// Tasks currently in `status`, ordered by task id.
+		fn tasks_by_status(status: pallet_tasks::TaskStatus, offset: u32, limit: u32) -> Vec<Task>;
+		/// Open tasks that any account can claim, in id order.
+		fn claimable_tasks(offset: u32, limit: u32) -> Vec<Task>;
+		/// Direct subtasks of `task_id`.
+		fn subtasks(task_id: u32) -> Vec<Task>;
+		/// Share of completed direct subtasks of `task_id`.
+		fn task_progress(task_id: u32) -> Option<sp_runtime::Percent>;
+		/// Whether `task_id` is still open and past its deadline.
+		fn task_overdue(task_id: u32) -> Option<bool>;
+		/// Global task counts by status, priority and difficulty band.
+		fn task_statistics() -> pallet_tasks::TaskStatistics;
+		/// Lifetime task statistics of `account`.
+		fn account_statistics(account: AccountId) -> pallet_tasks::AccountTaskStats;
+	}
+
+	/// Read-only queries over `pallet_reputation` storage.
+	pub trait ReputationApi<AccountId> where
+		AccountId: codec::Codec,
+	{
+		/// Reputation score and outcome counters of `account`.
+		fn reputation(account: AccountId) -> pallet_reputation::ReputationRecord;
+	}
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -220,6 +261,66 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl self::TasksApi<Block, AccountId, pallet_tasks::Task<Runtime>> for Runtime {
+		fn task(task_id: u32) -> Option<pallet_tasks::Task<Runtime>> {
+			pallet_tasks::Tasks::<Runtime>::get(task_id)
+		}
+
+		fn tasks_by_creator(
+			creator: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_tasks::Task<Runtime>> {
+			Tasks::tasks_by_creator(&creator, offset, limit)
+		}
+
+		fn tasks_by_assignee(
+			assignee: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_tasks::Task<Runtime>> {
+			Tasks::tasks_by_assignee(&assignee, offset, limit)
+		}
+
+		fn tasks_by_status(
+			status: pallet_tasks::TaskStatus,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_tasks::Task<Runtime>> {
+			Tasks::tasks_by_status(status, offset, limit)
+		}
+
+		fn claimable_tasks(offset: u32, limit: u32) -> Vec<pallet_tasks::Task<Runtime>> {
+			Tasks::claimable_tasks(offset, limit)
+		}
+
+		fn subtasks(task_id: u32) -> Vec<pallet_tasks::Task<Runtime>> {
+			Tasks::subtasks(task_id)
+		}
+
+		fn task_progress(task_id: u32) -> Option<sp_runtime::Percent> {
+			Tasks::task_progress(task_id)
+		}
+
+		fn task_overdue(task_id: u32) -> Option<bool> {
+			Tasks::is_task_overdue(task_id)
+		}
+
+		fn task_statistics() -> pallet_tasks::TaskStatistics {
+			Tasks::task_statistics()
+		}
+
+		fn account_statistics(account: AccountId) -> pallet_tasks::AccountTaskStats {
+			Tasks::account_statistics(&account)
+		}
+	}
+
+	impl self::ReputationApi<Block, AccountId> for Runtime {
+		fn reputation(account: AccountId) -> pallet_reputation::ReputationRecord {
+			Reputation::reputation_of(&account)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -31,4 +31,7 @@ frame_benchmarking::define_benchmarks!(
 	[pallet_timestamp, Timestamp]
 	[pallet_sudo, Sudo]
 	[pallet_template, Template]
+	[pallet_tasks, Tasks]
+	[pallet_achievements, Achievements]
+	[pallet_organizations, Organizations]
 );
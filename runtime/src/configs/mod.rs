@@ -24,25 +24,33 @@
 // For more information, please refer to <http://unlicense.org>
 
 // Substrate and Polkadot dependencies
+use codec::Encode;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Randomness, VariantCountOf},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureRoot,
+};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash as HashT, One, Saturating},
+	Perbill, Percent,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, Achievements, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce,
+	Organizations, PalletInfo, Reputation, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+	RuntimeHoldReason, RuntimeOrigin, RuntimeTask, System, Timestamp, DAYS, EXISTENTIAL_DEPOSIT,
+	SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -162,3 +170,108 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
+
+/// Randomness derived from the parent block hash.
+///
+/// The block author can influence this value, so it is only good enough for low-stakes choices
+/// such as picking verifiers. Do not use it where predicting the output is profitable.
+pub struct ParentHashRandomness;
+
+impl Randomness<Hash, BlockNumber> for ParentHashRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		let seed = (System::parent_hash(), subject).using_encoded(BlakeTwo256::hash);
+		(seed, System::block_number().saturating_sub(1))
+	}
+}
+
+parameter_types! {
+	/// At least 66% of the votes cast must approve a task for it to complete.
+	pub const TaskVerificationThreshold: Percent = Percent::from_percent(66);
+	/// Jurors who commit a vote but never reveal it lose a tenth of their stake.
+	pub const UnrevealedVotePenalty: Percent = Percent::from_percent(10);
+}
+
+/// Configure the pallet-tasks in pallets/tasks.
+impl pallet_tasks::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Moment = <Runtime as pallet_timestamp::Config>::Moment;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxTitleLength = ConstU32<128>;
+	type MaxDescriptionLength = ConstU32<1024>;
+	type MaxTasksPerUser = ConstU32<100>;
+	/// Deadlines are bucketed per minute; tasks expire at most one minute late.
+	type ExpiryBucketSize = ConstU64<60_000>;
+	type MaxTasksPerExpiryBucket = ConstU32<256>;
+	type MaxExpiriesPerBlock = ConstU32<64>;
+	type MaxTaskDepth = ConstU32<4>;
+	type MaxSubtasks = ConstU32<32>;
+	type MaxDependencies = ConstU32<16>;
+	type MaxDependencySearch = ConstU32<128>;
+	type CommitPeriod = ConstU32<{ 2 * DAYS }>;
+	type RevealPeriod = ConstU32<DAYS>;
+	type UnrevealedVotePenalty = UnrevealedVotePenalty;
+	type VerificationThreshold = TaskVerificationThreshold;
+	type MinVerificationVotes = ConstU32<3>;
+	type MaxVerifiers = ConstU32<32>;
+	type MaxVerificationsPerBlock = ConstU32<64>;
+	type MinJurorStake = ConstU128<{ 100 * UNIT }>;
+	type MaxJurors = ConstU32<256>;
+	type Randomness = ParentHashRandomness;
+	/// Tasks can be reopened for one day (in milliseconds) after they close.
+	type ReopenWindow = ConstU64<{ 24 * 60 * 60 * 1000 }>;
+	/// Assignment offers can be accepted for three days (in milliseconds).
+	type AssignmentOfferPeriod = ConstU64<{ 3 * 24 * 60 * 60 * 1000 }>;
+	type MaxApplicants = ConstU32<64>;
+	type MaxApplicationNoteLength = ConstU32<256>;
+	type MaxCoAssignees = ConstU32<8>;
+	type MaxMilestones = ConstU32<16>;
+	/// Slashed assignee bonds go to the task creator.
+	type BondBeneficiary = ();
+	/// Assignees resigning within one day (in milliseconds) of the deadline pay a penalty.
+	type ResignationPenalty = ConstU128<{ 10 * UNIT }>;
+	type ResignationPenaltyWindow = ConstU64<{ 24 * 60 * 60 * 1000 }>;
+	type Organizations = Organizations;
+	type LifecycleHandler = (Reputation, Achievements);
+	type WeightInfo = pallet_tasks::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Finishing before the deadline earns a fifth of the task's base points on top.
+	pub const OnTimeBonus: Percent = Percent::from_percent(20);
+	/// Passing community verification earns half of the task's base points on top.
+	pub const ApprovalBonus: Percent = Percent::from_percent(50);
+	/// Failing community verification costs half of the task's base points.
+	pub const RejectionPenalty: Percent = Percent::from_percent(50);
+	/// Walking away from an assigned task costs a quarter of its base points.
+	pub const CancellationPenalty: Percent = Percent::from_percent(25);
+}
+
+/// Configure the pallet-reputation in pallets/reputation.
+impl pallet_reputation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PointsPerWeight = ConstU64<10>;
+	type OnTimeBonus = OnTimeBonus;
+	type ApprovalBonus = ApprovalBonus;
+	type RejectionPenalty = RejectionPenalty;
+	type CancellationPenalty = CancellationPenalty;
+}
+
+/// Configure the pallet-achievements in pallets/achievements.
+impl pallet_achievements::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxBadges = ConstU32<32>;
+	type MaxNameLength = ConstU32<64>;
+	type WeightInfo = pallet_achievements::weights::SubstrateWeight<Runtime>;
+}
+
+/// Configure the pallet-organizations in pallets/organizations.
+impl pallet_organizations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxNameLength = ConstU32<64>;
+	type MaxMembers = ConstU32<100>;
+	type WeightInfo = pallet_organizations::weights::SubstrateWeight<Runtime>;
+}
@@ -15,20 +15,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{AccountId, Balance, BalancesConfig, RuntimeGenesisConfig, SudoConfig, TasksConfig};
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
+use pallet_tasks::Priority;
 use serde_json::Value;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::{self, PresetId};
 use sp_keyring::Sr25519Keyring;
 
+/// A task seeded at genesis: (creator, title, description, priority, difficulty, reward).
+type GenesisTask = (AccountId, Vec<u8>, Vec<u8>, Priority, u8, Balance);
+
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	endowed_accounts: Vec<AccountId>,
 	root: AccountId,
+	tasks: Vec<GenesisTask>,
 ) -> Value {
 	build_struct_json_patch!(RuntimeGenesisConfig {
 		balances: BalancesConfig {
@@ -45,6 +50,7 @@ fn testnet_genesis(
 			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
 		},
 		sudo: SudoConfig { key: Some(root) },
+		tasks: TasksConfig { tasks },
 	})
 }
 
@@ -62,6 +68,14 @@ pub fn development_config_genesis() -> Value {
 			Sr25519Keyring::BobStash.to_account_id(),
 		],
 		sp_keyring::Sr25519Keyring::Alice.to_account_id(),
+		vec![(
+			Sr25519Keyring::Alice.to_account_id(),
+			b"Welcome to DecentraTodo".to_vec(),
+			b"Assign this task to Bob and walk it through to Completed.".to_vec(),
+			Priority::Low,
+			1,
+			0,
+		)],
 	)
 }
 
@@ -83,6 +97,7 @@ pub fn local_config_genesis() -> Value {
 			.map(|v| v.to_account_id())
 			.collect::<Vec<_>>(),
 		Sr25519Keyring::Alice.to_account_id(),
+		vec![],
 	)
 }
 
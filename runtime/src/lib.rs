@@ -69,7 +69,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -225,4 +225,20 @@ mod runtime {
 	// Include the custom logic from the pallet-template in the runtime.
 	#[runtime::pallet_index(7)]
 	pub type Template = pallet_template;
+
+	// Task management logic from pallets/tasks.
+	#[runtime::pallet_index(8)]
+	pub type Tasks = pallet_tasks;
+
+	// Reputation scores driven by task outcomes, from pallets/reputation.
+	#[runtime::pallet_index(9)]
+	pub type Reputation = pallet_reputation;
+
+	// Soulbound achievement badges awarded for task milestones, from pallets/achievements.
+	#[runtime::pallet_index(10)]
+	pub type Achievements = pallet_achievements;
+
+	// Organisations whose members manage tasks according to their role, from pallets/organizations.
+	#[runtime::pallet_index(11)]
+	pub type Organizations = pallet_organizations;
 }
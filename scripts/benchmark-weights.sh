@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+# 在参考硬件上运行自定义 pallet 的基准测试，用生成的结果覆盖各 pallet 的 weights.rs
+#
+# 用法：scripts/benchmark-weights.sh [pallet...]，默认生成 tasks、achievements 和 organizations
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+PALLETS=("$@")
+if [ ${#PALLETS[@]} -eq 0 ]; then
+	PALLETS=(tasks achievements organizations)
+fi
+
+# 生成带 `WeightInfo` trait、`SubstrateWeight` 和 `()` 实现的 pallet 权重文件所需的模板
+TEMPLATE="${TEMPLATE:-.maintain/frame-weight-template.hbs}"
+if [ ! -f "$TEMPLATE" ]; then
+	mkdir -p "$(dirname "$TEMPLATE")"
+	curl -sSfL -o "$TEMPLATE" \
+		https://raw.githubusercontent.com/paritytech/polkadot-sdk/polkadot-stable2503/substrate/.maintain/frame-weight-template.hbs
+fi
+
+cargo build --release --locked --features runtime-benchmarks -p solochain-template-node
+
+for pallet in "${PALLETS[@]}"; do
+	./target/release/solochain-template-node benchmark pallet \
+		--chain dev \
+		--pallet "pallet_${pallet}" \
+		--extrinsic '*' \
+		--steps 50 \
+		--repeat 20 \
+		--wasm-execution compiled \
+		--template "$TEMPLATE" \
+		--output "pallets/${pallet}/src/weights.rs"
+done